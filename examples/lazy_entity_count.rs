@@ -0,0 +1,50 @@
+//! Scrolls a `LazyVStack` of 100,000 rows headlessly and prints the entity count,
+//! which stays constant no matter how far the list is scrolled.
+
+use std::time::Instant;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::InputPlugin;
+use bevy_math::Vec2;
+use bevy_ui::ComputedNode;
+use bevy_ui::prelude::*;
+use swoop_ui::binding::BindingPlugin;
+use swoop_ui::container::lazy::LazyPlugin;
+use swoop_ui::prelude::*;
+
+const ROWS: usize = 100_000;
+const ROW_HEIGHT: f32 = 20.0;
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((InputPlugin, BindingPlugin, LazyPlugin));
+
+    let list = app
+        .world_mut()
+        .spawn(
+            LazyVStack::new()
+                .row_height(ROW_HEIGHT)
+                .lazy_range(ROWS, |index| Name::new(format!("Row {index}"))),
+        )
+        .id();
+    // Without a renderer the layout never runs, so size the viewport by hand
+    app.world_mut().entity_mut(list).insert(ComputedNode {
+        size: Vec2::new(300.0, 600.0),
+        inverse_scale_factor: 1.0,
+        ..Default::default()
+    });
+
+    let start = Instant::now();
+    for step in 0..=10 {
+        let offset = step as f32 / 10.0 * ROWS as f32 * ROW_HEIGHT;
+        app.world_mut()
+            .get_mut::<ScrollPosition>(list)
+            .unwrap()
+            .offset_y = offset;
+        app.update();
+        let entities = app.world_mut().query::<Entity>().iter(app.world()).count();
+        println!("offset {offset:>9.0}px: {entities} entities");
+    }
+    println!("11 frames in {:?}", start.elapsed());
+}
@@ -0,0 +1,361 @@
+use std::collections::HashSet;
+
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::component::Mutable;
+use bevy_ecs::prelude::*;
+use bevy_text::prelude::*;
+use bevy_time::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::modifier::ModifierView;
+
+/// Timing curves
+pub mod ease;
+/// Blending between property values
+pub mod interpolate;
+/// Appear and disappear transitions
+pub mod transition;
+/// Explicit tweens started from commands
+pub mod tween;
+
+pub mod prelude {
+    pub use super::ease::Ease;
+    pub use super::interpolate::Interpolate;
+    pub use super::transition::{Edge, Exiting, Transition, TransitionExt};
+    pub use super::tween::{
+        ActiveTweens, AnimateExt, Repeat, Tween, TweenCompleted, TweenError, UiProp, UiValue,
+    };
+    pub use super::{Animatable, Animation, AnimationPlugin, AnimationSystems, AnimationView};
+}
+
+use ease::Ease;
+use interpolate::Interpolate;
+use transition::{Transition, despawn_exited, start_appear_transitions};
+use tween::{ActiveTweens, TweenCompleted, advance_tweens};
+
+/// Describes how property changes on a view are animated.
+///
+/// Attach it with [`AnimationView::animation`]. While present, changes to
+/// `BackgroundColor`, `BorderColor`, `BoxShadow`, `TextColor` and the node's
+/// width, height and padding tween from the displayed value to the new one
+/// instead of snapping.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Animation {
+    /// The timing curve
+    pub ease: Ease,
+    /// Duration in seconds
+    pub duration: f32,
+}
+
+impl Animation {
+    /// Creates an animation with the given curve and duration in seconds.
+    pub const fn new(ease: Ease, duration: f32) -> Self {
+        Self { ease, duration }
+    }
+
+    /// Constant speed animation.
+    pub const fn linear(duration: f32) -> Self {
+        Self::new(Ease::Linear, duration)
+    }
+
+    /// Animation that starts slowly.
+    pub const fn ease_in(duration: f32) -> Self {
+        Self::new(Ease::EaseIn, duration)
+    }
+
+    /// Animation that ends slowly.
+    pub const fn ease_out(duration: f32) -> Self {
+        Self::new(Ease::EaseOut, duration)
+    }
+
+    /// Animation that starts and ends slowly.
+    pub const fn ease_in_out(duration: f32) -> Self {
+        Self::new(Ease::EaseInOut, duration)
+    }
+
+    /// Spring animation settling within `duration` seconds.
+    ///
+    /// # Arguments
+    /// * `duration` - Time until the spring has settled.
+    /// * `damping` - Damping ratio, `1.0` for no overshoot, lower values bounce more.
+    pub const fn spring(duration: f32, damping: f32) -> Self {
+        Self::new(Ease::Spring { damping }, duration)
+    }
+}
+
+/// A trait for views whose property changes can be animated.
+pub trait AnimationView: ModifierView {
+    /// Animates subsequent changes to this view's visual properties.
+    ///
+    /// # Arguments
+    /// * `animation` - The curve and duration used for each change.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use swoop_ui::prelude::*;
+    /// HButton::new().animation(Animation::ease_in_out(0.2));
+    /// ```
+    fn animation(self, animation: Animation) -> Self {
+        self.modifier(move |entity| {
+            entity.insert(animation);
+            // Label colors are animated on the label entities themselves
+            let children = entity
+                .get::<Children>()
+                .map(|children| children.to_vec())
+                .unwrap_or_default();
+            entity.world_scope(|world| {
+                for child in children {
+                    if let Ok(mut child) = world.get_entity_mut(child)
+                        && child.contains::<TextColor>()
+                    {
+                        child.insert(animation);
+                    }
+                }
+            });
+        })
+    }
+
+    /// Animates this view in when spawned and out when removed with
+    /// [`despawn_with_transition`](transition::TransitionExt::despawn_with_transition).
+    ///
+    /// # Arguments
+    /// * `transition` - The effects to play, e.g. `Transition::fade()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use swoop_ui::prelude::*;
+    /// VStack::new().transition(Transition::fade().combined(Transition::slide(Edge::Leading)));
+    /// ```
+    fn transition(self, transition: Transition) -> Self {
+        self.modifier(move |entity| {
+            entity.insert(transition.clone());
+            let Some(child_transition) = transition.for_child() else {
+                return;
+            };
+            // Labels fade along with the view, the other effects move them already
+            let children = entity
+                .get::<Children>()
+                .map(|children| children.to_vec())
+                .unwrap_or_default();
+            entity.world_scope(|world| {
+                for child in children {
+                    if let Ok(mut child) = world.get_entity_mut(child)
+                        && child.contains::<TextColor>()
+                    {
+                        child.insert(child_transition.clone());
+                    }
+                }
+            });
+        })
+    }
+}
+
+/// A component whose value can be animated implicitly.
+pub trait Animatable: Component<Mutability = Mutable> {
+    /// The animated part of the component
+    type Value: Interpolate + PartialEq + Send + Sync + 'static;
+
+    /// Reads the animated value.
+    fn read(&self) -> Self::Value;
+
+    /// Writes the animated value.
+    fn write(&mut self, value: Self::Value);
+}
+
+impl Animatable for BackgroundColor {
+    type Value = Color;
+
+    fn read(&self) -> Color {
+        self.0
+    }
+
+    fn write(&mut self, value: Color) {
+        self.0 = value;
+    }
+}
+
+impl Animatable for BorderColor {
+    type Value = Color;
+
+    fn read(&self) -> Color {
+        self.0
+    }
+
+    fn write(&mut self, value: Color) {
+        self.0 = value;
+    }
+}
+
+impl Animatable for TextColor {
+    type Value = Color;
+
+    fn read(&self) -> Color {
+        self.0
+    }
+
+    fn write(&mut self, value: Color) {
+        self.0 = value;
+    }
+}
+
+impl Animatable for BoxShadow {
+    type Value = Vec<ShadowStyle>;
+
+    fn read(&self) -> Vec<ShadowStyle> {
+        self.0.clone()
+    }
+
+    fn write(&mut self, value: Vec<ShadowStyle>) {
+        self.0 = value;
+    }
+}
+
+/// The animated size and padding of a `Node`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodeFrame {
+    pub width: Val,
+    pub height: Val,
+    pub padding: UiRect,
+}
+
+impl Interpolate for NodeFrame {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        NodeFrame {
+            width: self.width.interpolate(&to.width, t),
+            height: self.height.interpolate(&to.height, t),
+            padding: self.padding.interpolate(&to.padding, t),
+        }
+    }
+}
+
+impl Animatable for Node {
+    type Value = NodeFrame;
+
+    fn read(&self) -> NodeFrame {
+        NodeFrame {
+            width: self.width,
+            height: self.height,
+            padding: self.padding,
+        }
+    }
+
+    fn write(&mut self, value: NodeFrame) {
+        self.width = value.width;
+        self.height = value.height;
+        self.padding = value.padding;
+    }
+}
+
+/// Progress of an implicit animation on one component.
+#[derive(Component)]
+struct ImplicitAnimation<T: Animatable> {
+    from: T::Value,
+    to: T::Value,
+    /// The value last written by the animation
+    current: T::Value,
+    elapsed: f32,
+}
+
+impl<T: Animatable> ImplicitAnimation<T> {
+    fn settled(value: T::Value) -> Self {
+        Self {
+            from: value.clone(),
+            to: value.clone(),
+            current: value,
+            elapsed: f32::INFINITY,
+        }
+    }
+}
+
+/// System set containing the animation systems, run in `PostUpdate` before UI layout.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnimationSystems;
+
+/// Drives implicit animations of views carrying an [`Animation`] and explicit tweens
+/// started with [`AnimateExt`](tween::AnimateExt).
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PostUpdate,
+            AnimationSystems.before(bevy_ui::UiSystem::Prepare),
+        )
+        .add_event::<TweenCompleted>()
+        .add_systems(
+            PostUpdate,
+            (
+                start_appear_transitions,
+                advance_tweens,
+                animate_implicit::<BackgroundColor>,
+                animate_implicit::<BorderColor>,
+                animate_implicit::<TextColor>,
+                animate_implicit::<BoxShadow>,
+                animate_implicit::<Node>,
+                despawn_exited,
+            )
+                .chain()
+                .in_set(AnimationSystems),
+        );
+    }
+}
+
+type ImplicitTarget<T> = (
+    Entity,
+    &'static Animation,
+    &'static mut T,
+    Option<&'static mut ImplicitAnimation<T>>,
+    Has<ActiveTweens>,
+);
+
+fn animate_implicit<T: Animatable>(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tweens_ended: RemovedComponents<ActiveTweens>,
+    mut views: Query<ImplicitTarget<T>>,
+) {
+    // The last value written by a tween that just finished is not a change to animate
+    let tweens_ended: HashSet<Entity> = tweens_ended.read().collect();
+    for (entity, animation, mut component, state, tweening) in &mut views {
+        let tweening = tweening || tweens_ended.contains(&entity);
+        let value = component.read();
+        let Some(mut state) = state else {
+            commands
+                .entity(entity)
+                .insert(ImplicitAnimation::<T>::settled(value));
+            continue;
+        };
+
+        if tweening {
+            // Explicit tweens take over; resume implicit animation from wherever they leave off
+            *state = ImplicitAnimation::settled(value);
+            continue;
+        }
+
+        if value != state.current {
+            // Someone else changed the value: animate from what is on screen to the new target
+            state.from = state.current.clone();
+            state.to = value;
+            state.elapsed = 0.0;
+            if animation.duration <= 0.0 {
+                state.current = state.to.clone();
+                state.elapsed = f32::INFINITY;
+                continue;
+            }
+        }
+        if state.elapsed >= animation.duration {
+            continue;
+        }
+
+        state.elapsed += time.delta_secs();
+        let displayed = if state.elapsed >= animation.duration {
+            state.to.clone()
+        } else {
+            let t = animation.ease.sample(state.elapsed / animation.duration);
+            state.from.interpolate(&state.to, t)
+        };
+        component.write(displayed.clone());
+        state.current = displayed;
+    }
+}
@@ -0,0 +1,99 @@
+use std::f32::consts::{FRAC_PI_2, LN_10, PI};
+
+/// Timing curves used to shape animations.
+///
+/// A curve maps linear progress `t` in `0.0..=1.0` to eased progress.
+/// Spring curves may overshoot `1.0` before settling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ease {
+    /// Constant speed
+    Linear,
+    /// Starts slowly and accelerates
+    EaseIn,
+    /// Starts quickly and decelerates
+    EaseOut,
+    /// Accelerates, then decelerates
+    EaseInOut,
+    /// Quadratic acceleration
+    QuadIn,
+    /// Quadratic deceleration
+    QuadOut,
+    /// Quadratic acceleration, then deceleration
+    QuadInOut,
+    /// Cubic acceleration
+    CubicIn,
+    /// Cubic deceleration
+    CubicOut,
+    /// Cubic acceleration, then deceleration
+    CubicInOut,
+    /// Sinusoidal acceleration
+    SineIn,
+    /// Sinusoidal deceleration
+    SineOut,
+    /// Sinusoidal acceleration, then deceleration
+    SineInOut,
+    /// Pulls back slightly before moving forward
+    BackIn,
+    /// Overshoots slightly before settling
+    BackOut,
+    /// A damped spring that settles by the end of the animation.
+    ///
+    /// `damping` is the damping ratio: `1.0` settles without overshoot,
+    /// lower values bounce more.
+    Spring { damping: f32 },
+}
+
+impl Ease {
+    /// Maps linear progress to eased progress.
+    ///
+    /// # Arguments
+    /// * `t` - Linear progress, clamped to `0.0..=1.0`.
+    pub fn sample(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Ease::Linear => t,
+            Ease::EaseIn | Ease::CubicIn => t.powi(3),
+            Ease::EaseOut | Ease::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Ease::EaseInOut | Ease::CubicInOut => in_out(t, 3),
+            Ease::QuadIn => t * t,
+            Ease::QuadOut => 1.0 - (1.0 - t).powi(2),
+            Ease::QuadInOut => in_out(t, 2),
+            Ease::SineIn => 1.0 - (t * FRAC_PI_2).cos(),
+            Ease::SineOut => (t * FRAC_PI_2).sin(),
+            Ease::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
+            Ease::BackIn => BACK_C3 * t.powi(3) - BACK_C1 * t * t,
+            Ease::BackOut => 1.0 + BACK_C3 * (t - 1.0).powi(3) + BACK_C1 * (t - 1.0).powi(2),
+            Ease::Spring { damping } => spring(t, damping),
+        }
+    }
+}
+
+const BACK_C1: f32 = 1.70158;
+const BACK_C3: f32 = BACK_C1 + 1.0;
+
+/// Polynomial ease-in-out of the given degree.
+fn in_out(t: f32, degree: i32) -> f32 {
+    if t < 0.5 {
+        2f32.powi(degree - 1) * t.powi(degree)
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(degree) / 2.0
+    }
+}
+
+/// Step response of a damped harmonic oscillator, scaled so that the
+/// envelope has decayed to 0.1% at `t = 1`.
+fn spring(t: f32, damping: f32) -> f32 {
+    if t >= 1.0 {
+        return 1.0;
+    }
+    let damping = damping.clamp(0.05, 1.0);
+    let decay = 3.0 * LN_10;
+    if damping >= 1.0 {
+        let omega = decay * 1.3;
+        return 1.0 - (-omega * t).exp() * (1.0 + omega * t);
+    }
+    let omega = decay / damping;
+    let omega_d = omega * (1.0 - damping * damping).sqrt();
+    let envelope = (-damping * omega * t).exp();
+    1.0 - envelope * ((omega_d * t).cos() + damping * omega / omega_d * (omega_d * t).sin())
+}
@@ -0,0 +1,91 @@
+use bevy_color::prelude::*;
+use bevy_math::prelude::*;
+use bevy_ui::prelude::*;
+
+/// Values that can be blended between a start and an end point.
+pub trait Interpolate: Clone {
+    /// Returns the value at progress `t`, where `0.0` is `self` and `1.0` is `to`.
+    ///
+    /// `t` may leave `0.0..=1.0` for overshooting curves.
+    fn interpolate(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Interpolate for Vec2 {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self.lerp(*to, t)
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self.mix(to, t)
+    }
+}
+
+impl Interpolate for Val {
+    /// Values of the same unit are blended; mixed units jump straight to `to`.
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (*self, *to) {
+            (Val::Px(a), Val::Px(b)) => Val::Px(a.interpolate(&b, t)),
+            (Val::Percent(a), Val::Percent(b)) => Val::Percent(a.interpolate(&b, t)),
+            (Val::Vw(a), Val::Vw(b)) => Val::Vw(a.interpolate(&b, t)),
+            (Val::Vh(a), Val::Vh(b)) => Val::Vh(a.interpolate(&b, t)),
+            (Val::VMin(a), Val::VMin(b)) => Val::VMin(a.interpolate(&b, t)),
+            (Val::VMax(a), Val::VMax(b)) => Val::VMax(a.interpolate(&b, t)),
+            _ => *to,
+        }
+    }
+}
+
+impl Interpolate for UiRect {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        UiRect {
+            left: self.left.interpolate(&to.left, t),
+            right: self.right.interpolate(&to.right, t),
+            top: self.top.interpolate(&to.top, t),
+            bottom: self.bottom.interpolate(&to.bottom, t),
+        }
+    }
+}
+
+impl Interpolate for BorderRadius {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        BorderRadius {
+            top_left: self.top_left.interpolate(&to.top_left, t),
+            top_right: self.top_right.interpolate(&to.top_right, t),
+            bottom_left: self.bottom_left.interpolate(&to.bottom_left, t),
+            bottom_right: self.bottom_right.interpolate(&to.bottom_right, t),
+        }
+    }
+}
+
+impl Interpolate for ShadowStyle {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        ShadowStyle {
+            color: self.color.interpolate(&to.color, t),
+            x_offset: self.x_offset.interpolate(&to.x_offset, t),
+            y_offset: self.y_offset.interpolate(&to.y_offset, t),
+            spread_radius: self.spread_radius.interpolate(&to.spread_radius, t),
+            blur_radius: self.blur_radius.interpolate(&to.blur_radius, t),
+        }
+    }
+}
+
+impl<T: Interpolate> Interpolate for Vec<T> {
+    /// Lists of equal length are blended element-wise; otherwise jumps to `to`.
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        if self.len() != to.len() {
+            return to.clone();
+        }
+        self.iter()
+            .zip(to)
+            .map(|(from, to)| from.interpolate(to, t))
+            .collect()
+    }
+}
@@ -0,0 +1,264 @@
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+use bevy_ui::prelude::*;
+
+use super::Animation;
+use super::tween::{ActiveTweens, Tween, TweenTarget, TweenTargetItem, UiProp, UiValue};
+
+/// An edge of a view, used to choose the direction of slide transitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    Top,
+    Bottom,
+    /// The left edge
+    Leading,
+    /// The right edge
+    Trailing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Effect {
+    Fade,
+    Move(Edge, Val),
+}
+
+/// How a view animates in when spawned and out before being despawned.
+///
+/// Attach it with [`AnimationView::transition`](super::AnimationView::transition)
+/// and remove views with [`TransitionExt::despawn_with_transition`].
+///
+/// # Example
+/// ```no_run
+/// # use swoop_ui::prelude::*;
+/// VStack::new().transition(Transition::fade().combined(Transition::slide(Edge::Leading)));
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Transition {
+    effects: Vec<Effect>,
+    animation: Animation,
+}
+
+impl Transition {
+    fn from_effect(effect: Effect) -> Self {
+        Self {
+            effects: vec![effect],
+            animation: Animation::ease_in_out(0.25),
+        }
+    }
+
+    /// Fades colors in from transparent.
+    pub fn fade() -> Self {
+        Self::from_effect(Effect::Fade)
+    }
+
+    /// Slides in from the given edge by the full size of the parent.
+    pub fn slide(edge: Edge) -> Self {
+        Self::move_by(edge, Val::Percent(100.0))
+    }
+
+    /// Slides in from the given edge by `distance`.
+    pub fn move_by(edge: Edge, distance: Val) -> Self {
+        Self::from_effect(Effect::Move(edge, distance))
+    }
+
+    /// Plays both transitions at the same time.
+    pub fn combined(mut self, other: Transition) -> Self {
+        self.effects.extend(other.effects);
+        self
+    }
+
+    /// Sets the curve and duration used to animate in and out.
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// Keeps only the effects that make sense on a child of the transitioning view.
+    pub(crate) fn for_child(&self) -> Option<Self> {
+        self.effects.contains(&Effect::Fade).then(|| Self {
+            effects: vec![Effect::Fade],
+            animation: self.animation,
+        })
+    }
+
+    /// Returns `(prop, hidden, shown)` for every property the transition animates.
+    fn keyframes(&self, target: &TweenTargetItem) -> Vec<(UiProp, UiValue, UiValue)> {
+        let mut keyframes = Vec::new();
+        for effect in &self.effects {
+            match *effect {
+                Effect::Fade => {
+                    for prop in [
+                        UiProp::BackgroundColor,
+                        UiProp::BorderColor,
+                        UiProp::TextColor,
+                        UiProp::TextShadowColor,
+                    ] {
+                        if let Some(UiValue::Color(color)) = target.read(prop) {
+                            keyframes.push((prop, color.with_alpha(0.0).into(), color.into()));
+                        }
+                    }
+                }
+                Effect::Move(edge, distance) => {
+                    let (prop, shown, sign) = move_prop(edge, target);
+                    // `Auto` cannot be interpolated, so settle on a zero offset in the same unit
+                    let shown = match shown {
+                        Val::Auto => offset(shown, distance, 0.0),
+                        shown => shown,
+                    };
+                    keyframes.push((prop, offset(shown, distance, sign).into(), shown.into()));
+                }
+            }
+        }
+        keyframes
+    }
+}
+
+/// Picks the position property to move along and the direction of the hidden offset.
+fn move_prop(edge: Edge, target: &TweenTargetItem) -> (UiProp, Val, f32) {
+    let get = |prop| match target.read(prop) {
+        Some(UiValue::Val(val)) => val,
+        _ => Val::Auto,
+    };
+    let (near, far) = match edge {
+        Edge::Leading => (UiProp::Left, UiProp::Right),
+        Edge::Trailing => (UiProp::Right, UiProp::Left),
+        Edge::Top => (UiProp::Top, UiProp::Bottom),
+        Edge::Bottom => (UiProp::Bottom, UiProp::Top),
+    };
+    // Views anchored to the far edge move that offset instead
+    if get(near) == Val::Auto && get(far) != Val::Auto {
+        (far, get(far), 1.0)
+    } else {
+        (near, get(near), -1.0)
+    }
+}
+
+/// Adds `sign * distance` to `val`, treating `Auto` as zero.
+///
+/// Mixed units cannot be added, so such views start `distance` away from zero.
+fn offset(val: Val, distance: Val, sign: f32) -> Val {
+    match (val, distance) {
+        (Val::Px(v), Val::Px(d)) => Val::Px(v + sign * d),
+        (Val::Percent(v), Val::Percent(d)) => Val::Percent(v + sign * d),
+        (Val::Vw(v), Val::Vw(d)) => Val::Vw(v + sign * d),
+        (Val::Vh(v), Val::Vh(d)) => Val::Vh(v + sign * d),
+        (Val::VMin(v), Val::VMin(d)) => Val::VMin(v + sign * d),
+        (Val::VMax(v), Val::VMax(d)) => Val::VMax(v + sign * d),
+        (_, Val::Px(d)) => Val::Px(sign * d),
+        (_, Val::Percent(d)) => Val::Percent(sign * d),
+        (_, Val::Vw(d)) => Val::Vw(sign * d),
+        (_, Val::Vh(d)) => Val::Vh(sign * d),
+        (_, Val::VMin(d)) => Val::VMin(sign * d),
+        (_, Val::VMax(d)) => Val::VMax(sign * d),
+        (val, Val::Auto) => val,
+    }
+}
+
+/// Marks a view that is animating out and will be despawned when done.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Exiting {
+    remaining: f32,
+}
+
+/// Extension methods for removing views with their exit transition.
+pub trait TransitionExt {
+    /// Plays the view's [`Transition`] in reverse, then despawns it.
+    ///
+    /// Views without a transition are despawned immediately.
+    fn despawn_with_transition(&mut self);
+}
+
+impl TransitionExt for EntityCommands<'_> {
+    fn despawn_with_transition(&mut self) {
+        self.queue(|mut entity: EntityWorldMut| {
+            if entity.contains::<Exiting>() {
+                return;
+            }
+            let Some(transition) = entity.get::<Transition>().cloned() else {
+                entity.despawn();
+                return;
+            };
+
+            let root = entity.id();
+            let mut targets = vec![root];
+            entity.world_scope(|world| {
+                let mut index = 0;
+                while index < targets.len() {
+                    if let Some(children) = world.get::<Children>(targets[index]) {
+                        targets.extend(children.iter());
+                    }
+                    index += 1;
+                }
+                let mut query = world.query::<(TweenTarget, Option<&Transition>)>();
+                let mut tweens = Vec::new();
+                for target in &targets {
+                    let Ok((item, Some(transition))) = query.get_mut(world, *target) else {
+                        continue;
+                    };
+                    tweens.push((*target, exit_tweens(transition, &item)));
+                }
+                for (target, exit) in tweens {
+                    start_tweens(world.entity_mut(target), exit);
+                }
+            });
+            entity.insert(Exiting {
+                remaining: transition.animation.duration,
+            });
+        });
+    }
+}
+
+fn exit_tweens(transition: &Transition, target: &TweenTargetItem) -> Vec<Tween> {
+    let Animation { ease, duration } = transition.animation;
+    transition
+        .keyframes(target)
+        .into_iter()
+        .filter_map(|(prop, hidden, _)| Tween::new(prop, hidden, duration, ease).ok())
+        .collect()
+}
+
+fn start_tweens(mut entity: EntityWorldMut, tweens: Vec<Tween>) {
+    if tweens.is_empty() {
+        return;
+    }
+    if !entity.contains::<ActiveTweens>() {
+        entity.insert(ActiveTweens::default());
+    }
+    let mut active = entity.get_mut::<ActiveTweens>().unwrap();
+    for tween in tweens {
+        active.start(tween);
+    }
+}
+
+pub(crate) fn start_appear_transitions(
+    mut commands: Commands,
+    mut views: Query<(Entity, &Transition, TweenTarget), Added<Transition>>,
+) {
+    for (entity, transition, mut target) in &mut views {
+        let Animation { ease, duration } = transition.animation;
+        let mut tweens = Vec::new();
+        for (prop, hidden, shown) in transition.keyframes(&target) {
+            target.write(prop, hidden);
+            tweens.extend(Tween::new(prop, shown, duration, ease).ok());
+        }
+        commands
+            .entity(entity)
+            .queue(move |entity: EntityWorldMut| {
+                start_tweens(entity, tweens);
+            });
+    }
+}
+
+pub(crate) fn despawn_exited(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut exiting: Query<(Entity, &mut Exiting)>,
+) {
+    for (entity, mut exiting) in &mut exiting {
+        exiting.remaining -= time.delta_secs();
+        if exiting.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,550 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::query::QueryData;
+use bevy_math::prelude::*;
+use bevy_text::prelude::*;
+use bevy_time::prelude::*;
+use bevy_ui::prelude::*;
+
+use super::ease::Ease;
+use super::interpolate::Interpolate;
+
+/// A UI property that can be tweened explicitly.
+///
+/// Covers the values set by `View`, `PositionView`, `BackgroundView` and `TextShadowView`.
+///
+/// `Top`, `Left`, `Right` and `Bottom` tween the offsets alone and keep the
+/// node's `PositionType`, so a relatively positioned node is nudged from its
+/// place in the layout. Use `PositionView` first to animate an absolute position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UiProp {
+    Width,
+    Height,
+    MinWidth,
+    MaxWidth,
+    MinHeight,
+    MaxHeight,
+    Padding,
+    FlexGrow,
+    FlexShrink,
+    Top,
+    Left,
+    Right,
+    Bottom,
+    BackgroundColor,
+    BorderColor,
+    TextColor,
+    TextShadowOffset,
+    TextShadowColor,
+}
+
+impl UiProp {
+    /// Returns whether `value` is of the kind this property holds.
+    pub fn accepts(self, value: &UiValue) -> bool {
+        matches!(
+            (self, value),
+            (
+                UiProp::Width
+                    | UiProp::Height
+                    | UiProp::MinWidth
+                    | UiProp::MaxWidth
+                    | UiProp::MinHeight
+                    | UiProp::MaxHeight
+                    | UiProp::Top
+                    | UiProp::Left
+                    | UiProp::Right
+                    | UiProp::Bottom,
+                UiValue::Val(_),
+            ) | (UiProp::Padding, UiValue::Rect(_))
+                | (UiProp::FlexGrow | UiProp::FlexShrink, UiValue::Float(_))
+                | (UiProp::TextShadowOffset, UiValue::Vec2(_))
+                | (
+                    UiProp::BackgroundColor
+                        | UiProp::BorderColor
+                        | UiProp::TextColor
+                        | UiProp::TextShadowColor,
+                    UiValue::Color(_),
+                )
+        )
+    }
+}
+
+/// Returned by [`Tween::new`] when the value is not of the kind the property holds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TweenError {
+    /// The property that was to be tweened
+    pub prop: UiProp,
+    /// The rejected target value
+    pub value: UiValue,
+}
+
+impl fmt::Display for TweenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot tween {:?} to {:?}, the value is of the wrong kind",
+            self.prop, self.value
+        )
+    }
+}
+
+impl std::error::Error for TweenError {}
+
+/// A value of a [`UiProp`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UiValue {
+    Val(Val),
+    Rect(UiRect),
+    Float(f32),
+    Vec2(Vec2),
+    Color(Color),
+}
+
+impl From<Val> for UiValue {
+    fn from(value: Val) -> Self {
+        UiValue::Val(value)
+    }
+}
+
+impl From<UiRect> for UiValue {
+    fn from(value: UiRect) -> Self {
+        UiValue::Rect(value)
+    }
+}
+
+impl From<f32> for UiValue {
+    fn from(value: f32) -> Self {
+        UiValue::Float(value)
+    }
+}
+
+impl From<Vec2> for UiValue {
+    fn from(value: Vec2) -> Self {
+        UiValue::Vec2(value)
+    }
+}
+
+impl From<Color> for UiValue {
+    fn from(value: Color) -> Self {
+        UiValue::Color(value)
+    }
+}
+
+impl Interpolate for UiValue {
+    /// Values of different kinds jump straight to `to`.
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        match (self, to) {
+            (UiValue::Val(a), UiValue::Val(b)) => UiValue::Val(a.interpolate(b, t)),
+            (UiValue::Rect(a), UiValue::Rect(b)) => UiValue::Rect(a.interpolate(b, t)),
+            (UiValue::Float(a), UiValue::Float(b)) => UiValue::Float(a.interpolate(b, t)),
+            (UiValue::Vec2(a), UiValue::Vec2(b)) => UiValue::Vec2(a.interpolate(b, t)),
+            (UiValue::Color(a), UiValue::Color(b)) => UiValue::Color(a.interpolate(b, t)),
+            _ => *to,
+        }
+    }
+}
+
+/// How often a tween plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Repeat {
+    /// Plays once
+    #[default]
+    Once,
+    /// Plays the given number of times
+    Times(u32),
+    /// Plays until stopped
+    Forever,
+}
+
+/// An explicit animation of one property towards a target value.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_color::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # fn open(mut commands: Commands, menu: Entity) -> Result {
+/// commands.entity(menu).tween(
+///     Tween::new(UiProp::Left, Val::Px(0.0), 0.3, Ease::CubicOut)?
+///         .then(Tween::new(UiProp::BackgroundColor, Color::WHITE, 0.2, Ease::Linear)?),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tween {
+    prop: UiProp,
+    to: UiValue,
+    duration: f32,
+    ease: Ease,
+    delay: f32,
+    repeat: Repeat,
+    yoyo: bool,
+    next: Vec<Tween>,
+}
+
+impl Tween {
+    /// Creates a tween of `prop` to `to` over `duration` seconds.
+    ///
+    /// # Errors
+    /// Returns a [`TweenError`] if `to` is not of the kind `prop` holds,
+    /// such as a `Color` for `UiProp::Width`.
+    pub fn new(
+        prop: UiProp,
+        to: impl Into<UiValue>,
+        duration: f32,
+        ease: Ease,
+    ) -> Result<Self, TweenError> {
+        let to = to.into();
+        if !prop.accepts(&to) {
+            return Err(TweenError { prop, value: to });
+        }
+        Ok(Self {
+            prop,
+            to,
+            duration,
+            ease,
+            delay: 0.0,
+            repeat: Repeat::Once,
+            yoyo: false,
+            next: Vec::new(),
+        })
+    }
+
+    /// Waits `seconds` before starting.
+    pub fn delay(mut self, seconds: f32) -> Self {
+        self.delay = seconds;
+        self
+    }
+
+    /// Plays the tween the given number of times.
+    pub fn repeat(mut self, times: u32) -> Self {
+        self.repeat = Repeat::Times(times);
+        self
+    }
+
+    /// Plays the tween until stopped.
+    pub fn looping(mut self) -> Self {
+        self.repeat = Repeat::Forever;
+        self
+    }
+
+    /// Returns to the start value at the end of every iteration.
+    pub fn yoyo(mut self) -> Self {
+        self.yoyo = true;
+        self
+    }
+
+    /// Starts `next` once this tween (and everything chained before) has completed.
+    pub fn then(mut self, next: Tween) -> Self {
+        self.next.push(next);
+        self
+    }
+
+    /// Returns the animated property.
+    pub fn prop(&self) -> UiProp {
+        self.prop
+    }
+}
+
+/// Sent when a tween has finished all of its iterations.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TweenCompleted {
+    /// The animated entity
+    pub entity: Entity,
+    /// The property that finished animating
+    pub prop: UiProp,
+}
+
+/// Tweens currently playing on an entity, removed once all have completed.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ActiveTweens(Vec<ActiveTween>);
+
+impl ActiveTweens {
+    /// Returns `true` if a tween of `prop` is playing.
+    pub fn is_animating(&self, prop: UiProp) -> bool {
+        self.0.iter().any(|active| active.tween.prop == prop)
+    }
+
+    pub(crate) fn start(&mut self, mut tween: Tween) {
+        // A new tween replaces whatever is animating the same property
+        self.0.retain(|active| active.tween.prop != tween.prop);
+        let queue = std::mem::take(&mut tween.next).into();
+        self.0.push(ActiveTween {
+            tween,
+            queue,
+            from: None,
+            elapsed: 0.0,
+            iteration: 0,
+        });
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ActiveTween {
+    tween: Tween,
+    queue: VecDeque<Tween>,
+    /// Captured when the delay has elapsed
+    from: Option<UiValue>,
+    elapsed: f32,
+    iteration: u32,
+}
+
+/// Extension methods for starting tweens with `Commands`.
+pub trait AnimateExt {
+    /// Tweens `prop` to `to` over `duration` seconds.
+    ///
+    /// # Errors
+    /// Returns a [`TweenError`] if `to` is not of the kind `prop` holds.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ui::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # fn expand(mut commands: Commands, panel: Entity) -> Result {
+    /// commands.entity(panel).animate(UiProp::Width, Val::Px(300.0), 0.5, Ease::CubicOut)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn animate(
+        &mut self,
+        prop: UiProp,
+        to: impl Into<UiValue>,
+        duration: f32,
+        ease: Ease,
+    ) -> Result<&mut Self, TweenError>;
+
+    /// Starts a configured tween, including anything chained with [`Tween::then`].
+    fn tween(&mut self, tween: Tween) -> &mut Self;
+
+    /// Stops all tweens, leaving properties at their current values.
+    fn stop_tweens(&mut self) -> &mut Self;
+}
+
+impl AnimateExt for EntityCommands<'_> {
+    fn animate(
+        &mut self,
+        prop: UiProp,
+        to: impl Into<UiValue>,
+        duration: f32,
+        ease: Ease,
+    ) -> Result<&mut Self, TweenError> {
+        Ok(self.tween(Tween::new(prop, to, duration, ease)?))
+    }
+
+    fn tween(&mut self, tween: Tween) -> &mut Self {
+        self.queue(
+            move |mut entity: EntityWorldMut| match entity.get_mut::<ActiveTweens>() {
+                Some(mut active) => active.start(tween),
+                None => {
+                    let mut active = ActiveTweens::default();
+                    active.start(tween);
+                    entity.insert(active);
+                }
+            },
+        )
+    }
+
+    fn stop_tweens(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            entity.remove::<ActiveTweens>();
+        })
+    }
+}
+
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub(crate) struct TweenTarget {
+    node: Option<&'static mut Node>,
+    background: Option<&'static mut BackgroundColor>,
+    border: Option<&'static mut BorderColor>,
+    text_color: Option<&'static mut TextColor>,
+    text_shadow: Option<&'static mut TextShadow>,
+}
+
+impl TweenTargetItem<'_> {
+    pub(crate) fn read(&self, prop: UiProp) -> Option<UiValue> {
+        let node = self.node.as_deref();
+        Some(match prop {
+            UiProp::Width => node?.width.into(),
+            UiProp::Height => node?.height.into(),
+            UiProp::MinWidth => node?.min_width.into(),
+            UiProp::MaxWidth => node?.max_width.into(),
+            UiProp::MinHeight => node?.min_height.into(),
+            UiProp::MaxHeight => node?.max_height.into(),
+            UiProp::Padding => node?.padding.into(),
+            UiProp::FlexGrow => node?.flex_grow.into(),
+            UiProp::FlexShrink => node?.flex_shrink.into(),
+            UiProp::Top => node?.top.into(),
+            UiProp::Left => node?.left.into(),
+            UiProp::Right => node?.right.into(),
+            UiProp::Bottom => node?.bottom.into(),
+            UiProp::BackgroundColor => self.background.as_deref()?.0.into(),
+            UiProp::BorderColor => self.border.as_deref()?.0.into(),
+            UiProp::TextColor => self.text_color.as_deref()?.0.into(),
+            UiProp::TextShadowOffset => self.text_shadow.as_deref()?.offset.into(),
+            UiProp::TextShadowColor => self.text_shadow.as_deref()?.color.into(),
+        })
+    }
+
+    /// Writes a value, which [`Tween::new`] checked is of the kind `prop` holds.
+    pub(crate) fn write(&mut self, prop: UiProp, value: UiValue) {
+        match (prop, value) {
+            (UiProp::Padding, UiValue::Rect(rect)) => {
+                if let Some(node) = self.node.as_mut() {
+                    node.padding = rect;
+                }
+            }
+            (UiProp::FlexGrow, UiValue::Float(grow)) => {
+                if let Some(node) = self.node.as_mut() {
+                    node.flex_grow = grow;
+                }
+            }
+            (UiProp::FlexShrink, UiValue::Float(shrink)) => {
+                if let Some(node) = self.node.as_mut() {
+                    node.flex_shrink = shrink;
+                }
+            }
+            (UiProp::BackgroundColor, UiValue::Color(color)) => {
+                if let Some(background) = self.background.as_mut() {
+                    background.0 = color;
+                }
+            }
+            (UiProp::BorderColor, UiValue::Color(color)) => {
+                if let Some(border) = self.border.as_mut() {
+                    border.0 = color;
+                }
+            }
+            (UiProp::TextColor, UiValue::Color(color)) => {
+                if let Some(text_color) = self.text_color.as_mut() {
+                    text_color.0 = color;
+                }
+            }
+            (UiProp::TextShadowOffset, UiValue::Vec2(offset)) => {
+                if let Some(shadow) = self.text_shadow.as_mut() {
+                    shadow.offset = offset;
+                }
+            }
+            (UiProp::TextShadowColor, UiValue::Color(color)) => {
+                if let Some(shadow) = self.text_shadow.as_mut() {
+                    shadow.color = color;
+                }
+            }
+            (prop, UiValue::Val(val)) => {
+                let Some(node) = self.node.as_mut() else {
+                    return;
+                };
+                let field = match prop {
+                    UiProp::Width => &mut node.width,
+                    UiProp::Height => &mut node.height,
+                    UiProp::MinWidth => &mut node.min_width,
+                    UiProp::MaxWidth => &mut node.max_width,
+                    UiProp::MinHeight => &mut node.min_height,
+                    UiProp::MaxHeight => &mut node.max_height,
+                    UiProp::Top => &mut node.top,
+                    UiProp::Left => &mut node.left,
+                    UiProp::Right => &mut node.right,
+                    UiProp::Bottom => &mut node.bottom,
+                    _ => return,
+                };
+                *field = val;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub(crate) fn advance_tweens(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut completed: EventWriter<TweenCompleted>,
+    mut tweens: Query<(Entity, &mut ActiveTweens, TweenTarget)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut active, mut target) in &mut tweens {
+        let mut finished = Vec::new();
+        let mut chained = Vec::new();
+        for (index, state) in active.0.iter_mut().enumerate() {
+            state.elapsed += delta;
+            if state.elapsed < state.tween.delay {
+                continue;
+            }
+            let prop = state.tween.prop;
+            let Some(from) = state.from.or_else(|| target.read(prop)) else {
+                finished.push(index);
+                continue;
+            };
+            state.from = Some(from);
+
+            let duration = state.tween.duration.max(f32::EPSILON);
+            let progress = (state.elapsed - state.tween.delay) / duration;
+            let done = progress >= 1.0;
+            let t = state.tween.ease.sample(progress.min(1.0));
+            let t = match (state.tween.yoyo, done) {
+                (true, true) => 0.0,
+                (true, false) => 1.0 - (2.0 * t - 1.0).abs(),
+                (false, true) => 1.0,
+                (false, false) => t,
+            };
+            target.write(prop, from.interpolate(&state.tween.to, t));
+            if !done {
+                continue;
+            }
+
+            state.iteration += 1;
+            let replay = match state.tween.repeat {
+                Repeat::Once => false,
+                Repeat::Times(times) => state.iteration < times,
+                Repeat::Forever => true,
+            };
+            if replay {
+                // Iterations after the first start from the same value as the first one
+                state.elapsed = state.tween.delay;
+                continue;
+            }
+
+            completed.write(TweenCompleted { entity, prop });
+            match state.queue.pop_front() {
+                Some(mut next) => {
+                    let queue = std::mem::take(&mut state.queue);
+                    let mut rest: VecDeque<Tween> = std::mem::take(&mut next.next).into();
+                    rest.extend(queue);
+                    *state = ActiveTween {
+                        tween: next,
+                        queue: rest,
+                        from: None,
+                        elapsed: 0.0,
+                        iteration: 0,
+                    };
+                    chained.push(index);
+                }
+                None => finished.push(index),
+            }
+        }
+
+        // Like `start`, a chained tween replaces whatever else animates its property
+        for index in chained {
+            if finished.contains(&index) {
+                continue;
+            }
+            let prop = active.0[index].tween.prop;
+            for (other, state) in active.0.iter().enumerate() {
+                if other != index && state.tween.prop == prop && !finished.contains(&other) {
+                    finished.push(other);
+                }
+            }
+        }
+        finished.sort_unstable();
+        for index in finished.into_iter().rev() {
+            active.0.remove(index);
+        }
+        if active.0.is_empty() {
+            commands.entity(entity).remove::<ActiveTweens>();
+        }
+    }
+}
@@ -49,4 +49,12 @@ impl BackgroundStyle {
             image: ImageNode::default(),
         }
     }
+
+    /// A solid color background without an image.
+    pub fn solid(color: impl Into<Color>) -> Self {
+        Self {
+            color: BackgroundColor(color.into()),
+            image: ImageNode::default(),
+        }
+    }
 }
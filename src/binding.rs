@@ -0,0 +1,400 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::component::Tick;
+use bevy_ecs::prelude::*;
+use bevy_ecs::query::QuerySingleError;
+use bevy_log::warn;
+use bevy_text::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::animation::AnimationSystems;
+use crate::modifier::ModifierView;
+
+pub mod prelude {
+    pub use super::{
+        BindSource, BindingPlugin, BindingSource, BindingSystems, BindingView, IntoBindingSource,
+        from_resource,
+    };
+}
+
+/// A value read from the world by a binding.
+pub trait BindingSource<T>: Send + Sync + 'static {
+    /// Reads the value if its source changed since `last_run`.
+    ///
+    /// # Arguments
+    /// * `view` - The bound view entity.
+    /// * `last_run` - When the binding last ran, `None` on its first run.
+    fn poll(&mut self, world: &mut World, view: Entity, last_run: Option<Tick>) -> Option<T>;
+}
+
+/// Conversion of closures into [`BindingSource`]s.
+///
+/// Closures taking a component, such as `|health: &Health| ...`, read it from
+/// the view's [`BindSource`] entity, or from the only entity with that
+/// component. When several entities have the component, the view must name
+/// one with [`BindingView::bind_source`]; otherwise the binding is not updated
+/// and a warning is logged. Use [`from_resource`] to read a resource instead.
+pub trait IntoBindingSource<T, Marker>: Send + Sync + 'static {
+    type Source: BindingSource<T> + Clone;
+
+    fn into_source(self) -> Self::Source;
+}
+
+/// The entity that component bindings of a view read from.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BindSource(pub Entity);
+
+/// Reads a component and maps it to a bound value.
+pub struct ComponentSource<C: Component, F> {
+    read: Arc<F>,
+    query: Option<QueryState<Entity, With<C>>>,
+    /// Set once the ambiguous source warning was logged
+    warned: bool,
+}
+
+impl<C: Component, F> Clone for ComponentSource<C, F> {
+    fn clone(&self) -> Self {
+        Self {
+            read: self.read.clone(),
+            query: None,
+            warned: false,
+        }
+    }
+}
+
+impl<C, F, T> BindingSource<T> for ComponentSource<C, F>
+where
+    C: Component,
+    F: Fn(&C) -> T + Send + Sync + 'static,
+{
+    fn poll(&mut self, world: &mut World, view: Entity, last_run: Option<Tick>) -> Option<T> {
+        let source = match world.get::<BindSource>(view) {
+            Some(source) => source.0,
+            None => match self
+                .query
+                .get_or_insert_with(|| world.query_filtered())
+                .single(world)
+            {
+                Ok(source) => source,
+                Err(QuerySingleError::MultipleEntities(_)) => {
+                    if !self.warned {
+                        self.warned = true;
+                        warn!(
+                            "binding of {view} reads `{}`, which several entities have; \
+                             choose one with `bind_source`",
+                            std::any::type_name::<C>()
+                        );
+                    }
+                    return None;
+                }
+                Err(QuerySingleError::NoEntities(_)) => return None,
+            },
+        };
+        let component = world.get_entity(source).ok()?.get_ref::<C>()?;
+        let changed = changed_since(component.last_changed(), last_run, world.read_change_tick());
+        changed.then(|| (self.read)(&component))
+    }
+}
+
+/// Marker for closures reading a component.
+pub struct ComponentMarker<C>(PhantomData<C>);
+
+impl<C, F, T> IntoBindingSource<T, ComponentMarker<C>> for F
+where
+    C: Component,
+    F: Fn(&C) -> T + Send + Sync + 'static,
+{
+    type Source = ComponentSource<C, F>;
+
+    fn into_source(self) -> Self::Source {
+        ComponentSource {
+            read: Arc::new(self),
+            query: None,
+            warned: false,
+        }
+    }
+}
+
+/// Reads a resource and maps it to a bound value, created by [`from_resource`].
+pub struct ResourceSource<R: Resource, F> {
+    read: Arc<F>,
+    resource: PhantomData<fn(&R)>,
+}
+
+impl<R: Resource, F> Clone for ResourceSource<R, F> {
+    fn clone(&self) -> Self {
+        Self {
+            read: self.read.clone(),
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<R, F, T> BindingSource<T> for ResourceSource<R, F>
+where
+    R: Resource,
+    F: Fn(&R) -> T + Send + Sync + 'static,
+{
+    fn poll(&mut self, world: &mut World, _view: Entity, last_run: Option<Tick>) -> Option<T> {
+        let resource = world.get_resource_ref::<R>()?;
+        let changed = changed_since(resource.last_changed(), last_run, world.read_change_tick());
+        changed.then(|| (self.read)(&resource))
+    }
+}
+
+impl<R, F, T> IntoBindingSource<T, ()> for ResourceSource<R, F>
+where
+    R: Resource,
+    F: Fn(&R) -> T + Send + Sync + 'static,
+{
+    type Source = Self;
+
+    fn into_source(self) -> Self {
+        self
+    }
+}
+
+/// Binds to a resource instead of a component.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Resource)]
+/// # struct Score(u32);
+/// SText::new().bind_text(from_resource(|score: &Score| format!("Score: {}", score.0)));
+/// ```
+pub fn from_resource<R, F, T>(read: F) -> ResourceSource<R, F>
+where
+    R: Resource,
+    F: Fn(&R) -> T + Send + Sync + 'static,
+{
+    ResourceSource {
+        read: Arc::new(read),
+        resource: PhantomData,
+    }
+}
+
+/// Returns whether a change at `changed` happened after `last_run`, always true on the first run.
+pub(crate) fn changed_since(changed: Tick, last_run: Option<Tick>, this_run: Tick) -> bool {
+    last_run.is_none_or(|last_run| changed.is_newer_than(last_run, this_run))
+}
+
+type Binding = Box<dyn FnMut(&mut World, Entity, Option<Tick>) + Send + Sync>;
+
+type WriteBack = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// Writes to bound data queued while bindings run, applied once all of them ran.
+#[derive(Resource, Default)]
+struct WriteBacks(Vec<WriteBack>);
+
+/// Queues a write to data bound by an input view.
+///
+/// Writes are applied after every binding was polled and with a newer change tick,
+/// so bindings that already ran this frame still see them on the next one.
+pub(crate) fn write_back(
+    world: &mut World,
+    write: impl FnOnce(&mut World) + Send + Sync + 'static,
+) {
+    world
+        .get_resource_or_init::<WriteBacks>()
+        .0
+        .push(Box::new(write));
+}
+
+/// Bindings attached to a view, updated by [`BindingPlugin`].
+#[derive(Component, Default)]
+pub struct Bindings {
+    bindings: Vec<Binding>,
+    last_run: Option<Tick>,
+}
+
+impl Bindings {
+    /// Adds a binding to `entity`, run every frame with the tick of its previous run.
+    pub(crate) fn attach(
+        entity: &mut EntityWorldMut,
+        binding: impl FnMut(&mut World, Entity, Option<Tick>) + Send + Sync + 'static,
+    ) {
+        if !entity.contains::<Bindings>() {
+            entity.insert(Bindings::default());
+        }
+        let mut bindings = entity.get_mut::<Bindings>().unwrap();
+        bindings.bindings.push(Box::new(binding));
+        // Run the new binding once even if the others are up to date
+        bindings.last_run = None;
+    }
+}
+
+/// A trait for views whose properties can follow ECS data.
+///
+/// Bound properties are only written when the source component or resource
+/// changes, so the view can still be edited in between.
+pub trait BindingView: ModifierView {
+    /// Binds a property of this view to a component or resource.
+    ///
+    /// # Arguments
+    /// * `source` - Reads the value, e.g. `|health: &Health| health.0`.
+    /// * `apply` - Writes the value to the spawned view.
+    fn bind<T: 'static, M>(
+        self,
+        source: impl IntoBindingSource<T, M>,
+        apply: impl Fn(&mut EntityWorldMut, T) + Send + Sync + 'static,
+    ) -> Self {
+        let source = source.into_source();
+        let apply = Arc::new(apply);
+        self.modifier(move |entity| {
+            let mut source = source.clone();
+            let apply = apply.clone();
+            Bindings::attach(entity, move |world, view, last_run| {
+                let Some(value) = source.poll(world, view, last_run) else {
+                    return;
+                };
+                if let Ok(mut entity) = world.get_entity_mut(view) {
+                    apply(&mut entity, value);
+                }
+            });
+        })
+    }
+
+    /// Reads component bindings from `entity` instead of the only entity with the component.
+    ///
+    /// Required when several entities have a bound component.
+    fn bind_source(self, entity: Entity) -> Self {
+        self.modifier(move |view| {
+            view.insert(BindSource(entity));
+        })
+    }
+
+    /// Binds the displayed text.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// SText::new().bind_text(|player: &Health| format!("{} HP", player.0));
+    /// ```
+    fn bind_text<M>(self, source: impl IntoBindingSource<String, M>) -> Self {
+        self.bind(source, |entity, value| {
+            for_each_text(entity, |entity| {
+                if let Some(mut text) = entity.get_mut::<Text>() {
+                    text.0.clone_from(&value);
+                }
+            });
+        })
+    }
+
+    /// Binds the text color.
+    fn bind_text_color<M>(self, source: impl IntoBindingSource<Color, M>) -> Self {
+        self.bind(source, |entity, value| {
+            for_each_text(entity, |entity| {
+                if let Some(mut color) = entity.get_mut::<TextColor>() {
+                    color.0 = value;
+                }
+            });
+        })
+    }
+
+    /// Binds whether the view is shown, hiding it with `Display::None`.
+    fn bind_visible<M>(self, source: impl IntoBindingSource<bool, M>) -> Self {
+        self.bind(source, set_visible)
+    }
+
+    /// Binds the width of the view.
+    fn bind_width<M>(self, source: impl IntoBindingSource<Val, M>) -> Self {
+        self.bind(source, |entity, width| {
+            if let Some(mut node) = entity.get_mut::<Node>() {
+                node.width = width;
+            }
+        })
+    }
+}
+
+/// The display mode of a view hidden by [`set_visible`].
+#[derive(Component)]
+struct ShownDisplay(Display);
+
+/// Shows or hides a view with `Display::None`, restoring its display mode when shown again.
+pub(crate) fn set_visible(entity: &mut EntityWorldMut, visible: bool) {
+    if visible {
+        if let Some(shown) = entity.take::<ShownDisplay>()
+            && let Some(mut node) = entity.get_mut::<Node>()
+        {
+            node.display = shown.0;
+        }
+    } else if let Some(display) = entity.get::<Node>().map(|node| node.display)
+        && display != Display::None
+    {
+        entity.insert(ShownDisplay(display));
+        entity.get_mut::<Node>().unwrap().display = Display::None;
+    }
+}
+
+/// Runs `f` on the view if it has text, otherwise on its text children.
+fn for_each_text(entity: &mut EntityWorldMut, mut f: impl FnMut(&mut EntityWorldMut)) {
+    if entity.contains::<Text>() {
+        f(entity);
+        return;
+    }
+    let children = entity
+        .get::<Children>()
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+    entity.world_scope(|world| {
+        for child in children {
+            if let Ok(mut child) = world.get_entity_mut(child)
+                && child.contains::<Text>()
+            {
+                f(&mut child);
+            }
+        }
+    });
+}
+
+/// System set containing binding updates, run in `PostUpdate` before animations.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BindingSystems;
+
+/// Updates view properties bound with [`BindingView`] when their sources change.
+pub struct BindingPlugin;
+
+impl Plugin for BindingPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(PostUpdate, BindingSystems.before(AnimationSystems))
+            .add_systems(PostUpdate, update_bindings.in_set(BindingSystems));
+    }
+}
+
+fn update_bindings(world: &mut World, views: &mut QueryState<Entity, With<Bindings>>) {
+    let views: Vec<Entity> = views.iter(world).collect();
+    for view in views {
+        let Some(mut bindings) = world.get_mut::<Bindings>(view) else {
+            continue;
+        };
+        let mut list = std::mem::take(&mut bindings.bindings);
+        let last_run = bindings.last_run;
+        for binding in &mut list {
+            binding(world, view, last_run);
+        }
+        let this_run = world.change_tick();
+        if let Some(mut bindings) = world.get_mut::<Bindings>(view) {
+            // Bindings added while running are kept after the existing ones
+            list.append(&mut bindings.bindings);
+            bindings.bindings = list;
+            bindings.last_run = Some(this_run);
+        }
+    }
+
+    let Some(mut write_backs) = world.get_resource_mut::<WriteBacks>() else {
+        return;
+    };
+    let write_backs = std::mem::take(&mut write_backs.0);
+    world.increment_change_tick();
+    for write in write_backs {
+        write(world);
+    }
+}
@@ -44,6 +44,22 @@ impl BorderStyle {
             border_color: BorderColor::default(),
         }
     }
+
+    /// Fully rounded ends, as used by toggles and sliders.
+    pub fn capsule() -> Self {
+        Self {
+            border_radius: BorderRadius::MAX,
+            ..Default::default()
+        }
+    }
+
+    /// Slightly rounded corners with a gray outline, as used by window panels.
+    pub fn panel() -> Self {
+        Self {
+            border_radius: BorderRadius::all(Val::Px(6.0)),
+            border_color: BorderColor(Srgba::gray(0.7).into()),
+        }
+    }
 }
 
 impl Default for BorderStyle {
@@ -2,10 +2,15 @@ use bevy_ecs::prelude::*;
 use bevy_ui::prelude::*;
 
 use crate::View;
+use crate::animation::AnimationView;
 use crate::background::BackgroundStyle;
+use crate::binding::BindingView;
 use crate::border::{BorderStyle, BorderView};
+use crate::focus::{FocusView, Focusable};
+use crate::modifier::{ModifierView, ViewModifiers};
 use crate::prelude::{BackgroundView, PositionView, StackView};
 use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
 
 /// A horizontally laid-out button view with customizable border, background, and shadow.
 ///
@@ -38,9 +43,18 @@ pub struct HButton {
     /// Layout node defining size, flex behavior, and spacing.
     node: Node,
 
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
     /// Button marker component used by Bevy's UI interaction system.
     botton: Button,
 
+    /// Keyboard and gamepad focus settings.
+    focusable: Focusable,
+
     /// Style information for borders.
     border: BorderStyle,
 
@@ -64,7 +78,10 @@ impl Default for HButton {
                 column_gap: Val::Px(0.0),
                 ..Default::default()
             },
+            class: ViewClass::new("HButton"),
+            modifiers: ViewModifiers::default(),
             botton: Button,
+            focusable: Focusable::default(),
             border: BorderStyle::button(),
             background: BackgroundStyle::button(),
             shadow: BoxShadow::default(),
@@ -82,6 +99,18 @@ impl View for HButton {
     }
 }
 
+impl ModifierView for HButton {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for HButton {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
 impl StackView for HButton {}
 
 impl BackgroundView for HButton {
@@ -103,3 +132,13 @@ impl BoxShadowView for HButton {
 }
 
 impl PositionView for HButton {}
+
+impl AnimationView for HButton {}
+
+impl FocusView for HButton {
+    fn focusable_node(&mut self) -> &mut Focusable {
+        &mut self.focusable
+    }
+}
+
+impl BindingView for HButton {}
@@ -3,10 +3,15 @@ use bevy_ecs::prelude::*;
 use bevy_math::prelude::*;
 use bevy_ui::prelude::*;
 
+use crate::animation::AnimationView;
 use crate::background::BackgroundStyle;
+use crate::binding::BindingView;
 use crate::border::{BorderStyle, BorderView};
+use crate::focus::{FocusView, Focusable};
+use crate::modifier::{ModifierView, ViewModifiers};
 use crate::prelude::{BackgroundView, PositionView, StackView};
 use crate::shadow::{BoxShadowView, TextShadowView};
+use crate::style::{ClassView, ViewClass};
 use crate::text::{TextStyle, TextView};
 use crate::{View, ViewToBundle};
 
@@ -41,9 +46,18 @@ pub struct TextButton {
     /// Layout node controlling size, flex behavior, and spacing.
     node: Node,
 
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
     /// Bevy's built-in Button marker for interaction detection.
     botton: Button,
 
+    /// Keyboard and gamepad focus settings.
+    focusable: Focusable,
+
     /// Visual styling for borders (widths, colors, radius).
     border: BorderStyle,
 
@@ -72,7 +86,10 @@ impl Default for TextButton {
                 column_gap: Val::Px(0.0),
                 ..Default::default()
             },
+            class: ViewClass::new("TextButton"),
+            modifiers: ViewModifiers::default(),
             botton: Button,
+            focusable: Focusable::default(),
             border: BorderStyle::button(),
             background: BackgroundStyle::button(),
             box_shadow: BoxShadow::default(),
@@ -95,12 +112,27 @@ impl View for TextButton {
     }
 }
 
+impl ModifierView for TextButton {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for TextButton {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
 impl ViewToBundle for TextButton {
     fn pack(self) -> impl Bundle {
         (
             self.name,
             self.node,
+            self.class,
+            self.modifiers,
             self.botton,
+            self.focusable,
             self.border,
             self.background,
             self.box_shadow,
@@ -142,3 +174,13 @@ impl TextShadowView for TextButton {
 }
 
 impl PositionView for TextButton {}
+
+impl AnimationView for TextButton {}
+
+impl FocusView for TextButton {
+    fn focusable_node(&mut self) -> &mut Focusable {
+        &mut self.focusable
+    }
+}
+
+impl BindingView for TextButton {}
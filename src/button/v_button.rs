@@ -2,10 +2,15 @@ use bevy_ecs::prelude::*;
 use bevy_ui::prelude::*;
 
 use crate::View;
+use crate::animation::AnimationView;
 use crate::background::BackgroundStyle;
+use crate::binding::BindingView;
 use crate::border::{BorderStyle, BorderView};
+use crate::focus::{FocusView, Focusable};
+use crate::modifier::{ModifierView, ViewModifiers};
 use crate::prelude::{BackgroundView, PositionView, StackView};
 use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
 
 /// A vertically stacked button view with customizable styling, including border, background,
 /// and box shadow. Suited for UI layouts where label and icon should be stacked top-to-bottom.
@@ -35,9 +40,18 @@ pub struct VButton {
     /// Layout configuration node using vertical flex.
     node: Node,
 
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
     /// Interaction marker enabling Bevy’s UI click behavior.
     botton: Button,
 
+    /// Keyboard and gamepad focus settings.
+    focusable: Focusable,
+
     /// Border visuals such as width, color, and radius.
     border: BorderStyle,
 
@@ -61,7 +75,10 @@ impl Default for VButton {
                 row_gap: Val::Px(0.0),
                 ..Default::default()
             },
+            class: ViewClass::new("VButton"),
+            modifiers: ViewModifiers::default(),
             botton: Button,
+            focusable: Focusable::default(),
             border: BorderStyle::button(),
             background: BackgroundStyle::button(),
             shadow: BoxShadow::default(),
@@ -79,6 +96,18 @@ impl View for VButton {
     }
 }
 
+impl ModifierView for VButton {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for VButton {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
 impl StackView for VButton {}
 
 impl BackgroundView for VButton {
@@ -100,3 +129,13 @@ impl BoxShadowView for VButton {
 }
 
 impl PositionView for VButton {}
+
+impl AnimationView for VButton {}
+
+impl FocusView for VButton {
+    fn focusable_node(&mut self) -> &mut Focusable {
+        &mut self.focusable
+    }
+}
+
+impl BindingView for VButton {}
@@ -1,9 +1,33 @@
+/// Views showing one of several branches
+pub mod conditional;
+/// Collapsible groups and accordions
+pub mod disclosure;
+/// Rows generated from a collection
+pub mod for_each;
 /// Grid layout container
 pub mod grid;
+/// Containers that only spawn their visible rows
+pub mod lazy;
+/// Lists whose rows can be dragged to a new index
+pub mod reorder;
+/// Two panes with a draggable divider
+pub mod split;
 /// Flex layout container
 pub mod stack;
+/// Sortable tables of rows generated from a collection
+pub mod table;
+/// Outlines of nested, expandable rows
+pub mod tree;
 
 pub mod prelude {
+    pub use super::conditional::prelude::*;
+    pub use super::disclosure::prelude::*;
+    pub use super::for_each::{ForEach, ForEachView};
     pub use super::grid::prelude::*;
+    pub use super::lazy::{LazyGridView, LazyLayout, LazyView};
+    pub use super::reorder::{ReorderView, Reorderable, Reordered};
+    pub use super::split::prelude::*;
     pub use super::stack::prelude::*;
+    pub use super::table::{RowSelected, Table, TableColumn, TableSelection, TableSort, TableView};
+    pub use super::tree::{Tree, TreeOptions, TreeState, TreeView};
 }
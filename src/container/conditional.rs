@@ -0,0 +1,332 @@
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+
+use crate::animation::transition::TransitionExt;
+use crate::binding::{BindingSource, Bindings, IntoBindingSource, set_visible};
+use crate::modifier::ModifierView;
+
+/// Shows a branch while a condition holds
+pub mod if_view;
+/// Shows the branch matching a value
+pub mod switch;
+
+pub mod prelude {
+    pub use super::if_view::If;
+    pub use super::switch::Switch;
+    pub use super::{BranchMode, Branches, ConditionalView, IfView, SwitchView};
+}
+
+/// How a conditional view removes branches that are no longer selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BranchMode {
+    /// Despawns the old branch and builds the new one from scratch
+    #[default]
+    Despawn,
+    /// Hides the old branch with `Display::None`, keeping its entities and state
+    Hide,
+}
+
+type BuildBranch = Arc<dyn Fn(&mut World, Entity) -> Entity + Send + Sync>;
+type MatchBranch = Arc<dyn Fn(&dyn Any) -> bool + Send + Sync>;
+
+/// When a branch is selected.
+#[derive(Clone)]
+enum Condition {
+    /// When no other branch is
+    Otherwise,
+    /// When the value of a [`Switch`](switch::Switch) matches
+    Case(MatchBranch),
+    /// While the condition of an [`If`](if_view::If) holds, `None` until it was first read
+    When(Option<bool>),
+}
+
+#[derive(Clone)]
+struct Branch {
+    condition: Condition,
+    build: BuildBranch,
+    spawned: Option<Entity>,
+}
+
+/// The branches of an [`If`](if_view::If) or [`Switch`](switch::Switch) view and the selected one.
+#[derive(Component, Clone, Default)]
+pub struct Branches {
+    branches: Vec<Branch>,
+    selected: Option<usize>,
+    mode: BranchMode,
+}
+
+impl fmt::Debug for Branches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Branches")
+            .field("len", &self.branches.len())
+            .field("selected", &self.selected)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl Branches {
+    /// Returns the index of the selected branch, if any.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Returns the root entity of the selected branch, if spawned.
+    pub fn selected_entity(&self) -> Option<Entity> {
+        self.selected.and_then(|index| self.branches[index].spawned)
+    }
+
+    /// Adds a branch and returns its index.
+    fn push<B: Bundle>(
+        &mut self,
+        condition: Condition,
+        build: impl Fn() -> B + Send + Sync + 'static,
+    ) -> usize {
+        self.branches.push(Branch {
+            condition,
+            build: Arc::new(move |world, view| world.spawn((build(), ChildOf(view))).id()),
+            spawned: None,
+        });
+        self.branches.len() - 1
+    }
+
+    /// Returns the fallback branch, if any.
+    fn otherwise(&self) -> Option<usize> {
+        self.branches
+            .iter()
+            .position(|branch| matches!(branch.condition, Condition::Otherwise))
+    }
+
+    /// Returns the first branch whose `when` condition holds, or the fallback branch.
+    fn first_holding(&self) -> Option<usize> {
+        self.branches
+            .iter()
+            .position(|branch| matches!(branch.condition, Condition::When(Some(true))))
+            .or_else(|| self.otherwise())
+    }
+
+    /// Returns the first `case` branch matching `value`, or the fallback branch.
+    fn first_matching(&self, value: &dyn Any) -> Option<usize> {
+        self.branches
+            .iter()
+            .position(|branch| match &branch.condition {
+                Condition::Case(matches) => matches(value),
+                _ => false,
+            })
+            .or_else(|| self.otherwise())
+    }
+}
+
+/// A trait for views that show one of several branches.
+///
+/// The branch is chosen from a component or resource, read like a
+/// [`BindingView`](crate::binding::BindingView) binding, and only changes when that source changes. Use
+/// `from_resource(|state: &State<GameState>| *state.get())` to follow Bevy
+/// `States`.
+///
+/// Branches are switched by the `BindingPlugin`.
+pub trait ConditionalView: ModifierView {
+    /// Returns a mutable reference to the branches of this view.
+    fn branches_node(&mut self) -> &mut Branches;
+
+    /// Sets how branches that are no longer selected are removed.
+    fn branch_mode(mut self, mode: BranchMode) -> Self {
+        self.branches_node().mode = mode;
+        self
+    }
+
+    /// Keeps unselected branches spawned but hidden, preserving their state.
+    fn keep_hidden(self) -> Self {
+        self.branch_mode(BranchMode::Hide)
+    }
+
+    /// Adds the branch shown when no other branch matches.
+    fn otherwise<B: Bundle>(mut self, build: impl Fn() -> B + Send + Sync + 'static) -> Self {
+        self.branches_node().push(Condition::Otherwise, build);
+        self
+    }
+}
+
+/// A trait for views showing a branch while a condition holds.
+pub trait IfView: ConditionalView {
+    /// Shows `then` while `condition` is true, and the [`otherwise`](ConditionalView::otherwise)
+    /// branch, if any, while it is false.
+    ///
+    /// Like `else if`, further calls add branches checked in order: the first branch
+    /// whose condition holds is shown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # #[derive(Resource)]
+    /// # struct Session {
+    /// #     logged_in: bool,
+    /// #     banned: bool,
+    /// # }
+    /// # fn banned_notice() -> VStack {
+    /// #     VStack::new()
+    /// # }
+    /// # fn profile_menu() -> VStack {
+    /// #     VStack::new()
+    /// # }
+    /// # fn login_menu() -> VStack {
+    /// #     VStack::new()
+    /// # }
+    /// If::default()
+    ///     .when(from_resource(|session: &Session| session.banned), || banned_notice())
+    ///     .when(from_resource(|session: &Session| session.logged_in), || profile_menu())
+    ///     .otherwise(|| login_menu());
+    /// ```
+    fn when<M, B: Bundle>(
+        mut self,
+        condition: impl IntoBindingSource<bool, M>,
+        then: impl Fn() -> B + Send + Sync + 'static,
+    ) -> Self {
+        let index = self.branches_node().push(Condition::When(None), then);
+        let source = condition.into_source();
+        self.modifier(move |entity| {
+            let mut source = source.clone();
+            Bindings::attach(entity, move |world, view, last_run| {
+                let holds = source.poll(world, view, last_run);
+                let Some(mut branches) = world.get_mut::<Branches>(view) else {
+                    return;
+                };
+                if let Some(holds) = holds {
+                    branches.branches[index].condition = Condition::When(Some(holds));
+                }
+                // Conditions are read in order, select once the last one was
+                let last = branches
+                    .branches
+                    .iter()
+                    .rposition(|branch| matches!(branch.condition, Condition::When(_)));
+                let all_read = !branches
+                    .branches
+                    .iter()
+                    .any(|branch| matches!(branch.condition, Condition::When(None)));
+                if last == Some(index) && all_read {
+                    let selected = branches.first_holding();
+                    select_branch(world, view, selected);
+                }
+            });
+        })
+    }
+}
+
+/// A trait for views showing the branch matching a value.
+pub trait SwitchView: ConditionalView {
+    /// Chooses the branch from the value read by `selector`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_state::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    /// # enum MenuPage {
+    /// #     #[default]
+    /// #     Main,
+    /// #     Options,
+    /// # }
+    /// # fn main_menu() -> VStack {
+    /// #     VStack::new()
+    /// # }
+    /// # fn options_menu() -> VStack {
+    /// #     VStack::new()
+    /// # }
+    /// Switch::new()
+    ///     .on(from_resource(|state: &State<MenuPage>| *state.get()))
+    ///     .case(MenuPage::Main, || main_menu())
+    ///     .case(MenuPage::Options, || options_menu())
+    ///     .otherwise(|| VStack::new());
+    /// ```
+    fn on<K, M>(self, selector: impl IntoBindingSource<K, M>) -> Self
+    where
+        K: Send + Sync + 'static,
+    {
+        select_with(self, selector)
+    }
+
+    /// Adds a branch shown while the selected value equals `value`.
+    fn case<K, B>(mut self, value: K, build: impl Fn() -> B + Send + Sync + 'static) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+        B: Bundle,
+    {
+        self.branches_node()
+            .push(Condition::Case(matches(value)), build);
+        self
+    }
+}
+
+fn matches<K: PartialEq + Send + Sync + 'static>(value: K) -> MatchBranch {
+    Arc::new(move |selected| selected.downcast_ref::<K>() == Some(&value))
+}
+
+fn select_with<V: ModifierView, K: Send + Sync + 'static, M>(
+    view: V,
+    selector: impl IntoBindingSource<K, M>,
+) -> V {
+    let source = selector.into_source();
+    view.modifier(move |entity| {
+        let mut source = source.clone();
+        Bindings::attach(entity, move |world, view, last_run| {
+            if let Some(value) = source.poll(world, view, last_run)
+                && let Some(branches) = world.get::<Branches>(view)
+            {
+                let selected = branches.first_matching(&value);
+                select_branch(world, view, selected);
+            }
+        });
+    })
+}
+
+/// Shows the `selected` branch, removing the previously shown one.
+fn select_branch(world: &mut World, view: Entity, selected: Option<usize>) {
+    let Some(mut branches) = world.get_mut::<Branches>(view) else {
+        return;
+    };
+    if selected == branches.selected {
+        return;
+    }
+    let previous = std::mem::replace(&mut branches.selected, selected);
+    let mode = branches.mode;
+
+    if let Some(previous) = previous {
+        let branch = &mut branches.branches[previous];
+        let entity = match mode {
+            BranchMode::Despawn => branch.spawned.take(),
+            BranchMode::Hide => branch.spawned,
+        };
+        if let Some(entity) = entity
+            && world.get_entity(entity).is_ok()
+        {
+            match mode {
+                BranchMode::Despawn => {
+                    world.commands().entity(entity).despawn_with_transition();
+                    world.flush();
+                }
+                BranchMode::Hide => set_visible(&mut world.entity_mut(entity), false),
+            }
+        }
+    }
+
+    let Some(selected) = selected else {
+        return;
+    };
+    let branch = world.get::<Branches>(view).unwrap().branches[selected].clone();
+    match branch.spawned {
+        Some(entity) if world.get_entity(entity).is_ok() => {
+            set_visible(&mut world.entity_mut(entity), true);
+        }
+        _ => {
+            let entity = (branch.build)(world, view);
+            if let Some(mut branches) = world.get_mut::<Branches>(view) {
+                branches.branches[selected].spawned = Some(entity);
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::{BindingView, IntoBindingSource};
+use crate::border::{BorderStyle, BorderView};
+use crate::container::stack::StackView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+use super::{Branches, ConditionalView, IfView};
+
+/// A container showing one branch while a condition holds and another otherwise.
+///
+/// Replaces duplicated menus toggled by hand, see [`IfView`]. Start from
+/// `If::default()` to chain several conditions.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Resource)]
+/// # struct Session {
+/// #     logged_in: bool,
+/// # }
+/// # fn profile_menu() -> VStack {
+/// #     VStack::new()
+/// # }
+/// # fn login_menu() -> VStack {
+/// #     VStack::new()
+/// # }
+/// If::new(
+///     from_resource(|session: &Session| session.logged_in),
+///     || profile_menu(),
+///     || login_menu(),
+/// );
+/// ```
+#[derive(Bundle, Debug, Clone)]
+pub struct If {
+    /// Named "If" in the hierarchy
+    name: Name,
+    /// Column holding the children of the selected branch
+    node: Node,
+    /// Matched by `If` selectors and any added classes
+    class: ViewClass,
+    /// Attaches the `when` bindings on spawn
+    modifiers: ViewModifiers,
+    /// The branches and the selected one
+    branches: Branches,
+    /// Border around the selected branch
+    border: BorderStyle,
+    /// Fill behind the selected branch
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+}
+
+impl Default for If {
+    fn default() -> Self {
+        Self {
+            name: Name::new("If"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                ..Default::default()
+            },
+            class: ViewClass::new("If"),
+            modifiers: ViewModifiers::default(),
+            branches: Branches::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl If {
+    /// Creates a view showing `then` while `condition` is true and `otherwise` while it is false.
+    ///
+    /// # Arguments
+    /// * `condition` - Reads the condition, e.g. `|player: &Health| player.0 > 0`.
+    /// * `then` - Builds the branch shown while the condition holds.
+    /// * `otherwise` - Builds the branch shown while it does not.
+    pub fn new<M, A: Bundle, B: Bundle>(
+        condition: impl IntoBindingSource<bool, M>,
+        then: impl Fn() -> A + Send + Sync + 'static,
+        otherwise: impl Fn() -> B + Send + Sync + 'static,
+    ) -> Self {
+        Self::default().when(condition, then).otherwise(otherwise)
+    }
+}
+
+impl View for If {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for If {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for If {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ConditionalView for If {
+    fn branches_node(&mut self) -> &mut Branches {
+        &mut self.branches
+    }
+}
+
+impl IfView for If {}
+
+impl StackView for If {}
+
+impl BackgroundView for If {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for If {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for If {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for If {}
+
+impl AnimationView for If {}
+
+impl BindingView for If {}
@@ -0,0 +1,115 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
+use crate::border::{BorderStyle, BorderView};
+use crate::container::stack::StackView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+use super::{Branches, ConditionalView, SwitchView};
+
+/// A container showing the branch matching a value, such as the current game state.
+///
+/// See [`SwitchView`].
+#[derive(Bundle, Debug, Clone)]
+pub struct Switch {
+    /// Named "Switch" in the hierarchy
+    name: Name,
+    /// Column holding the children of the matching case
+    node: Node,
+    /// Matched by `Switch` selectors and any added classes
+    class: ViewClass,
+    /// Attaches the binding that picks a case on spawn
+    modifiers: ViewModifiers,
+    /// The branches and the selected one
+    branches: Branches,
+    /// Border around the matching case
+    border: BorderStyle,
+    /// Fill behind the matching case
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+}
+
+impl Default for Switch {
+    fn default() -> Self {
+        Self {
+            name: Name::new("Switch"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                ..Default::default()
+            },
+            class: ViewClass::new("Switch"),
+            modifiers: ViewModifiers::default(),
+            branches: Branches::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl View for Switch {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for Switch {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for Switch {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ConditionalView for Switch {
+    fn branches_node(&mut self) -> &mut Branches {
+        &mut self.branches
+    }
+}
+
+impl SwitchView for Switch {}
+
+impl StackView for Switch {}
+
+impl BackgroundView for Switch {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for Switch {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for Switch {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for Switch {}
+
+impl AnimationView for Switch {}
+
+impl BindingView for Switch {}
@@ -0,0 +1,250 @@
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_text::prelude::*;
+use bevy_ui::ComputedNode;
+use bevy_ui::prelude::*;
+
+use crate::animation::{Animation, AnimationView};
+use crate::background::BackgroundView;
+use crate::border::BorderView;
+use crate::button::text_button::TextButton;
+use crate::container::stack::StackView;
+use crate::container::stack::v_stack::VStack;
+use crate::modifier::ModifierView;
+use crate::text::TextView;
+use crate::{View, ViewToBundle};
+
+/// Groups that keep only one disclosure group expanded
+pub mod accordion;
+/// A header that shows or hides its content
+pub mod disclosure_group;
+
+pub mod prelude {
+    pub use super::accordion::Accordion;
+    pub use super::disclosure_group::DisclosureGroup;
+    pub use super::{Disclosure, DisclosureGroupView};
+}
+
+/// How content is revealed and hidden.
+const EXPAND_ANIMATION: Animation = Animation::ease_out(0.2);
+
+/// Expanded state and header label of a [`DisclosureGroup`](disclosure_group::DisclosureGroup).
+///
+/// Set `expanded` to open or close the group from code.
+#[derive(Component, Debug, Clone, PartialEq, Default)]
+pub struct Disclosure {
+    /// Text of the header button
+    pub label: String,
+    /// Whether the content is shown
+    pub expanded: bool,
+}
+
+/// The header button toggling a disclosure group.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+struct DisclosureHeader {
+    group: Entity,
+}
+
+/// Clips the content of a disclosure group and animates its height.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+struct DisclosureContent {
+    group: Entity,
+    /// The content measured at its natural size
+    inner: Entity,
+    /// Height last requested, in logical pixels
+    target: f32,
+}
+
+/// Marks a container whose disclosure groups close when a sibling group opens.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct SingleExpansion;
+
+/// A trait for views with a header that shows or hides their content.
+pub trait DisclosureGroupView: ModifierView {
+    /// Returns a mutable reference to the expanded state of this view.
+    fn disclosure_node(&mut self) -> &mut Disclosure;
+
+    /// Sets the text of the header button.
+    fn label(mut self, label: impl Into<String>) -> Self {
+        self.disclosure_node().label = label.into();
+        self
+    }
+
+    /// Sets whether the content is initially shown.
+    fn expanded(mut self, expanded: bool) -> Self {
+        self.disclosure_node().expanded = expanded;
+        self
+    }
+
+    /// Sets the content shown below the header while expanded.
+    ///
+    /// # Arguments
+    /// * `build` - Builds the content, laid out in a column.
+    fn content<B: Bundle>(self, build: impl Fn() -> B + Send + Sync + 'static) -> Self {
+        self.modifier(move |entity| {
+            let group = entity.id();
+            let Some(disclosure) = entity.get::<Disclosure>().cloned() else {
+                return;
+            };
+            let mut spawned = None;
+            entity.with_children(|view| {
+                view.spawn((header(&disclosure), DisclosureHeader { group }));
+                let mut body = None;
+                let content = view
+                    .spawn(
+                        VStack::from_name("DisclosureContent")
+                            .align_items(AlignItems::Stretch)
+                            .height(Val::Px(0.0))
+                            .animation(EXPAND_ANIMATION),
+                    )
+                    .with_children(|content| {
+                        let mut inner = content.spawn(
+                            VStack::from_name("DisclosureBody")
+                                .align_items(AlignItems::Stretch)
+                                .flex_shrink(0.0),
+                        );
+                        body = Some(inner.id());
+                        inner.with_child(build());
+                    })
+                    .id();
+                spawned = body.map(|body| (content, body));
+            });
+            let Some((content, body)) = spawned else {
+                return;
+            };
+            entity.world_scope(|world| {
+                if let Ok(mut content) = world.get_entity_mut(content) {
+                    if let Some(mut node) = content.get_mut::<Node>() {
+                        node.overflow = Overflow::clip();
+                    }
+                    content.insert(DisclosureContent {
+                        group,
+                        inner: body,
+                        target: 0.0,
+                    });
+                }
+            });
+        })
+    }
+}
+
+fn header(disclosure: &Disclosure) -> impl Bundle {
+    TextButton::new()
+        .text(header_text(disclosure))
+        .text_color(Color::BLACK)
+        .text_alignment(JustifyText::Left)
+        .justify_content(JustifyContent::Start)
+        .background_color(Color::NONE)
+        .border_radius(BorderRadius::all(Val::Px(4.0)))
+        .padding(UiRect::axes(Val::Px(8.0), Val::Px(6.0)))
+        .pack()
+}
+
+fn header_text(disclosure: &Disclosure) -> String {
+    let chevron = if disclosure.expanded { "▾" } else { "▸" };
+    format!("{chevron} {}", disclosure.label)
+}
+
+/// Opening and closing [`DisclosureGroup`](disclosure_group::DisclosureGroup)s and
+/// [`Accordion`](accordion::Accordion)s.
+///
+/// Content height changes are animated when the `AnimationPlugin` is added.
+pub struct DisclosurePlugin;
+
+impl Plugin for DisclosurePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                press_disclosure_headers,
+                collapse_accordion_siblings,
+                update_disclosure_headers,
+                resize_disclosure_content,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Toggles the group of pressed headers.
+fn press_disclosure_headers(
+    headers: Query<(&Interaction, &DisclosureHeader), Changed<Interaction>>,
+    mut groups: Query<&mut Disclosure>,
+) {
+    for (interaction, header) in &headers {
+        if *interaction == Interaction::Pressed
+            && let Ok(mut disclosure) = groups.get_mut(header.group)
+        {
+            disclosure.expanded = !disclosure.expanded;
+        }
+    }
+}
+
+/// Closes the other groups of an accordion when one of them opens.
+fn collapse_accordion_siblings(
+    mut groups: Query<(Entity, &mut Disclosure, &ChildOf)>,
+    accordions: Query<&Children, With<SingleExpansion>>,
+) {
+    let opened: Vec<(Entity, Entity)> = groups
+        .iter_mut()
+        .filter(|(_, disclosure, _)| disclosure.is_changed() && disclosure.expanded)
+        .map(|(group, _, accordion)| (group, accordion.parent()))
+        .collect();
+    for (group, accordion) in opened {
+        let Ok(siblings) = accordions.get(accordion) else {
+            continue;
+        };
+        for sibling in siblings.iter().filter(|sibling| *sibling != group) {
+            if let Ok((_, mut sibling, _)) = groups.get_mut(sibling)
+                && sibling.expanded
+            {
+                sibling.expanded = false;
+            }
+        }
+    }
+}
+
+/// Keeps header chevrons and labels in sync with their group.
+fn update_disclosure_headers(
+    groups: Query<&Disclosure, Changed<Disclosure>>,
+    headers: Query<(&DisclosureHeader, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    for (header, children) in &headers {
+        let Ok(disclosure) = groups.get(header.group) else {
+            continue;
+        };
+        let mut texts = texts.iter_many_mut(children);
+        while let Some(mut text) = texts.fetch_next() {
+            text.0 = header_text(disclosure);
+        }
+    }
+}
+
+/// Sizes the content of each group to its natural height while expanded, and to nothing
+/// while collapsed.
+fn resize_disclosure_content(
+    groups: Query<&Disclosure>,
+    mut contents: Query<(&mut DisclosureContent, &mut Node)>,
+    measured: Query<&ComputedNode>,
+) {
+    for (mut content, mut node) in &mut contents {
+        let Ok(disclosure) = groups.get(content.group) else {
+            continue;
+        };
+        let target = if disclosure.expanded {
+            measured
+                .get(content.inner)
+                .map(|inner| inner.size().y * inner.inverse_scale_factor())
+                .unwrap_or_default()
+        } else {
+            0.0
+        };
+        // Only new targets are written, so a running animation is not restarted
+        if content.target != target {
+            content.target = target;
+            node.height = Val::Px(target);
+        }
+    }
+}
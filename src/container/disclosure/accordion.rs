@@ -0,0 +1,137 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
+use crate::border::{BorderStyle, BorderView};
+use crate::container::for_each::ForEachView;
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+use crate::container::stack::StackView;
+
+use super::SingleExpansion;
+
+/// A column of [`DisclosureGroup`](super::disclosure_group::DisclosureGroup)s of which
+/// only one is expanded at a time.
+///
+/// Opening a group closes the one that was open before.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # fn video_settings() -> VStack {
+/// #     VStack::new()
+/// # }
+/// # fn audio_settings() -> VStack {
+/// #     VStack::new()
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     Accordion::new(),
+///     children![
+///         DisclosureGroup::new().label("Video").content(video_settings),
+///         DisclosureGroup::new().label("Audio").content(audio_settings),
+///     ],
+/// ));
+/// # }
+/// ```
+#[derive(Bundle, Debug, Clone)]
+pub struct Accordion {
+    /// Named "Accordion" in the hierarchy
+    name: Name,
+    /// Column of disclosure groups without gaps
+    node: Node,
+    /// Matched by `Accordion` selectors and any added classes
+    class: ViewClass,
+    /// Changes applied once the accordion is spawned
+    modifiers: ViewModifiers,
+    /// Border around all groups
+    border: BorderStyle,
+    /// Fill behind the groups
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+    /// Closes the other groups when one opens
+    single_expansion: SingleExpansion,
+}
+
+impl Default for Accordion {
+    fn default() -> Self {
+        Self {
+            name: Name::new("Accordion"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                row_gap: Val::Px(0.0),
+                ..Default::default()
+            },
+            class: ViewClass::new("Accordion"),
+            modifiers: ViewModifiers::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+            single_expansion: SingleExpansion,
+        }
+    }
+}
+
+impl View for Accordion {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for Accordion {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for Accordion {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl StackView for Accordion {}
+
+impl BackgroundView for Accordion {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for Accordion {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for Accordion {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for Accordion {}
+
+impl AnimationView for Accordion {}
+
+impl FocusScopeView for Accordion {}
+
+impl BindingView for Accordion {}
+
+impl ForEachView for Accordion {}
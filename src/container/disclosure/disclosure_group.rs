@@ -0,0 +1,125 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+use super::{Disclosure, DisclosureGroupView};
+
+/// A header button that shows or hides the content below it.
+///
+/// The expanded state lives in the [`Disclosure`] component and can be changed from code.
+/// Opening and closing animate the content height.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ui::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// DisclosureGroup::new()
+///     .label("Audio")
+///     .expanded(true)
+///     .content(|| VStack::new().row_gap(Val::Px(4.0)));
+/// ```
+#[derive(Bundle, Debug, Clone)]
+pub struct DisclosureGroup {
+    /// Named "DisclosureGroup" in the hierarchy
+    name: Name,
+    /// Column with the header above the content
+    node: Node,
+    /// Matched by `DisclosureGroup` selectors and any added classes
+    class: ViewClass,
+    /// Spawns the header and content once the group is spawned
+    modifiers: ViewModifiers,
+    /// Border around the header and content
+    border: BorderStyle,
+    /// Fill behind the header and content
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+    /// Expanded state and header label
+    disclosure: Disclosure,
+}
+
+impl Default for DisclosureGroup {
+    fn default() -> Self {
+        Self {
+            name: Name::new("DisclosureGroup"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                row_gap: Val::Px(0.0),
+                ..Default::default()
+            },
+            class: ViewClass::new("DisclosureGroup"),
+            modifiers: ViewModifiers::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+            disclosure: Disclosure::default(),
+        }
+    }
+}
+
+impl View for DisclosureGroup {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for DisclosureGroup {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for DisclosureGroup {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl DisclosureGroupView for DisclosureGroup {
+    fn disclosure_node(&mut self) -> &mut Disclosure {
+        &mut self.disclosure
+    }
+}
+
+impl BackgroundView for DisclosureGroup {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for DisclosureGroup {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for DisclosureGroup {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for DisclosureGroup {}
+
+impl AnimationView for DisclosureGroup {}
+
+impl FocusScopeView for DisclosureGroup {}
+
+impl BindingView for DisclosureGroup {}
@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::animation::transition::TransitionExt;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::{BindingView, Bindings, changed_since};
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+use super::stack::StackView;
+
+/// A trait for containers whose children are generated from a collection.
+///
+/// Each item is identified by a key. When the collection changes, rows for new
+/// keys are spawned, rows for missing keys are despawned (playing their exit
+/// [`Transition`](crate::animation::transition::Transition), if any) and the
+/// remaining rows are reordered. Rows whose item changed are rebuilt in place and
+/// the others are left untouched, so row entities, animations and focus survive
+/// updates. Only the first item with a given key gets a row.
+///
+/// Rows are updated by the `BindingPlugin`.
+pub trait ForEachView: ModifierView {
+    /// Generates one row per item of a collection stored in a resource.
+    ///
+    /// # Arguments
+    /// * `items` - Returns the collection, e.g. `|inventory: &Inventory| &inventory.items`.
+    /// * `key` - Returns the identity of an item.
+    /// * `row` - Builds the row for an item.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Entry {
+    /// #     player_id: u64,
+    /// #     name: String,
+    /// #     score: u32,
+    /// # }
+    /// # #[derive(Resource)]
+    /// # struct Leaderboard {
+    /// #     entries: Vec<Entry>,
+    /// # }
+    /// ForEach::new().for_each(
+    ///     |board: &Leaderboard| &board.entries,
+    ///     |entry| entry.player_id,
+    ///     |entry| SText::new().text(format!("{} {}", entry.name, entry.score)),
+    /// );
+    /// ```
+    fn for_each<R, T, K, B>(
+        self,
+        items: impl Fn(&R) -> &[T] + Send + Sync + 'static,
+        key: impl Fn(&T) -> K + Send + Sync + 'static,
+        row: impl Fn(&T) -> B + Send + Sync + 'static,
+    ) -> Self
+    where
+        R: Resource,
+        T: Clone + PartialEq + Send + Sync + 'static,
+        K: Clone + Hash + Eq + Send + Sync + 'static,
+        B: Bundle,
+    {
+        let items = Arc::new(items);
+        let key = Arc::new(key);
+        let row = Arc::new(row);
+        self.modifier(move |entity| {
+            let (items, key, row) = (items.clone(), key.clone(), row.clone());
+            let mut rows = HashMap::new();
+            // The item each row was built from
+            let mut built: HashMap<K, T> = HashMap::new();
+            Bindings::attach(entity, move |world, container, last_run| {
+                let this_run = world.read_change_tick();
+                let Some(resource) = world.get_resource_ref::<R>() else {
+                    return;
+                };
+                if !changed_since(resource.last_changed(), last_run, this_run) {
+                    return;
+                }
+                let mut items_now = HashMap::new();
+                let list = items(&resource)
+                    .iter()
+                    .map(|item| {
+                        let key = key(item);
+                        let stale = built.get(&key) != Some(item);
+                        let bundle = (stale || needs_row(world, &rows, &key)).then(|| row(item));
+                        items_now.entry(key.clone()).or_insert_with(|| item.clone());
+                        (key, bundle)
+                    })
+                    .collect();
+                built = items_now;
+                update_rows(world, container, &mut rows, list);
+            });
+        })
+    }
+
+    /// Generates one row per entity with component `C`, sorted by key.
+    ///
+    /// # Arguments
+    /// * `key` - Returns the identity and sort order of an entity's row.
+    /// * `row` - Builds the row for an entity.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Server {
+    /// #     name: String,
+    /// # }
+    /// ForEach::new().for_each_component(
+    ///     |_, server: &Server| server.name.clone(),
+    ///     |_, server: &Server| TextButton::new().text(&server.name).pack(),
+    /// );
+    /// ```
+    fn for_each_component<C, K, B>(
+        self,
+        key: impl Fn(Entity, &C) -> K + Send + Sync + 'static,
+        row: impl Fn(Entity, &C) -> B + Send + Sync + 'static,
+    ) -> Self
+    where
+        C: Component,
+        K: Hash + Ord + Send + Sync + 'static,
+        B: Bundle,
+    {
+        let key = Arc::new(key);
+        let row = Arc::new(row);
+        self.modifier(move |entity| {
+            let (key, row) = (key.clone(), row.clone());
+            let mut rows = HashMap::new();
+            let mut last_count = 0;
+            let mut query: Option<QueryState<(Entity, Ref<C>)>> = None;
+            Bindings::attach(entity, move |world, container, last_run| {
+                let this_run = world.read_change_tick();
+                let query = query.get_or_insert_with(|| world.query());
+                // Added or changed components show up as changes, removals as a different count
+                let count = query.iter(world).count();
+                let changed = count != last_count
+                    || query.iter(world).any(|(_, component)| {
+                        changed_since(component.last_changed(), last_run, this_run)
+                    });
+                last_count = count;
+                if !changed {
+                    return;
+                }
+                let mut list: Vec<_> = query
+                    .iter(world)
+                    .map(|(entity, component)| {
+                        let key = key(entity, &component);
+                        let stale = changed_since(component.last_changed(), last_run, this_run);
+                        let bundle = (stale || needs_row(world, &rows, &key))
+                            .then(|| row(entity, &component));
+                        (key, bundle)
+                    })
+                    .collect();
+                list.sort_by(|(a, _), (b, _)| a.cmp(b));
+                update_rows(world, container, &mut rows, list);
+            });
+        })
+    }
+}
+
+/// Returns whether `key` has no row yet, or its row was despawned elsewhere.
+fn needs_row<K: Hash + Eq>(world: &World, rows: &HashMap<K, Entity>, key: &K) -> bool {
+    rows.get(key)
+        .is_none_or(|entity| world.get_entity(*entity).is_err())
+}
+
+/// Spawns, rebuilds, despawns and reorders rows to match `list`, which holds a bundle
+/// for every key whose row must be built.
+fn update_rows<K: Hash + Eq, B: Bundle>(
+    world: &mut World,
+    container: Entity,
+    rows: &mut HashMap<K, Entity>,
+    list: Vec<(K, Option<B>)>,
+) {
+    let mut order = Vec::with_capacity(list.len());
+    let mut kept = HashMap::with_capacity(list.len());
+    for (key, bundle) in list {
+        if kept.contains_key(&key) {
+            // Only the first item with a key gets a row
+            continue;
+        }
+        let entity = match (rows.remove(&key), bundle) {
+            (Some(entity), Some(bundle)) if world.get_entity(entity).is_ok() => {
+                rebuild_row(&mut world.entity_mut(entity), bundle);
+                entity
+            }
+            (_, Some(bundle)) => world.spawn((bundle, ChildOf(container))).id(),
+            (Some(entity), None) => entity,
+            (None, None) => continue,
+        };
+        order.push(entity);
+        kept.insert(key, entity);
+    }
+
+    for (_, entity) in rows.drain() {
+        if world.get_entity(entity).is_ok() {
+            world.commands().entity(entity).despawn_with_transition();
+        }
+    }
+    world.flush();
+    *rows = kept;
+
+    let index: HashMap<Entity, usize> = order.iter().enumerate().map(|(i, e)| (*e, i)).collect();
+    if let Some(mut children) = world.get_mut::<Children>(container) {
+        // Rows that are still animating out move to the end
+        children.sort_by_key(|entity| index.get(entity).copied().unwrap_or(usize::MAX));
+    }
+}
+
+/// Replaces what the previous build of a reused row spawned with `bundle`.
+///
+/// Components other systems added to the row, such as the focus ring, tweens
+/// or drag state, are kept.
+pub(crate) fn rebuild_row<B: Bundle>(row: &mut EntityWorldMut, bundle: B) {
+    row.despawn_related::<Children>();
+    // Bindings accumulate, so the ones the previous build attached go too
+    row.remove::<(B, Bindings)>();
+    row.insert(bundle);
+}
+
+/// A vertical list container whose rows are generated with [`ForEachView`].
+///
+/// Lays out rows like a `VStack`; use `.flex_direction()` for other directions.
+#[derive(Bundle, Debug, Clone)]
+pub struct ForEach {
+    /// Named "ForEach" in the hierarchy
+    name: Name,
+    /// Column of generated rows
+    node: Node,
+    /// Matched by `ForEach` selectors and any added classes
+    class: ViewClass,
+    /// Attaches the row binding on spawn
+    modifiers: ViewModifiers,
+    /// Border around the rows
+    border: BorderStyle,
+    /// Fill behind the rows
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+}
+
+impl Default for ForEach {
+    fn default() -> Self {
+        Self {
+            name: Name::new("ForEach"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                row_gap: Val::Px(0.0),
+                ..Default::default()
+            },
+            class: ViewClass::new("ForEach"),
+            modifiers: ViewModifiers::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl View for ForEach {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for ForEach {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for ForEach {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ForEachView for ForEach {}
+
+impl StackView for ForEach {}
+
+impl BackgroundView for ForEach {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for ForEach {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for ForEach {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for ForEach {}
+
+impl AnimationView for ForEach {}
+
+impl FocusScopeView for ForEach {}
+
+impl BindingView for ForEach {}
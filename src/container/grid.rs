@@ -4,12 +4,15 @@ use crate::View;
 
 /// Horizontal Grid Layout
 pub mod h_grid;
+/// Virtualized Vertical Grid Layout
+pub mod lazy_v_grid;
 /// Vertical Grid Layout
 pub mod v_grid;
 
 pub mod prelude {
     pub use super::GridView;
     pub use super::h_grid::HGrid;
+    pub use super::lazy_v_grid::LazyVGrid;
     pub use super::v_grid::VGrid;
 }
 
@@ -2,10 +2,16 @@ use bevy_ecs::prelude::*;
 use bevy_ui::prelude::*;
 
 use crate::View;
+use crate::animation::AnimationView;
 use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
 use crate::border::{BorderStyle, BorderView};
+use crate::container::for_each::ForEachView;
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
 use crate::prelude::PositionView;
 use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
 
 use super::GridView;
 
@@ -17,6 +23,10 @@ pub struct HGrid {
     name: Name,
     /// The layout node controlling grid behavior and spacing
     node: Node,
+    /// Type name and classes matched by stylesheets
+    class: ViewClass,
+    /// Deferred changes recorded by modifiers
+    modifiers: ViewModifiers,
     /// Border rendering style (color and radius)
     border: BorderStyle,
     /// Background rendering style (color or image)
@@ -37,6 +47,8 @@ impl Default for HGrid {
                 column_gap: Val::Px(0.0),
                 ..Default::default()
             },
+            class: ViewClass::new("HGrid"),
+            modifiers: ViewModifiers::default(),
             border: BorderStyle::default(),
             background: BackgroundStyle::default(),
             shadow: BoxShadow::default(),
@@ -54,6 +66,18 @@ impl View for HGrid {
     }
 }
 
+impl ModifierView for HGrid {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for HGrid {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
 impl GridView for HGrid {}
 
 impl BackgroundView for HGrid {
@@ -75,3 +99,11 @@ impl BoxShadowView for HGrid {
 }
 
 impl PositionView for HGrid {}
+
+impl AnimationView for HGrid {}
+
+impl FocusScopeView for HGrid {}
+
+impl BindingView for HGrid {}
+
+impl ForEachView for HGrid {}
@@ -0,0 +1,122 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::RelativeCursorPosition;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
+use crate::border::{BorderStyle, BorderView};
+use crate::container::lazy::{LazyGridView, LazyLayout, LazyView};
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+/// A scrolling grid that only spawns the rows of cells intersecting its viewport.
+/// Cells are generated with [`LazyView`] and laid out in equal-width columns.
+#[derive(Bundle, Debug, Clone)]
+pub struct LazyVGrid {
+    /// Named "LazyVGrid" in the hierarchy
+    name: Name,
+    /// Vertically scrolling grid, one flexible column by default
+    node: Node,
+    /// Matched by `LazyVGrid` selectors and any added classes
+    class: ViewClass,
+    /// Attaches the item binding on spawn
+    modifiers: ViewModifiers,
+    /// Row height, column count and overscan
+    layout: LazyLayout,
+    /// Scroll offset of the viewport
+    scroll: ScrollPosition,
+    /// Pointer position, used to scroll the hovered container
+    cursor: RelativeCursorPosition,
+    /// Border around the viewport
+    border: BorderStyle,
+    /// Fill behind the visible items
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+}
+
+impl Default for LazyVGrid {
+    fn default() -> Self {
+        Self {
+            name: Name::new("LazyVGrid"),
+            node: Node {
+                display: Display::Grid,
+                grid_auto_flow: GridAutoFlow::Row,
+                grid_template_columns: RepeatedGridTrack::flex(1, 1.0),
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                overflow: Overflow::scroll_y(),
+                ..Default::default()
+            },
+            class: ViewClass::new("LazyVGrid"),
+            modifiers: ViewModifiers::default(),
+            layout: LazyLayout::default(),
+            scroll: ScrollPosition::default(),
+            cursor: RelativeCursorPosition::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl View for LazyVGrid {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for LazyVGrid {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for LazyVGrid {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl LazyView for LazyVGrid {
+    fn lazy_node(&mut self) -> &mut LazyLayout {
+        &mut self.layout
+    }
+}
+
+impl LazyGridView for LazyVGrid {}
+
+impl BackgroundView for LazyVGrid {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for LazyVGrid {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for LazyVGrid {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for LazyVGrid {}
+
+impl AnimationView for LazyVGrid {}
+
+impl FocusScopeView for LazyVGrid {}
+
+impl BindingView for LazyVGrid {}
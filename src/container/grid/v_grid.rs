@@ -2,10 +2,16 @@ use bevy_ecs::prelude::*;
 use bevy_ui::prelude::*;
 
 use crate::View;
+use crate::animation::AnimationView;
 use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
 use crate::border::{BorderStyle, BorderView};
+use crate::container::for_each::ForEachView;
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
 use crate::prelude::PositionView;
 use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
 
 use super::GridView;
 
@@ -17,6 +23,10 @@ pub struct VGrid {
     name: Name,
     /// The layout node controlling grid structure, flow, and spacing
     node: Node,
+    /// Type name and classes matched by stylesheets
+    class: ViewClass,
+    /// Deferred changes recorded by modifiers
+    modifiers: ViewModifiers,
     /// Border rendering style (color and radius)
     border: BorderStyle,
     /// Background rendering style (color or image)
@@ -37,6 +47,8 @@ impl Default for VGrid {
                 row_gap: Val::Px(0.0),
                 ..Default::default()
             },
+            class: ViewClass::new("VGrid"),
+            modifiers: ViewModifiers::default(),
             border: BorderStyle::default(),
             background: BackgroundStyle::default(),
             shadow: BoxShadow::default(),
@@ -54,6 +66,18 @@ impl View for VGrid {
     }
 }
 
+impl ModifierView for VGrid {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for VGrid {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
 impl GridView for VGrid {}
 
 impl BackgroundView for VGrid {
@@ -75,3 +99,11 @@ impl BoxShadowView for VGrid {
 }
 
 impl PositionView for VGrid {}
+
+impl AnimationView for VGrid {}
+
+impl FocusScopeView for VGrid {}
+
+impl BindingView for VGrid {}
+
+impl ForEachView for VGrid {}
@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, RelativeCursorPosition};
+
+use crate::binding::{Bindings, changed_since};
+use crate::modifier::ModifierView;
+
+use super::for_each::rebuild_row;
+
+/// Sizing of the rows of a lazy container.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct LazyLayout {
+    /// Height of every row in logical pixels
+    pub row_height: f32,
+    /// Number of items per row
+    pub columns: usize,
+    /// Extra rows kept spawned above and below the viewport
+    pub overscan: usize,
+}
+
+impl Default for LazyLayout {
+    fn default() -> Self {
+        Self {
+            row_height: 32.0,
+            columns: 1,
+            overscan: 2,
+        }
+    }
+}
+
+/// Marks a recycled row slot of a lazy container, holding the row built for `index`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LazySlot {
+    pub index: usize,
+}
+
+/// Marks the spacers standing in for rows above and below the spawned ones.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct LazySpacer;
+
+/// A trait for containers that only spawn the rows visible in their viewport.
+///
+/// The container scrolls vertically with the mouse wheel or by changing its
+/// `ScrollPosition`. Every row has the same height, set with
+/// [`row_height`](LazyView::row_height). Rows are spawned into a fixed pool of
+/// slot entities that are reused as the user scrolls, so the number of
+/// entities depends on the viewport size and not on the number of items.
+///
+/// Rows are updated by the `BindingPlugin`.
+pub trait LazyView: ModifierView {
+    /// Returns a mutable reference to the row sizing of this container.
+    fn lazy_node(&mut self) -> &mut LazyLayout;
+
+    /// Sets the height of every row in logical pixels.
+    fn row_height(mut self, height: f32) -> Self {
+        self.lazy_node().row_height = height;
+        self
+    }
+
+    /// Sets how many rows beyond the viewport are kept spawned.
+    fn overscan(mut self, rows: usize) -> Self {
+        self.lazy_node().overscan = rows;
+        self
+    }
+
+    /// Generates rows from a collection stored in a resource.
+    ///
+    /// Visible rows are rebuilt when the resource changes.
+    ///
+    /// # Arguments
+    /// * `items` - Returns the collection, e.g. `|db: &ItemDatabase| &db.items`.
+    /// * `row` - Builds the row for an item and its index.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # struct Item {
+    /// #     name: String,
+    /// # }
+    /// # #[derive(Resource)]
+    /// # struct ItemDatabase {
+    /// #     items: Vec<Item>,
+    /// # }
+    /// LazyVStack::new()
+    ///     .row_height(24.0)
+    ///     .lazy_rows(|db: &ItemDatabase| &db.items, |_, item| SText::new().text(&item.name));
+    /// ```
+    fn lazy_rows<R, T, B>(
+        self,
+        items: impl Fn(&R) -> &[T] + Send + Sync + 'static,
+        row: impl Fn(usize, &T) -> B + Send + Sync + 'static,
+    ) -> Self
+    where
+        R: Resource,
+        T: 'static,
+        B: Bundle,
+    {
+        let items = Arc::new(items);
+        let row = Arc::new(row);
+        self.modifier(move |entity| {
+            let (items, row) = (items.clone(), row.clone());
+            let mut state = LazyState::default();
+            Bindings::attach(entity, move |world, container, last_run| {
+                let this_run = world.read_change_tick();
+                let Some(resource) = world.get_resource_ref::<R>() else {
+                    return;
+                };
+                let changed = changed_since(resource.last_changed(), last_run, this_run);
+                let count = items(&resource).len();
+                update_lazy(
+                    world,
+                    container,
+                    &mut state,
+                    count,
+                    changed,
+                    |world, index| row(index, &items(world.resource::<R>())[index]),
+                );
+            });
+        })
+    }
+
+    /// Generates `count` rows from their index.
+    ///
+    /// # Arguments
+    /// * `count` - Number of rows.
+    /// * `row` - Builds the row at an index.
+    fn lazy_range<B: Bundle>(
+        self,
+        count: usize,
+        row: impl Fn(usize) -> B + Send + Sync + 'static,
+    ) -> Self {
+        let row = Arc::new(row);
+        self.modifier(move |entity| {
+            let row = row.clone();
+            let mut state = LazyState::default();
+            Bindings::attach(entity, move |world, container, _| {
+                update_lazy(world, container, &mut state, count, false, |_, index| {
+                    row(index)
+                });
+            });
+        })
+    }
+}
+
+/// A trait for lazy containers that place several items on each row.
+pub trait LazyGridView: LazyView {
+    /// Lays out items in `columns` equal-width columns.
+    fn columns(mut self, columns: u16) -> Self {
+        self.lazy_node().columns = columns.max(1) as usize;
+        self.node_node().grid_template_columns = RepeatedGridTrack::flex(columns.max(1), 1.0);
+        self
+    }
+}
+
+/// A recycled row slot and the row entity rebuilt for each item it shows.
+struct Slot {
+    entity: Entity,
+    row: Entity,
+    index: Option<usize>,
+}
+
+/// Spawned slots and spacers of one lazy container.
+#[derive(Default)]
+struct LazyState {
+    slots: Vec<Slot>,
+    spacers: Option<(Entity, Entity)>,
+    shown: Range<usize>,
+}
+
+/// Returns how many items fit the viewport at any scroll offset, including overscan.
+fn pool_size(layout: &LazyLayout, viewport: f32) -> usize {
+    // A row cut off at each edge can be visible at the same time
+    let rows = (viewport / layout.row_height.max(1.0)).ceil().max(0.0) as usize + 1;
+    (rows + 2 * layout.overscan) * layout.columns.max(1)
+}
+
+/// Returns the range of item indices to spawn.
+fn visible_items(layout: &LazyLayout, count: usize, offset: f32, viewport: f32) -> Range<usize> {
+    let columns = layout.columns.max(1);
+    let row_height = layout.row_height.max(1.0);
+    let total_rows = count.div_ceil(columns);
+    // Scrolling past the end still shows the last page
+    let offset = offset.min((total_rows as f32 * row_height - viewport).max(0.0));
+    let first_row = ((offset / row_height).floor().max(0.0) as usize)
+        .saturating_sub(layout.overscan)
+        .min(total_rows);
+    let last_row = (((offset + viewport) / row_height).ceil().max(0.0) as usize + layout.overscan)
+        .clamp(first_row, total_rows);
+    first_row * columns..(last_row * columns).min(count)
+}
+
+fn update_lazy<B: Bundle>(
+    world: &mut World,
+    container: Entity,
+    state: &mut LazyState,
+    count: usize,
+    data_changed: bool,
+    build: impl Fn(&World, usize) -> B,
+) {
+    let Some(layout) = world.get::<LazyLayout>(container).copied() else {
+        return;
+    };
+    let viewport = world
+        .get::<ComputedNode>(container)
+        .map_or(0.0, |node| node.size().y * node.inverse_scale_factor());
+    let offset = world
+        .get::<ScrollPosition>(container)
+        .map_or(0.0, |scroll| scroll.offset_y);
+    let range = visible_items(&layout, count, offset, viewport);
+    if range == state.shown && !data_changed && state.spacers.is_some() {
+        return;
+    }
+
+    let (top, bottom) = *state.spacers.get_or_insert_with(|| {
+        let spacer = (LazySpacer, Node::default(), ChildOf(container));
+        (world.spawn(spacer.clone()).id(), world.spawn(spacer).id())
+    });
+
+    // The pool only grows when the viewport does, slots without an item are hidden
+    let capacity = pool_size(&layout, viewport).max(range.len());
+    while state.slots.len() < capacity {
+        let entity = world
+            .spawn((
+                Name::new("LazySlot"),
+                Node {
+                    display: Display::None,
+                    height: Val::Px(layout.row_height),
+                    flex_shrink: 0.0,
+                    ..Default::default()
+                },
+                ChildOf(container),
+            ))
+            .id();
+        let row = world.spawn(ChildOf(entity)).id();
+        state.slots.push(Slot {
+            entity,
+            row,
+            index: None,
+        });
+    }
+
+    let mut assigned = HashMap::with_capacity(range.len());
+    let mut free = Vec::new();
+    let mut rebuild = Vec::new();
+    for (position, slot) in state.slots.iter().enumerate() {
+        match slot.index {
+            // Items keep their slot when the data changes, so focus stays on their row
+            Some(index) if range.contains(&index) => {
+                assigned.insert(index, slot.entity);
+                if data_changed {
+                    rebuild.push(position);
+                }
+            }
+            _ => free.push(position),
+        }
+    }
+    for index in range.clone() {
+        if assigned.contains_key(&index) {
+            continue;
+        }
+        let Some(position) = free.pop() else {
+            break;
+        };
+        let slot = &mut state.slots[position];
+        slot.index = Some(index);
+        assigned.insert(index, slot.entity);
+        rebuild.push(position);
+    }
+    for position in free {
+        let slot = &mut state.slots[position];
+        slot.index = None;
+        set_slot_shown(world, slot.entity, false);
+    }
+
+    for position in rebuild {
+        let Some(index) = state.slots[position].index else {
+            continue;
+        };
+        let row = build(world, index);
+        let slot = &mut state.slots[position];
+        world.entity_mut(slot.entity).insert(LazySlot { index });
+        set_slot_shown(world, slot.entity, true);
+        // The row entity is reused, only what the previous item built is replaced
+        if let Ok(mut entity) = world.get_entity_mut(slot.row) {
+            rebuild_row(&mut entity, row);
+        }
+    }
+
+    let columns = layout.columns.max(1);
+    let total_rows = count.div_ceil(columns);
+    let spacer_height = |rows: usize| Node {
+        height: Val::Px(rows as f32 * layout.row_height),
+        grid_column: GridPlacement::start_end(1, -1),
+        ..Default::default()
+    };
+    world
+        .entity_mut(top)
+        .insert(spacer_height(range.start / columns));
+    world
+        .entity_mut(bottom)
+        .insert(spacer_height(total_rows - range.end.div_ceil(columns)));
+
+    let mut order: HashMap<Entity, usize> = range
+        .clone()
+        .map(|index| (assigned[&index], index + 1))
+        .collect();
+    order.insert(top, 0);
+    // Hidden slots go after the bottom spacer
+    order.insert(bottom, usize::MAX - 1);
+    if let Some(mut children) = world.get_mut::<Children>(container) {
+        children.sort_by_key(|entity| order.get(entity).copied().unwrap_or(usize::MAX));
+    }
+    state.shown = range;
+}
+
+/// Shows or hides a slot, only writing its node when that changes.
+fn set_slot_shown(world: &mut World, slot: Entity, shown: bool) {
+    let display = if shown { Display::Flex } else { Display::None };
+    if let Some(mut node) = world.get_mut::<Node>(slot)
+        && node.display != display
+    {
+        node.display = display;
+    }
+}
+
+/// Scrolls lazy containers under the cursor with the mouse wheel.
+pub struct LazyPlugin;
+
+impl Plugin for LazyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, scroll_lazy_views);
+    }
+}
+
+/// Distance scrolled per wheel line, in logical pixels.
+pub(crate) const LINE_HEIGHT: f32 = 24.0;
+
+fn scroll_lazy_views(
+    mut wheel: EventReader<MouseWheel>,
+    mut views: Query<(
+        &LazyLayout,
+        &ComputedNode,
+        &RelativeCursorPosition,
+        &Children,
+        &mut ScrollPosition,
+    )>,
+    spacers: Query<&Node, With<LazySpacer>>,
+    slots: Query<&Node, With<LazySlot>>,
+) {
+    let delta: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+    if delta == 0.0 {
+        return;
+    }
+    for (layout, node, cursor, children, mut scroll) in &mut views {
+        if !cursor.mouse_over() {
+            continue;
+        }
+        let scale = node.inverse_scale_factor();
+        let mut content = 0.0;
+        let mut shown = 0_usize;
+        for child in children {
+            if let Ok(spacer) = spacers.get(*child)
+                && let Val::Px(height) = spacer.height
+            {
+                content += height;
+            } else if slots
+                .get(*child)
+                .is_ok_and(|slot| slot.display != Display::None)
+            {
+                shown += 1;
+            }
+        }
+        // Shown items start on a row boundary, so they fill whole rows but the last
+        content += shown.div_ceil(layout.columns.max(1)) as f32 * layout.row_height;
+        let max = (content - node.size().y * scale).max(0.0);
+        scroll.offset_y = (scroll.offset_y - delta).clamp(0.0, max);
+    }
+}
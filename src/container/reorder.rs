@@ -0,0 +1,350 @@
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_math::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, FocusPolicy};
+use bevy_window::{PrimaryWindow, Window};
+
+use crate::View;
+
+/// How far the pointer must move while pressing a row before it is picked up, in logical pixels.
+const DRAG_THRESHOLD: f32 = 4.0;
+/// The picked up row is drawn above the rest of the UI.
+const DRAGGED_Z_INDEX: i32 = 2000;
+
+/// Reordering settings of a list whose children can be dragged to a new index.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Reorderable {
+    /// Whether rows can currently be picked up
+    pub enabled: bool,
+    /// Fill of the placeholder showing where the row will be inserted
+    pub placeholder_color: Color,
+}
+
+impl Default for Reorderable {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            placeholder_color: Srgba::new(0.25, 0.55, 1.0, 0.15).into(),
+        }
+    }
+}
+
+/// Sent when a row of a [`Reorderable`] list is dropped at a new index.
+///
+/// The children of the list are already in their new order.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reordered {
+    pub list: Entity,
+    /// Index of the row before it was moved
+    pub from: usize,
+    /// Index of the row after it was moved
+    pub to: usize,
+}
+
+/// A trait for lists whose rows can be dragged to a new index.
+///
+/// Rows are picked up by pressing them and moving the mouse, and are reordered by the
+/// `ReorderPlugin`. Escape puts the row back.
+pub trait ReorderView: View {
+    /// Returns a mutable reference to the reordering settings of this view.
+    fn reorder_node(&mut self) -> &mut Reorderable;
+
+    /// Sets whether rows can be picked up.
+    fn reorder_enabled(mut self, enabled: bool) -> Self {
+        self.reorder_node().enabled = enabled;
+        self
+    }
+
+    /// Sets the fill of the placeholder showing the insert position.
+    fn placeholder_color(mut self, color: impl Into<Color>) -> Self {
+        self.reorder_node().placeholder_color = color.into();
+        self
+    }
+}
+
+/// Fills the slot a picked up row will be dropped into.
+#[derive(Component)]
+struct ReorderPlaceholder;
+
+/// Layout of the picked up row before it was lifted out of the list.
+#[derive(Clone, Copy)]
+struct SavedLayout {
+    position_type: PositionType,
+    left: Val,
+    top: Val,
+    width: Val,
+    /// The row's own `GlobalZIndex`, replaced while it is dragged
+    global_z_index: Option<GlobalZIndex>,
+}
+
+/// The pressed or picked up row.
+#[derive(Resource, Default)]
+pub(crate) struct ReorderState {
+    row: Option<Entity>,
+    list: Option<Entity>,
+    /// Cursor position when the row was pressed
+    pressed_at: Vec2,
+    /// Cursor position relative to the top left corner of the row
+    grab: Vec2,
+    /// Index of the row when it was picked up
+    from: usize,
+    placeholder: Option<Entity>,
+    /// Set once the row is picked up
+    saved: Option<SavedLayout>,
+}
+
+impl ReorderState {
+    /// Returns `true` while a row is picked up.
+    pub(crate) fn is_dragging(&self) -> bool {
+        self.saved.is_some()
+    }
+}
+
+/// Dragging rows of [`Reorderable`] lists to a new index.
+///
+/// Requires Bevy's `InputPlugin`.
+pub struct ReorderPlugin;
+
+impl Plugin for ReorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReorderState>()
+            .add_event::<Reordered>()
+            .add_systems(
+                Update,
+                (make_rows_pressable, pick_up_rows, move_rows, drop_rows).chain(),
+            );
+    }
+}
+
+/// Returns the bounds of a node, in logical pixels.
+fn logical_rect(node: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    let scale = node.inverse_scale_factor();
+    Rect::from_center_size(
+        transform.translation().truncate() * scale,
+        node.size() * scale,
+    )
+}
+
+fn cursor(windows: &Query<&Window, With<PrimaryWindow>>) -> Option<Vec2> {
+    windows.single().ok().and_then(Window::cursor_position)
+}
+
+/// Lets rows without their own `Interaction` be pressed.
+fn make_rows_pressable(
+    mut commands: Commands,
+    lists: Query<&Children, (With<Reorderable>, Changed<Children>)>,
+    rows: Query<(), (Without<Interaction>, Without<ReorderPlaceholder>)>,
+) {
+    for children in &lists {
+        for row in children.iter().filter(|row| rows.contains(*row)) {
+            commands
+                .entity(row)
+                .insert((Interaction::default(), FocusPolicy::Block));
+        }
+    }
+}
+
+/// Remembers the pressed row and lifts it out of the list once the pointer moved far enough.
+fn pick_up_rows(
+    mut commands: Commands,
+    mut state: ResMut<ReorderState>,
+    (mouse, windows): (
+        Res<ButtonInput<MouseButton>>,
+        Query<&Window, With<PrimaryWindow>>,
+    ),
+    lists: Query<(&Reorderable, &Children)>,
+    rows: Query<(Entity, &Interaction, &ChildOf)>,
+    mut nodes: Query<(
+        &mut Node,
+        &ComputedNode,
+        &GlobalTransform,
+        Option<&GlobalZIndex>,
+    )>,
+) {
+    let Some(cursor) = cursor(&windows) else {
+        return;
+    };
+    let Some(row) = state.row else {
+        let pressed = rows.iter().find(|(_, interaction, list)| {
+            **interaction == Interaction::Pressed
+                && lists
+                    .get(list.parent())
+                    .is_ok_and(|(reorderable, _)| reorderable.enabled)
+        });
+        if let Some((row, _, list)) = pressed
+            && mouse.just_pressed(MouseButton::Left)
+            && let Ok((_, node, transform, _)) = nodes.get(row)
+        {
+            *state = ReorderState {
+                row: Some(row),
+                list: Some(list.parent()),
+                pressed_at: cursor,
+                grab: cursor - logical_rect(node, transform).min,
+                ..Default::default()
+            };
+        }
+        return;
+    };
+    if state.saved.is_some() {
+        return;
+    }
+    if !mouse.pressed(MouseButton::Left) {
+        // Released without moving, a plain click
+        *state = ReorderState::default();
+        return;
+    }
+    if cursor.distance(state.pressed_at) < DRAG_THRESHOLD {
+        return;
+    }
+    let Some((list, (reorderable, children))) = state
+        .list
+        .and_then(|list| lists.get(list).ok().map(|found| (list, found)))
+    else {
+        *state = ReorderState::default();
+        return;
+    };
+    let Some(from) = children.iter().position(|child| child == row) else {
+        *state = ReorderState::default();
+        return;
+    };
+    let Ok((mut node, computed, transform, global_z_index)) = nodes.get_mut(row) else {
+        return;
+    };
+    let size = logical_rect(computed, transform).size();
+
+    let placeholder = commands
+        .spawn((
+            Name::new("ReorderPlaceholder"),
+            Node {
+                height: Val::Px(size.y),
+                flex_shrink: 0.0,
+                ..Default::default()
+            },
+            BackgroundColor(reorderable.placeholder_color),
+            BorderRadius::all(Val::Px(4.0)),
+            ReorderPlaceholder,
+        ))
+        .id();
+    commands.entity(list).insert_children(from, &[placeholder]);
+    // Drawn last, above its siblings, while it follows the cursor
+    commands.entity(list).add_child(row);
+    commands.entity(row).insert(GlobalZIndex(DRAGGED_Z_INDEX));
+
+    state.saved = Some(SavedLayout {
+        position_type: node.position_type,
+        left: node.left,
+        top: node.top,
+        width: node.width,
+        global_z_index: global_z_index.copied(),
+    });
+    node.position_type = PositionType::Absolute;
+    node.width = Val::Px(size.x);
+    state.from = from;
+    state.placeholder = Some(placeholder);
+}
+
+/// Moves the picked up row with the cursor and the placeholder to the insert position.
+fn move_rows(
+    mut commands: Commands,
+    state: Res<ReorderState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    children: Query<&Children>,
+    mut nodes: Query<(&mut Node, &ComputedNode, &GlobalTransform)>,
+) {
+    let (Some(row), Some(list), Some(placeholder), Some(_)) =
+        (state.row, state.list, state.placeholder, state.saved)
+    else {
+        return;
+    };
+    let (Some(cursor), Ok(children)) = (cursor(&windows), children.get(list)) else {
+        return;
+    };
+    let Ok((_, list_node, list_transform)) = nodes.get(list) else {
+        return;
+    };
+    let scale = list_node.inverse_scale_factor();
+    let border = list_node.border();
+    let origin =
+        logical_rect(list_node, list_transform).min + Vec2::new(border.left, border.top) * scale;
+    let row_size = nodes
+        .get(row)
+        .map(|(_, node, transform)| logical_rect(node, transform).size())
+        .unwrap_or_default();
+
+    let top_left = cursor - state.grab;
+    if let Ok((mut node, ..)) = nodes.get_mut(row) {
+        let position = top_left - origin;
+        node.left = Val::Px(position.x);
+        node.top = Val::Px(position.y);
+    }
+
+    // The row goes after every sibling whose center is above its own
+    let center = top_left.y + row_size.y / 2.0;
+    let others = children
+        .iter()
+        .filter(|child| *child != row && *child != placeholder);
+    let index = others
+        .filter(|other| {
+            nodes
+                .get(*other)
+                .is_ok_and(|(_, node, transform)| logical_rect(node, transform).center().y < center)
+        })
+        .count();
+    if children.iter().position(|child| child == placeholder) != Some(index) {
+        commands.entity(list).insert_children(index, &[placeholder]);
+    }
+}
+
+/// Drops the row in place of the placeholder on release, or puts it back on Escape.
+pub(crate) fn drop_rows(
+    mut commands: Commands,
+    mut state: ResMut<ReorderState>,
+    (mouse, keys): (Res<ButtonInput<MouseButton>>, Res<ButtonInput<KeyCode>>),
+    children: Query<&Children>,
+    mut nodes: Query<&mut Node>,
+    mut reordered: EventWriter<Reordered>,
+) {
+    let (Some(row), Some(list), Some(placeholder), Some(saved)) =
+        (state.row, state.list, state.placeholder, state.saved)
+    else {
+        return;
+    };
+    let cancel = keys.just_pressed(KeyCode::Escape) || nodes.get(row).is_err();
+    if mouse.pressed(MouseButton::Left) && !cancel {
+        return;
+    }
+
+    let to = if cancel {
+        state.from
+    } else {
+        children
+            .get(list)
+            .ok()
+            .and_then(|children| children.iter().position(|child| child == placeholder))
+            .unwrap_or(state.from)
+    };
+    commands.entity(placeholder).try_despawn();
+    if let Ok(mut node) = nodes.get_mut(row) {
+        node.position_type = saved.position_type;
+        node.left = saved.left;
+        node.top = saved.top;
+        node.width = saved.width;
+        match saved.global_z_index {
+            Some(global_z_index) => commands.entity(row).insert(global_z_index),
+            None => commands.entity(row).remove::<GlobalZIndex>(),
+        };
+        commands.entity(list).insert_children(to, &[row]);
+    }
+    if to != state.from && !cancel {
+        reordered.write(Reordered {
+            list,
+            from: state.from,
+            to,
+        });
+    }
+    *state = ReorderState::default();
+}
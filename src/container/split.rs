@@ -0,0 +1,456 @@
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_math::prelude::*;
+use bevy_time::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, FocusPolicy};
+use bevy_window::{PrimaryWindow, Window};
+
+use crate::View;
+use crate::container::stack::StackView;
+use crate::container::stack::v_stack::VStack;
+use crate::modifier::ModifierView;
+
+/// Side by side panes
+pub mod h_split_view;
+/// Stacked panes
+pub mod v_split_view;
+
+pub mod prelude {
+    pub use super::h_split_view::HSplitView;
+    pub use super::v_split_view::VSplitView;
+    pub use super::{PaneLimits, SplitOptions, SplitPane, SplitRatio, SplitView};
+}
+
+/// Two presses of a divider within this many seconds collapse or restore a pane.
+const DOUBLE_CLICK_SECS: f32 = 0.4;
+/// How far the pointer must move while pressing a divider before it follows, in logical pixels.
+const DRAG_THRESHOLD: f32 = 3.0;
+
+/// One of the two panes of a split view.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SplitPane {
+    /// The left or top pane
+    #[default]
+    First,
+    /// The right or bottom pane
+    Second,
+}
+
+/// How the space of a split view is divided between its panes.
+///
+/// Kept on the split view and updated while the divider is dragged, so it can be
+/// saved and restored with the rest of an editor layout.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SplitRatio {
+    /// Share of the split view given to the first pane, between `0.0` and `1.0`
+    pub ratio: f32,
+    /// Whether the collapsible pane is hidden. The ratio is kept to restore it.
+    pub collapsed: bool,
+}
+
+impl Default for SplitRatio {
+    fn default() -> Self {
+        Self {
+            ratio: 0.5,
+            collapsed: false,
+        }
+    }
+}
+
+/// Size limits of a pane along the split axis, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaneLimits {
+    pub min: f32,
+    /// `f32::INFINITY` for no limit
+    pub max: f32,
+}
+
+impl Default for PaneLimits {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: f32::INFINITY,
+        }
+    }
+}
+
+/// Pane limits and divider look of a split view.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SplitOptions {
+    pub first: PaneLimits,
+    pub second: PaneLimits,
+    /// The pane hidden by a double-click on the divider
+    pub collapsible: SplitPane,
+    /// Thickness of the divider in logical pixels
+    pub divider_thickness: f32,
+    pub divider_color: Color,
+}
+
+impl Default for SplitOptions {
+    fn default() -> Self {
+        Self {
+            first: PaneLimits::default(),
+            second: PaneLimits::default(),
+            collapsible: SplitPane::First,
+            divider_thickness: 6.0,
+            divider_color: Srgba::gray(0.85).into(),
+        }
+    }
+}
+
+impl SplitOptions {
+    fn limits(&self, pane: SplitPane) -> PaneLimits {
+        match pane {
+            SplitPane::First => self.first,
+            SplitPane::Second => self.second,
+        }
+    }
+}
+
+/// Marks the draggable divider between the panes of a split view.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+struct SplitDivider;
+
+/// A trait for views dividing their space between two resizable panes.
+///
+/// The panes are laid out along the flex direction of the view, with a divider
+/// between them that can be dragged, and double-clicked to collapse a pane.
+pub trait SplitView: ModifierView {
+    /// Returns a mutable reference to the initial split of this view.
+    fn split_ratio_node(&mut self) -> &mut SplitRatio;
+
+    /// Returns a mutable reference to the pane limits and divider look of this view.
+    fn split_node(&mut self) -> &mut SplitOptions;
+
+    /// Sets the share of the space initially given to the first pane.
+    ///
+    /// # Arguments
+    /// * `ratio` - Between `0.0` and `1.0`.
+    fn ratio(mut self, ratio: f32) -> Self {
+        self.split_ratio_node().ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Starts with the collapsible pane hidden.
+    fn collapsed(mut self, collapsed: bool) -> Self {
+        self.split_ratio_node().collapsed = collapsed;
+        self
+    }
+
+    /// Sets the size limits of a pane along the split axis, in logical pixels.
+    ///
+    /// # Arguments
+    /// * `pane` - The pane to limit.
+    /// * `min` - The smallest size the divider can shrink the pane to.
+    /// * `max` - The largest size, `f32::INFINITY` for no limit.
+    fn pane_limits(mut self, pane: SplitPane, min: f32, max: f32) -> Self {
+        let limits = PaneLimits { min, max };
+        match pane {
+            SplitPane::First => self.split_node().first = limits,
+            SplitPane::Second => self.split_node().second = limits,
+        }
+        self
+    }
+
+    /// Sets the pane hidden by a double-click on the divider.
+    fn collapsible(mut self, pane: SplitPane) -> Self {
+        self.split_node().collapsible = pane;
+        self
+    }
+
+    /// Sets the thickness and color of the divider.
+    fn divider(mut self, thickness: f32, color: impl Into<Color>) -> Self {
+        let options = self.split_node();
+        options.divider_thickness = thickness;
+        options.divider_color = color.into();
+        self
+    }
+
+    /// Sets the content of the two panes.
+    ///
+    /// # Arguments
+    /// * `first` - Builds the left or top pane.
+    /// * `second` - Builds the right or bottom pane.
+    fn panes<A: Bundle, B: Bundle>(
+        self,
+        first: impl Fn() -> A + Send + Sync + 'static,
+        second: impl Fn() -> B + Send + Sync + 'static,
+    ) -> Self {
+        self.modifier(move |entity| {
+            let Some(node) = entity.get::<Node>() else {
+                return;
+            };
+            let horizontal = is_horizontal(node);
+            let (Some(split), Some(options)) = (
+                entity.get::<SplitRatio>().copied(),
+                entity.get::<SplitOptions>().copied(),
+            ) else {
+                return;
+            };
+            entity.with_children(|view| {
+                view.spawn((pane(SplitPane::First, horizontal), SplitPane::First))
+                    .with_child(first());
+                view.spawn((
+                    Name::new("SplitDivider"),
+                    Node::default(),
+                    BackgroundColor(options.divider_color),
+                    Interaction::default(),
+                    FocusPolicy::Block,
+                    SplitDivider,
+                ));
+                view.spawn((pane(SplitPane::Second, horizontal), SplitPane::Second))
+                    .with_child(second());
+            });
+            let view = entity.id();
+            entity.world_scope(|world| layout_panes(world, view, split, options));
+        })
+    }
+}
+
+fn is_horizontal(node: &Node) -> bool {
+    matches!(
+        node.flex_direction,
+        FlexDirection::Row | FlexDirection::RowReverse
+    )
+}
+
+/// Builds the container of a pane, filling the split view across the split axis.
+fn pane(pane: SplitPane, horizontal: bool) -> VStack {
+    let view = VStack::from_name(match pane {
+        SplitPane::First => "FirstPane",
+        SplitPane::Second => "SecondPane",
+    })
+    .align_items(AlignItems::Stretch);
+    if horizontal {
+        view.height(Val::Percent(100.0))
+    } else {
+        view.width(Val::Percent(100.0))
+    }
+}
+
+/// Sizes the panes and divider of a split view from its ratio and options.
+fn layout_panes(world: &mut World, view: Entity, split: SplitRatio, options: SplitOptions) {
+    let Some(horizontal) = world.get::<Node>(view).map(is_horizontal) else {
+        return;
+    };
+    let Some(children) = world.get::<Children>(view) else {
+        return;
+    };
+    let children: Vec<Entity> = children.iter().collect();
+    for child in children {
+        let Ok(mut entity) = world.get_entity_mut(child) else {
+            continue;
+        };
+        if entity.contains::<SplitDivider>() {
+            let thickness = Val::Px(options.divider_thickness);
+            if let Some(mut color) = entity.get_mut::<BackgroundColor>() {
+                color.0 = options.divider_color;
+            }
+            if let Some(mut node) = entity.get_mut::<Node>() {
+                node.flex_shrink = 0.0;
+                if horizontal {
+                    node.width = thickness;
+                    node.height = Val::Auto;
+                } else {
+                    node.width = Val::Auto;
+                    node.height = thickness;
+                }
+            }
+            continue;
+        }
+        let Some(pane) = entity.get::<SplitPane>().copied() else {
+            continue;
+        };
+        let Some(mut node) = entity.get_mut::<Node>() else {
+            continue;
+        };
+        let hidden = split.collapsed && options.collapsible == pane;
+        let limits = options.limits(pane);
+        let (min, max) = if hidden {
+            (Val::Px(0.0), Val::Px(0.0))
+        } else if limits.max.is_finite() {
+            (Val::Px(limits.min), Val::Px(limits.max))
+        } else {
+            (Val::Px(limits.min), Val::Auto)
+        };
+        // The first pane takes its share, the second one the rest
+        let size = match pane {
+            SplitPane::First if split.collapsed => Val::Percent(if hidden { 0.0 } else { 100.0 }),
+            SplitPane::First => Val::Percent(split.ratio * 100.0),
+            SplitPane::Second => Val::Auto,
+        };
+        node.flex_basis = match pane {
+            SplitPane::First => Val::Auto,
+            SplitPane::Second => Val::Px(0.0),
+        };
+        node.flex_grow = match pane {
+            SplitPane::First => 0.0,
+            SplitPane::Second => 1.0,
+        };
+        node.flex_shrink = 1.0;
+        node.display = if hidden { Display::None } else { Display::Flex };
+        if horizontal {
+            node.width = size;
+            node.min_width = min;
+            node.max_width = max;
+        } else {
+            node.height = size;
+            node.min_height = min;
+            node.max_height = max;
+        }
+    }
+}
+
+/// The divider being dragged.
+#[derive(Resource, Default)]
+struct SplitDrag {
+    divider: Option<Entity>,
+    /// Cursor position when the divider was pressed
+    pressed_at: Vec2,
+    /// Set once the cursor moved far enough to resize the panes
+    moved: bool,
+    /// The last press on a divider, to detect double-clicks
+    last_press: Option<(Entity, f32)>,
+}
+
+/// Resizing and collapsing the panes of [`SplitView`]s.
+///
+/// Requires Bevy's `InputPlugin` and `TimePlugin`.
+pub struct SplitPlugin;
+
+impl Plugin for SplitPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SplitDrag>().add_systems(
+            Update,
+            (press_dividers, drag_dividers, update_split_layout).chain(),
+        );
+    }
+}
+
+fn cursor(windows: &Query<&Window, With<PrimaryWindow>>) -> Option<Vec2> {
+    windows.single().ok().and_then(Window::cursor_position)
+}
+
+/// Starts dragging pressed dividers, and collapses or restores a pane on double-click.
+fn press_dividers(
+    mut drag: ResMut<SplitDrag>,
+    (mouse, time): (Res<ButtonInput<MouseButton>>, Res<Time<Real>>),
+    windows: Query<&Window, With<PrimaryWindow>>,
+    dividers: Query<(Entity, &Interaction, &ChildOf), With<SplitDivider>>,
+    mut splits: Query<&mut SplitRatio>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        if !mouse.pressed(MouseButton::Left) {
+            drag.divider = None;
+        }
+        return;
+    }
+    let Some((divider, view)) = dividers
+        .iter()
+        .find(|(_, interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(divider, _, view)| (divider, view.parent()))
+    else {
+        return;
+    };
+    let now = time.elapsed_secs();
+    let double_click = drag
+        .last_press
+        .is_some_and(|(last, at)| last == divider && now - at <= DOUBLE_CLICK_SECS);
+    if double_click {
+        if let Ok(mut split) = splits.get_mut(view) {
+            split.collapsed = !split.collapsed;
+        }
+        drag.divider = None;
+        drag.last_press = None;
+    } else {
+        drag.divider = Some(divider);
+        drag.pressed_at = cursor(&windows).unwrap_or_default();
+        drag.moved = false;
+        drag.last_press = Some((divider, now));
+    }
+}
+
+/// Moves the divider being dragged to the cursor, within the limits of both panes.
+fn drag_dividers(
+    mut drag: ResMut<SplitDrag>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    dividers: Query<&ChildOf, With<SplitDivider>>,
+    mut splits: Query<(
+        &mut SplitRatio,
+        &SplitOptions,
+        &Node,
+        &ComputedNode,
+        &GlobalTransform,
+    )>,
+) {
+    let Some(view) = drag
+        .divider
+        .and_then(|divider| dividers.get(divider).ok())
+        .map(ChildOf::parent)
+    else {
+        return;
+    };
+    let Some(cursor) = cursor(&windows) else {
+        return;
+    };
+    // A press without moving leaves the split alone, so double-clicks can restore a pane
+    if !drag.moved {
+        if cursor.distance(drag.pressed_at) < DRAG_THRESHOLD {
+            return;
+        }
+        drag.moved = true;
+    }
+    let Ok((mut split, options, node, computed, transform)) = splits.get_mut(view) else {
+        return;
+    };
+
+    let scale = computed.inverse_scale_factor();
+    let (border, padding) = (computed.border(), computed.padding());
+    let rect = Rect::from_center_size(
+        transform.translation().truncate() * scale,
+        computed.size() * scale,
+    );
+    let content = Rect {
+        min: rect.min + Vec2::new(border.left + padding.left, border.top + padding.top) * scale,
+        max: rect.max
+            - Vec2::new(border.right + padding.right, border.bottom + padding.bottom) * scale,
+    };
+    let (start, length, position) = if is_horizontal(node) {
+        (content.min.x, content.width(), cursor.x)
+    } else {
+        (content.min.y, content.height(), cursor.y)
+    };
+    if length <= 0.0 {
+        return;
+    }
+
+    // The divider is centered on the cursor
+    let available = length - options.divider_thickness;
+    let first = position - start - options.divider_thickness / 2.0;
+    let lowest = options.first.min.max(available - options.second.max);
+    let highest = options.first.max.min(available - options.second.min);
+    let first = first.min(highest).max(lowest).max(0.0);
+    let ratio = (first / length).clamp(0.0, 1.0);
+    if split.ratio != ratio || split.collapsed {
+        split.ratio = ratio;
+        split.collapsed = false;
+    }
+}
+
+/// Split views whose ratio or options changed this frame.
+type ChangedSplit = Or<(Changed<SplitRatio>, Changed<SplitOptions>)>;
+
+/// Lays out the panes of split views whose ratio or options changed.
+fn update_split_layout(
+    mut commands: Commands,
+    splits: Query<(Entity, &SplitRatio, &SplitOptions), ChangedSplit>,
+) {
+    for (view, split, options) in &splits {
+        let (split, options) = (*split, *options);
+        commands.queue(move |world: &mut World| layout_panes(world, view, split, options));
+    }
+}
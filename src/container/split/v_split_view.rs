@@ -0,0 +1,139 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+use super::{SplitOptions, SplitRatio, SplitView};
+
+/// Two stacked panes, separated by a divider that can be dragged to resize them.
+///
+/// Double-click the divider to collapse the collapsible pane, and again to restore it.
+/// The current split is kept in the [`SplitRatio`] component.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # fn build_viewport() -> VStack {
+/// #     VStack::new()
+/// # }
+/// # fn build_console() -> VStack {
+/// #     VStack::new()
+/// # }
+/// VSplitView::new()
+///     .frame(Val::Percent(100.0), Val::Percent(100.0))
+///     .ratio(0.7)
+///     .collapsible(SplitPane::Second)
+///     .panes(build_viewport, build_console);
+/// ```
+#[derive(Bundle, Debug, Clone)]
+pub struct VSplitView {
+    /// Named "VSplitView" in the hierarchy
+    name: Name,
+    /// Column holding the top pane, divider and bottom pane
+    node: Node,
+    /// Matched by `VSplitView` selectors and any added classes
+    class: ViewClass,
+    /// Spawns the panes and divider once the view is spawned
+    modifiers: ViewModifiers,
+    /// Border around both panes
+    border: BorderStyle,
+    /// Fill behind both panes
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+    /// Current share of each pane
+    ratio: SplitRatio,
+    /// Pane limits and divider look
+    options: SplitOptions,
+}
+
+impl Default for VSplitView {
+    fn default() -> Self {
+        Self {
+            name: Name::new("VSplitView"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                overflow: Overflow::clip(),
+                ..Default::default()
+            },
+            class: ViewClass::new("VSplitView"),
+            modifiers: ViewModifiers::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+            ratio: SplitRatio::default(),
+            options: SplitOptions::default(),
+        }
+    }
+}
+
+impl View for VSplitView {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for VSplitView {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for VSplitView {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl SplitView for VSplitView {
+    fn split_ratio_node(&mut self) -> &mut SplitRatio {
+        &mut self.ratio
+    }
+
+    fn split_node(&mut self) -> &mut SplitOptions {
+        &mut self.options
+    }
+}
+
+impl BackgroundView for VSplitView {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for VSplitView {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for VSplitView {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for VSplitView {}
+
+impl AnimationView for VSplitView {}
+
+impl FocusScopeView for VSplitView {}
+
+impl BindingView for VSplitView {}
@@ -4,12 +4,18 @@ use crate::View;
 
 /// Horizontal Flex Layout
 pub mod h_stack;
+/// Virtualized Vertical Flex Layout
+pub mod lazy_v_stack;
+/// Vertical Flex Layout with draggable rows
+pub mod reorderable_v_stack;
 /// Vertical Flex Layout
 pub mod v_stack;
 
 pub mod prelude {
     pub use super::StackView;
     pub use super::h_stack::HStack;
+    pub use super::lazy_v_stack::LazyVStack;
+    pub use super::reorderable_v_stack::ReorderableVStack;
     pub use super::v_stack::VStack;
 }
 
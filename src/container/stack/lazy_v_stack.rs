@@ -0,0 +1,119 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::RelativeCursorPosition;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
+use crate::border::{BorderStyle, BorderView};
+use crate::container::lazy::{LazyLayout, LazyView};
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+/// A scrolling vertical stack that only spawns the rows intersecting its viewport.
+/// Rows are generated with [`LazyView`] and all share the same height.
+#[derive(Bundle, Debug, Clone)]
+pub struct LazyVStack {
+    /// Named "LazyVStack" in the hierarchy
+    name: Name,
+    /// Vertically scrolling column of slots
+    node: Node,
+    /// Matched by `LazyVStack` selectors and any added classes
+    class: ViewClass,
+    /// Attaches the item binding on spawn
+    modifiers: ViewModifiers,
+    /// Row height and overscan
+    layout: LazyLayout,
+    /// Scroll offset of the viewport
+    scroll: ScrollPosition,
+    /// Pointer position, used to scroll the hovered container
+    cursor: RelativeCursorPosition,
+    /// Border around the viewport
+    border: BorderStyle,
+    /// Fill behind the visible rows
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+}
+
+impl Default for LazyVStack {
+    fn default() -> Self {
+        Self {
+            name: Name::new("LazyVStack"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                overflow: Overflow::scroll_y(),
+                ..Default::default()
+            },
+            class: ViewClass::new("LazyVStack"),
+            modifiers: ViewModifiers::default(),
+            layout: LazyLayout::default(),
+            scroll: ScrollPosition::default(),
+            cursor: RelativeCursorPosition::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl View for LazyVStack {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for LazyVStack {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for LazyVStack {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl LazyView for LazyVStack {
+    fn lazy_node(&mut self) -> &mut LazyLayout {
+        &mut self.layout
+    }
+}
+
+impl BackgroundView for LazyVStack {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for LazyVStack {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for LazyVStack {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for LazyVStack {}
+
+impl AnimationView for LazyVStack {}
+
+impl FocusScopeView for LazyVStack {}
+
+impl BindingView for LazyVStack {}
@@ -0,0 +1,142 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
+use crate::border::{BorderStyle, BorderView};
+use crate::container::for_each::ForEachView;
+use crate::container::reorder::{ReorderView, Reorderable};
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+use super::StackView;
+
+/// A vertical stack whose children can be dragged to a new index.
+///
+/// While a row is dragged, a placeholder shows where it will be inserted. Dropping it
+/// moves the row and sends [`Reordered`](crate::container::reorder::Reordered).
+///
+/// # Example
+/// ```no_run
+/// # use bevy_color::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Task {
+/// #     id: u32,
+/// #     title: String,
+/// # }
+/// # #[derive(Resource)]
+/// # struct TaskList {
+/// #     tasks: Vec<Task>,
+/// # }
+/// ReorderableVStack::new()
+///     .placeholder_color(Srgba::new(0.2, 0.8, 0.4, 0.2))
+///     .row_gap(Val::Px(4.0))
+///     .for_each(|list: &TaskList| &list.tasks, |task| task.id, |task| SText::new().text(task.title.clone()));
+/// ```
+#[derive(Bundle, Debug, Clone)]
+pub struct ReorderableVStack {
+    /// Named "ReorderableVStack" in the hierarchy
+    name: Name,
+    /// Column of rows, the dragged row floats above it
+    node: Node,
+    /// Matched by `ReorderableVStack` selectors and any added classes
+    class: ViewClass,
+    /// Changes applied once the list is spawned
+    modifiers: ViewModifiers,
+    /// Border around the list
+    border: BorderStyle,
+    /// Fill behind the rows
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+    /// Reordering settings
+    reorderable: Reorderable,
+}
+
+impl Default for ReorderableVStack {
+    fn default() -> Self {
+        Self {
+            name: Name::new("ReorderableVStack"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                row_gap: Val::Px(0.0),
+                ..Default::default()
+            },
+            class: ViewClass::new("ReorderableVStack"),
+            modifiers: ViewModifiers::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+            reorderable: Reorderable::default(),
+        }
+    }
+}
+
+impl View for ReorderableVStack {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for ReorderableVStack {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for ReorderableVStack {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ReorderView for ReorderableVStack {
+    fn reorder_node(&mut self) -> &mut Reorderable {
+        &mut self.reorderable
+    }
+}
+
+impl StackView for ReorderableVStack {}
+
+impl BackgroundView for ReorderableVStack {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for ReorderableVStack {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for ReorderableVStack {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for ReorderableVStack {}
+
+impl AnimationView for ReorderableVStack {}
+
+impl FocusScopeView for ReorderableVStack {}
+
+impl BindingView for ReorderableVStack {}
+
+impl ForEachView for ReorderableVStack {}
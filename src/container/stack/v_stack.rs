@@ -2,10 +2,16 @@ use bevy_ecs::prelude::*;
 use bevy_ui::prelude::*;
 
 use crate::View;
+use crate::animation::AnimationView;
 use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
 use crate::border::{BorderStyle, BorderView};
+use crate::container::for_each::ForEachView;
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
 use crate::prelude::PositionView;
 use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
 
 use super::StackView;
 
@@ -17,6 +23,10 @@ pub struct VStack {
     name: Name,
     /// The layout node controlling size, flex direction, spacing, etc.
     node: Node,
+    /// Type name and classes matched by stylesheets
+    class: ViewClass,
+    /// Deferred changes recorded by modifiers
+    modifiers: ViewModifiers,
     /// Border rendering style (color and radius)
     border: BorderStyle,
     /// Background rendering style (color or image)
@@ -37,6 +47,8 @@ impl Default for VStack {
                 row_gap: Val::Px(0.0),
                 ..Default::default()
             },
+            class: ViewClass::new("VStack"),
+            modifiers: ViewModifiers::default(),
             border: BorderStyle::default(),
             background: BackgroundStyle::default(),
             shadow: BoxShadow::default(),
@@ -54,6 +66,18 @@ impl View for VStack {
     }
 }
 
+impl ModifierView for VStack {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for VStack {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
 impl StackView for VStack {}
 
 impl BackgroundView for VStack {
@@ -75,3 +99,11 @@ impl BoxShadowView for VStack {
 }
 
 impl PositionView for VStack {}
+
+impl AnimationView for VStack {}
+
+impl FocusScopeView for VStack {}
+
+impl BindingView for VStack {}
+
+impl ForEachView for VStack {}
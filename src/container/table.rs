@@ -0,0 +1,650 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, RelativeCursorPosition};
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::{BindingView, Bindings, changed_since};
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::text::TextView;
+use crate::text::swoop_text::SText;
+
+use super::grid::GridView;
+use super::grid::v_grid::VGrid;
+use super::lazy::LINE_HEIGHT;
+
+type BuildCell<T> = Arc<dyn Fn(&mut World, Entity, &T) + Send + Sync>;
+type CompareRows<T> = Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+
+/// A column of a [`Table`] showing items of type `T`.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ui::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # struct Item {
+/// #     damage: u32,
+/// # }
+/// TableColumn::new("Damage", GridTrack::px(80.0), |item: &Item| {
+///     SText::new().text(item.damage.to_string())
+/// })
+/// .sort_by_key(|item| item.damage);
+/// ```
+pub struct TableColumn<T> {
+    header: String,
+    width: GridTrack,
+    cell: BuildCell<T>,
+    compare: Option<CompareRows<T>>,
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            width: self.width,
+            cell: self.cell.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for TableColumn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableColumn")
+            .field("header", &self.header)
+            .field("width", &self.width)
+            .field("sortable", &self.compare.is_some())
+            .finish()
+    }
+}
+
+impl<T: 'static> TableColumn<T> {
+    /// Creates a column.
+    ///
+    /// # Arguments
+    /// * `header` - Text shown in the header row.
+    /// * `width` - Width of the column, e.g. `GridTrack::flex(1.0)`.
+    /// * `cell` - Builds the cell of a row.
+    pub fn new<B: Bundle>(
+        header: impl Into<String>,
+        width: GridTrack,
+        cell: impl Fn(&T) -> B + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            width,
+            cell: Arc::new(move |world, parent, item| {
+                world.spawn((cell(item), ChildOf(parent)));
+            }),
+            compare: None,
+        }
+    }
+
+    /// Lets the header sort rows with a comparator.
+    pub fn sortable(
+        mut self,
+        compare: impl Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    ) -> Self {
+        self.compare = Some(Arc::new(compare));
+        self
+    }
+
+    /// Lets the header sort rows by a key.
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&T) -> K + Send + Sync + 'static) -> Self {
+        self.sortable(move |a, b| key(a).cmp(&key(b)))
+    }
+}
+
+/// The column rows of a [`Table`] are sorted by.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TableSort {
+    /// Index of the sorting column, `None` to keep the collection order
+    pub column: Option<usize>,
+    /// Sorts from smallest to largest
+    pub ascending: bool,
+}
+
+/// The selected row of a [`Table`].
+///
+/// The selection follows its item, identified by the key passed to
+/// [`TableView::rows`], when the collection changes or is sorted, and is cleared
+/// when the item is removed.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TableSelection {
+    /// Index of the selected item in the collection as last shown
+    pub row: Option<usize>,
+    /// Background of the cells of the selected row
+    pub color: Color,
+}
+
+impl Default for TableSelection {
+    fn default() -> Self {
+        Self {
+            row: None,
+            color: Srgba::rgb(0.8, 0.88, 1.0).into(),
+        }
+    }
+}
+
+/// Layout options of a [`Table`].
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TableOptions {
+    /// Keeps the header in place while the rows scroll
+    pub sticky_header: bool,
+    /// Background of the header cells
+    pub header_color: Color,
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self {
+            sticky_header: false,
+            header_color: Srgba::gray(0.9).into(),
+        }
+    }
+}
+
+/// Sent when a row of a [`Table`] is pressed.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowSelected {
+    /// The table
+    pub table: Entity,
+    /// Index of the item in the collection as shown when pressed
+    pub row: usize,
+}
+
+/// Marks a header cell of a [`Table`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableHeader {
+    pub table: Entity,
+    pub column: usize,
+}
+
+/// Marks a body cell of a [`Table`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableCell {
+    pub table: Entity,
+    /// Index of the item in the collection
+    pub row: usize,
+}
+
+/// Marks the body grid of a [`Table`], which scrolls when the header is sticky.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableBody {
+    pub table: Entity,
+}
+
+/// A trait for configuring a [`Table`].
+pub trait TableView: ModifierView {
+    /// Returns a mutable reference to the layout options of this table.
+    fn table_node(&mut self) -> &mut TableOptions;
+
+    /// Keeps the header in place while the rows scroll.
+    fn sticky_header(mut self, sticky: bool) -> Self {
+        self.table_node().sticky_header = sticky;
+        self
+    }
+
+    /// Sets the background of the header cells.
+    fn header_color(mut self, color: impl Into<Color>) -> Self {
+        self.table_node().header_color = color.into();
+        self
+    }
+
+    /// Shows a collection stored in a resource, one row per item.
+    ///
+    /// Rows are rebuilt when the resource changes or the table is sorted.
+    ///
+    /// # Arguments
+    /// * `items` - Returns the collection, e.g. `|stats: &PlayerStats| &stats.players`.
+    /// * `key` - Returns the identity of an item, used to keep it selected.
+    /// * `columns` - The columns, from left to right.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ui::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # struct Player {
+    /// #     id: u64,
+    /// #     name: String,
+    /// #     kills: u32,
+    /// # }
+    /// # #[derive(Resource)]
+    /// # struct PlayerStats {
+    /// #     players: Vec<Player>,
+    /// # }
+    /// Table::new().sticky_header(true).rows(
+    ///     |stats: &PlayerStats| &stats.players,
+    ///     |p| p.id,
+    ///     vec![
+    ///         TableColumn::new("Name", GridTrack::flex(1.0), |p: &Player| SText::new().text(&p.name))
+    ///             .sort_by_key(|p| p.name.clone()),
+    ///         TableColumn::new("Kills", GridTrack::px(60.0), |p: &Player| SText::new().text(p.kills.to_string()))
+    ///             .sort_by_key(|p| p.kills),
+    ///     ],
+    /// );
+    /// ```
+    fn rows<R, T, K>(
+        self,
+        items: impl Fn(&R) -> &[T] + Send + Sync + 'static,
+        key: impl Fn(&T) -> K + Send + Sync + 'static,
+        columns: Vec<TableColumn<T>>,
+    ) -> Self
+    where
+        R: Resource,
+        T: 'static,
+        K: Clone + PartialEq + Send + Sync + 'static,
+    {
+        let items = Arc::new(items);
+        let key = Arc::new(key);
+        let columns = Arc::new(columns);
+        self.modifier(move |entity| {
+            let (items, key, columns) = (items.clone(), key.clone(), columns.clone());
+            let mut sections = None;
+            // Keys of the items as last shown, in collection order
+            let mut shown: Vec<K> = Vec::new();
+            let mut selected: Option<K> = None;
+            Bindings::attach(entity, move |world, table, last_run| {
+                let this_run = world.read_change_tick();
+                if sections.is_some()
+                    && let Some(selection) = world.entity(table).get_ref::<TableSelection>()
+                    && changed_since(selection.last_changed(), last_run, this_run)
+                {
+                    selected = selection.row.and_then(|row| shown.get(row)).cloned();
+                }
+                let (Some(resource), Some(sort)) = (
+                    world.get_resource_ref::<R>(),
+                    world.entity(table).get_ref::<TableSort>(),
+                ) else {
+                    return;
+                };
+                if !changed_since(resource.last_changed(), last_run, this_run)
+                    && !changed_since(sort.last_changed(), last_run, this_run)
+                {
+                    return;
+                }
+                let sort = *sort;
+                let first_build = sections.is_none();
+                let (header, body) =
+                    *sections.get_or_insert_with(|| spawn_sections(world, table, &columns));
+                world.entity_mut(header).despawn_related::<Children>();
+                world.entity_mut(body).despawn_related::<Children>();
+                spawn_header(world, table, header, &columns, sort);
+                world.resource_scope(|world, resource: Mut<R>| {
+                    let items = items(&resource);
+                    shown = items.iter().map(|item| key(item)).collect();
+                    let mut selection = world
+                        .get::<TableSelection>(table)
+                        .copied()
+                        .unwrap_or_default();
+                    if first_build {
+                        selected = selection.row.and_then(|row| shown.get(row)).cloned();
+                    }
+                    // The selected item may have moved or been removed
+                    let row = selected
+                        .as_ref()
+                        .and_then(|selected| shown.iter().position(|key| key == selected));
+                    if selection.row != row {
+                        selection.row = row;
+                        if let Some(mut current) = world.get_mut::<TableSelection>(table) {
+                            current.row = row;
+                        }
+                    }
+                    let mut order: Vec<usize> = (0..items.len()).collect();
+                    if let Some(compare) = sort
+                        .column
+                        .and_then(|column| columns.get(column))
+                        .and_then(|column| column.compare.as_ref())
+                    {
+                        order.sort_by(|a, b| {
+                            let ordering = compare(&items[*a], &items[*b]);
+                            if sort.ascending {
+                                ordering
+                            } else {
+                                ordering.reverse()
+                            }
+                        });
+                    }
+                    for row in order {
+                        let background = if selection.row == Some(row) {
+                            selection.color
+                        } else {
+                            Color::NONE
+                        };
+                        for column in columns.iter() {
+                            let cell = world
+                                .spawn((
+                                    Node {
+                                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
+                                    Button,
+                                    BackgroundColor(background),
+                                    TableCell { table, row },
+                                    ChildOf(body),
+                                ))
+                                .id();
+                            (column.cell)(world, cell, &items[row]);
+                        }
+                    }
+                });
+            });
+        })
+    }
+}
+
+/// Spawns the header and body grids of a table.
+fn spawn_sections<T>(
+    world: &mut World,
+    table: Entity,
+    columns: &[TableColumn<T>],
+) -> (Entity, Entity) {
+    let tracks: Vec<RepeatedGridTrack> = columns.iter().map(|column| column.width.into()).collect();
+    let sticky = world
+        .get::<TableOptions>(table)
+        .is_some_and(|options| options.sticky_header);
+    let header = world
+        .spawn((
+            VGrid::from_name("TableHeader")
+                .grid_template_columns(tracks.clone())
+                .align_items(AlignItems::Stretch),
+            ChildOf(table),
+        ))
+        .id();
+    let mut body = VGrid::from_name("TableBody")
+        .grid_template_columns(tracks)
+        .align_items(AlignItems::Stretch);
+    if sticky {
+        // Only the rows scroll, below the header
+        let node = body.node_node();
+        node.overflow = Overflow::scroll_y();
+        node.flex_grow = 1.0;
+        node.min_height = Val::Px(0.0);
+    }
+    let body = world
+        .spawn((body, TableBody { table }, ChildOf(table)))
+        .id();
+    (header, body)
+}
+
+fn spawn_header<T>(
+    world: &mut World,
+    table: Entity,
+    header: Entity,
+    columns: &[TableColumn<T>],
+    sort: TableSort,
+) {
+    let color = world
+        .get::<TableOptions>(table)
+        .map_or(Color::NONE, |options| options.header_color);
+    for (index, column) in columns.iter().enumerate() {
+        let arrow = if sort.column != Some(index) {
+            ""
+        } else if sort.ascending {
+            " ▲"
+        } else {
+            " ▼"
+        };
+        let label = SText::new().text(format!("{}{arrow}", column.header));
+        let mut cell = world.spawn((
+            Node {
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(color),
+            TableHeader {
+                table,
+                column: index,
+            },
+            ChildOf(header),
+            children![label],
+        ));
+        if column.compare.is_some() {
+            cell.insert(Button);
+        }
+    }
+}
+
+/// A grid of rows generated from a collection, with a header row.
+///
+/// Pressing a sortable header sorts by that column, pressing it again reverses
+/// the order. Pressing a cell selects its row and sends [`RowSelected`]. The
+/// mouse wheel scrolls the rows of the hovered table.
+#[derive(Bundle, Debug, Clone)]
+pub struct Table {
+    /// Named "Table" in the hierarchy
+    name: Name,
+    /// The layout node holding the header and body grids
+    node: Node,
+    /// Matched by `Table` selectors and any added classes
+    class: ViewClass,
+    /// Attaches the row binding on spawn
+    modifiers: ViewModifiers,
+    /// Header and selection colors, sticky header
+    options: TableOptions,
+    /// The sorting column
+    sort: TableSort,
+    /// The selected row
+    selection: TableSelection,
+    /// Pointer position, used to scroll the hovered table
+    cursor: RelativeCursorPosition,
+    /// Border around the header and body
+    border: BorderStyle,
+    /// Fill behind the cells
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self {
+            name: Name::new("Table"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                overflow: Overflow::scroll_y(),
+                ..Default::default()
+            },
+            class: ViewClass::new("Table"),
+            modifiers: ViewModifiers::default(),
+            options: TableOptions::default(),
+            sort: TableSort::default(),
+            selection: TableSelection::default(),
+            cursor: RelativeCursorPosition::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl View for Table {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for Table {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for Table {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl TableView for Table {
+    fn table_node(&mut self) -> &mut TableOptions {
+        &mut self.options
+    }
+}
+
+impl BackgroundView for Table {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for Table {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for Table {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for Table {}
+
+impl AnimationView for Table {}
+
+impl FocusScopeView for Table {}
+
+impl BindingView for Table {}
+
+/// Sorting, row selection and mouse wheel scrolling for [`Table`]s.
+pub struct TablePlugin;
+
+impl Plugin for TablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RowSelected>().add_systems(
+            Update,
+            (
+                sort_on_header_press,
+                select_on_cell_press,
+                highlight_selection,
+                scroll_tables,
+            )
+                .chain(),
+        );
+    }
+}
+
+fn sort_on_header_press(
+    headers: Query<(&Interaction, &TableHeader), Changed<Interaction>>,
+    mut tables: Query<&mut TableSort>,
+) {
+    for (interaction, header) in &headers {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut sort) = tables.get_mut(header.table) else {
+            continue;
+        };
+        if sort.column == Some(header.column) {
+            sort.ascending = !sort.ascending;
+        } else {
+            sort.column = Some(header.column);
+            sort.ascending = true;
+        }
+    }
+}
+
+fn select_on_cell_press(
+    cells: Query<(&Interaction, &TableCell), Changed<Interaction>>,
+    mut tables: Query<&mut TableSelection>,
+    mut selected: EventWriter<RowSelected>,
+) {
+    for (interaction, cell) in &cells {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut selection) = tables.get_mut(cell.table)
+            && selection.row != Some(cell.row)
+        {
+            selection.row = Some(cell.row);
+        }
+        selected.write(RowSelected {
+            table: cell.table,
+            row: cell.row,
+        });
+    }
+}
+
+fn highlight_selection(
+    tables: Query<Ref<TableSelection>>,
+    mut cells: Query<(&TableCell, &mut BackgroundColor)>,
+) {
+    for (cell, mut background) in &mut cells {
+        let Ok(selection) = tables.get(cell.table) else {
+            continue;
+        };
+        if !selection.is_changed() {
+            continue;
+        }
+        let color = if selection.row == Some(cell.row) {
+            selection.color
+        } else {
+            Color::NONE
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+fn scroll_tables(
+    mut wheel: EventReader<MouseWheel>,
+    tables: Query<(Entity, &RelativeCursorPosition, &TableOptions)>,
+    bodies: Query<(Entity, &TableBody)>,
+    mut scrolled: Query<(&ComputedNode, &mut ScrollPosition)>,
+) {
+    let delta: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+    if delta == 0.0 {
+        return;
+    }
+    for (table, cursor, options) in &tables {
+        if !cursor.mouse_over() {
+            continue;
+        }
+        // With a sticky header only the body scrolls
+        let target = if options.sticky_header {
+            bodies
+                .iter()
+                .find(|(_, body)| body.table == table)
+                .map(|(body, _)| body)
+        } else {
+            Some(table)
+        };
+        let Some(Ok((node, mut scroll))) = target.map(|target| scrolled.get_mut(target)) else {
+            continue;
+        };
+        let scale = node.inverse_scale_factor();
+        let max = ((node.content_size.y - node.size().y) * scale).max(0.0);
+        scroll.offset_y = (scroll.offset_y - delta).clamp(0.0, max);
+    }
+}
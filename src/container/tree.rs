@@ -0,0 +1,562 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::{BindingView, Bindings};
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::{CapturesHorizontalArrows, FocusScopeView, Focusable, FocusedEntity};
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::text::TextView;
+use crate::text::swoop_text::SText;
+
+/// Expanded and selected nodes of a [`Tree`] whose nodes are identified by `K`.
+///
+/// Inserted on the tree when it is spawned. Query it to react to selection, or
+/// change it to expand nodes from code.
+#[derive(Component, Debug, Clone)]
+pub struct TreeState<K: Clone + Eq + Hash + Send + Sync + 'static> {
+    expanded: HashSet<K>,
+    selected: Option<K>,
+    /// Bumped whenever the visible rows need to be rebuilt
+    revision: u64,
+    /// Keys of the visible rows, top to bottom
+    visible: Vec<K>,
+}
+
+impl<K: Clone + Eq + Hash + Send + Sync + 'static> Default for TreeState<K> {
+    fn default() -> Self {
+        Self {
+            expanded: HashSet::new(),
+            selected: None,
+            revision: 0,
+            visible: Vec::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash + Send + Sync + 'static> TreeState<K> {
+    /// Returns the selected node, if any.
+    pub fn selected(&self) -> Option<&K> {
+        self.selected.as_ref()
+    }
+
+    /// Selects a node.
+    pub fn select(&mut self, key: K) {
+        self.selected = Some(key);
+    }
+
+    /// Returns whether the children of a node are shown.
+    pub fn is_expanded(&self, key: &K) -> bool {
+        self.expanded.contains(key)
+    }
+
+    /// Shows the children of a node.
+    pub fn expand(&mut self, key: K) {
+        if self.expanded.insert(key) {
+            self.revision += 1;
+        }
+    }
+
+    /// Hides the children of a node.
+    pub fn collapse(&mut self, key: &K) {
+        if self.expanded.remove(key) {
+            self.revision += 1;
+        }
+    }
+
+    /// Expands a collapsed node or collapses an expanded one.
+    pub fn toggle(&mut self, key: K) {
+        if self.is_expanded(&key) {
+            self.collapse(&key);
+        } else {
+            self.expand(key);
+        }
+    }
+
+    /// Rebuilds the rows, e.g. after the mirrored hierarchy changed.
+    pub fn refresh(&mut self) {
+        self.revision += 1;
+    }
+}
+
+/// Indentation and colors of a [`Tree`].
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TreeOptions {
+    /// Indentation per nesting level in logical pixels
+    pub indent: f32,
+    /// Background of the selected row
+    pub selection_color: Color,
+}
+
+impl Default for TreeOptions {
+    fn default() -> Self {
+        Self {
+            indent: 16.0,
+            selection_color: Srgba::rgb(0.8, 0.88, 1.0).into(),
+        }
+    }
+}
+
+/// A row of a [`Tree`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreeRow {
+    pub tree: Entity,
+    /// Position among the visible rows
+    pub index: usize,
+    /// Nesting level, `0` for roots
+    pub depth: usize,
+    /// Whether the node may have children, known once it has been expanded
+    pub expandable: bool,
+    pub expanded: bool,
+}
+
+/// Marks the expand/collapse chevron of a [`TreeRow`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreeChevron {
+    pub row: Entity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TreeAction {
+    Select(usize),
+    Toggle(usize),
+    Expand(usize),
+    Collapse(usize),
+}
+
+impl TreeAction {
+    /// Returns the index of the row acted on.
+    fn row(self) -> usize {
+        match self {
+            TreeAction::Select(row)
+            | TreeAction::Toggle(row)
+            | TreeAction::Expand(row)
+            | TreeAction::Collapse(row) => row,
+        }
+    }
+}
+
+/// Row interactions waiting to be applied to the [`TreeState`] of a tree.
+#[derive(Component, Debug, Clone, Default)]
+struct TreeActions(Vec<TreeAction>);
+
+/// A trait for configuring a [`Tree`].
+pub trait TreeView: ModifierView {
+    /// Returns a mutable reference to the options of this tree.
+    fn tree_node(&mut self) -> &mut TreeOptions;
+
+    /// Sets the indentation per nesting level.
+    fn indent(mut self, indent: f32) -> Self {
+        self.tree_node().indent = indent;
+        self
+    }
+
+    /// Sets the background of the selected row.
+    fn selection_color(mut self, color: impl Into<Color>) -> Self {
+        self.tree_node().selection_color = color.into();
+        self
+    }
+
+    /// Shows a hierarchy of nodes identified by `K`.
+    ///
+    /// Children are only requested for expanded nodes, so large or generated
+    /// hierarchies are built as they are expanded. Until then a collapsed node
+    /// shows a chevron, which disappears if expanding it reveals no children.
+    ///
+    /// # Arguments
+    /// * `roots` - Returns the top level nodes.
+    /// * `children` - Returns the children of a node.
+    /// * `label` - Builds the content of a node's row.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// Tree::new().nodes(
+    ///     |world| {
+    ///         let mut roots = world.try_query_filtered::<Entity, Without<ChildOf>>().unwrap();
+    ///         roots.iter(world).collect()
+    ///     },
+    ///     |world, entity| world.get::<Children>(*entity).map_or(vec![], |c| c.to_vec()),
+    ///     |_, entity| SText::new().text(format!("{entity}")),
+    /// );
+    /// ```
+    fn nodes<K, B>(
+        self,
+        roots: impl Fn(&World) -> Vec<K> + Send + Sync + 'static,
+        children: impl Fn(&World, &K) -> Vec<K> + Send + Sync + 'static,
+        label: impl Fn(&World, &K) -> B + Send + Sync + 'static,
+    ) -> Self
+    where
+        K: Clone + Eq + Hash + Send + Sync + 'static,
+        B: Bundle,
+    {
+        let roots = Arc::new(roots);
+        let children = Arc::new(children);
+        let label = Arc::new(label);
+        self.modifier(move |entity| {
+            let (roots, children, label) = (roots.clone(), children.clone(), label.clone());
+            entity.insert((TreeState::<K>::default(), TreeActions::default()));
+            let mut built = None;
+            Bindings::attach(entity, move |world, tree, _| {
+                apply_actions::<K>(world, tree);
+                let Some(state) = world.get::<TreeState<K>>(tree) else {
+                    return;
+                };
+                if built != Some(state.revision) {
+                    built = Some(state.revision);
+                    rebuild_rows(world, tree, &*roots, &*children, &*label);
+                }
+                highlight_selection::<K>(world, tree);
+            });
+        })
+    }
+}
+
+/// Applies row interactions recorded by the tree systems.
+fn apply_actions<K: Clone + Eq + Hash + Send + Sync + 'static>(world: &mut World, tree: Entity) {
+    let Some(mut actions) = world.get_mut::<TreeActions>(tree) else {
+        return;
+    };
+    if actions.0.is_empty() {
+        return;
+    }
+    let actions = std::mem::take(&mut actions.0);
+    let Some(mut state) = world.get_mut::<TreeState<K>>(tree) else {
+        return;
+    };
+    for action in actions {
+        let Some(key) = state.visible.get(action.row()).cloned() else {
+            continue;
+        };
+        match action {
+            TreeAction::Select(_) if state.selected.as_ref() != Some(&key) => state.select(key),
+            TreeAction::Select(_) => {}
+            TreeAction::Toggle(_) => state.toggle(key),
+            TreeAction::Expand(_) => state.expand(key),
+            TreeAction::Collapse(_) => state.collapse(&key),
+        }
+    }
+}
+
+fn rebuild_rows<K, B>(
+    world: &mut World,
+    tree: Entity,
+    roots: &dyn Fn(&World) -> Vec<K>,
+    children: &dyn Fn(&World, &K) -> Vec<K>,
+    label: &dyn Fn(&World, &K) -> B,
+) where
+    K: Clone + Eq + Hash + Send + Sync + 'static,
+    B: Bundle,
+{
+    let Some(state) = world.get::<TreeState<K>>(tree) else {
+        return;
+    };
+    let indent = world
+        .get::<TreeOptions>(tree)
+        .copied()
+        .unwrap_or_default()
+        .indent;
+
+    // Depth-first walk of the expanded nodes, children pushed in reverse to keep their order
+    let mut rows = Vec::new();
+    let mut pending: Vec<(K, usize)> = roots(world).into_iter().rev().map(|k| (k, 0)).collect();
+    while let Some((key, depth)) = pending.pop() {
+        let expanded = state.is_expanded(&key);
+        let mut expandable = true;
+        if expanded {
+            let nested = children(world, &key);
+            expandable = !nested.is_empty();
+            pending.extend(nested.into_iter().rev().map(|child| (child, depth + 1)));
+        }
+        rows.push((key, depth, expandable, expanded));
+    }
+
+    // Keep focus on the same node if one of the old rows had it
+    let focused = world.get_resource::<FocusedEntity>().and_then(|f| f.0);
+    let focused_key = focused
+        .and_then(|entity| world.get::<TreeRow>(entity))
+        .filter(|row| row.tree == tree)
+        .and_then(|row| state.visible.get(row.index).cloned());
+
+    let bundles: Vec<_> = rows.iter().map(|(key, ..)| label(world, key)).collect();
+    world.entity_mut(tree).despawn_related::<Children>();
+    let mut visible = Vec::with_capacity(rows.len());
+    let mut refocus = None;
+    for (index, ((key, depth, expandable, expanded), bundle)) in
+        rows.into_iter().zip(bundles).enumerate()
+    {
+        let row = world
+            .spawn((
+                Name::new("TreeRow"),
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::left(Val::Px(depth as f32 * indent)),
+                    ..Default::default()
+                },
+                Button,
+                Focusable::default(),
+                CapturesHorizontalArrows,
+                BackgroundColor(Color::NONE),
+                TreeRow {
+                    tree,
+                    index,
+                    depth,
+                    expandable,
+                    expanded,
+                },
+                ChildOf(tree),
+            ))
+            .id();
+        let chevron = if !expandable {
+            ""
+        } else if expanded {
+            "▾"
+        } else {
+            "▸"
+        };
+        let mut chevron = world.spawn((
+            SText::new().text(chevron).width(Val::Px(16.0)),
+            TreeChevron { row },
+            ChildOf(row),
+        ));
+        if expandable {
+            chevron.insert(Button);
+        }
+        world.spawn((bundle, ChildOf(row)));
+        if focused_key.as_ref() == Some(&key) {
+            refocus = Some(row);
+        }
+        visible.push(key);
+    }
+
+    if let Some(mut state) = world.get_mut::<TreeState<K>>(tree) {
+        state.bypass_change_detection().visible = visible;
+    }
+    if let Some(row) = refocus
+        && let Some(mut focused) = world.get_resource_mut::<FocusedEntity>()
+    {
+        focused.0 = Some(row);
+    }
+}
+
+fn highlight_selection<K: Clone + Eq + Hash + Send + Sync + 'static>(
+    world: &mut World,
+    tree: Entity,
+) {
+    let Some(state) = world.get::<TreeState<K>>(tree) else {
+        return;
+    };
+    let color = world
+        .get::<TreeOptions>(tree)
+        .copied()
+        .unwrap_or_default()
+        .selection_color;
+    let selected = state
+        .selected
+        .as_ref()
+        .and_then(|key| state.visible.iter().position(|visible| visible == key));
+    let rows: Vec<Entity> = world
+        .get::<Children>(tree)
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+    for row in rows {
+        let Some(index) = world.get::<TreeRow>(row).map(|row| row.index) else {
+            continue;
+        };
+        let background = if selected == Some(index) {
+            color
+        } else {
+            Color::NONE
+        };
+        if let Some(mut current) = world.get_mut::<BackgroundColor>(row)
+            && current.0 != background
+        {
+            current.0 = background;
+        }
+    }
+}
+
+/// An outline of nested nodes shown as indented rows, see [`TreeView`].
+///
+/// Pressing a chevron expands or collapses its node and pressing a row selects
+/// it. Rows are focusable: Up and Down move between them, and Left and Right
+/// collapse and expand the focused node instead of moving focus.
+#[derive(Bundle, Debug, Clone)]
+pub struct Tree {
+    /// Named "Tree" in the hierarchy
+    name: Name,
+    /// Column of rows, indented by their depth
+    node: Node,
+    /// Matched by `Tree` selectors and any added classes
+    class: ViewClass,
+    /// Attaches the node binding on spawn
+    modifiers: ViewModifiers,
+    /// Indentation and selection color
+    options: TreeOptions,
+    /// Border around the rows
+    border: BorderStyle,
+    /// Fill behind the rows
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self {
+            name: Name::new("Tree"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                ..Default::default()
+            },
+            class: ViewClass::new("Tree"),
+            modifiers: ViewModifiers::default(),
+            options: TreeOptions::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl View for Tree {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for Tree {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for Tree {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl TreeView for Tree {
+    fn tree_node(&mut self) -> &mut TreeOptions {
+        &mut self.options
+    }
+}
+
+impl BackgroundView for Tree {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for Tree {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for Tree {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for Tree {}
+
+impl AnimationView for Tree {}
+
+impl FocusScopeView for Tree {}
+
+impl BindingView for Tree {}
+
+/// Expanding, collapsing and selecting rows of [`Tree`]s.
+///
+/// Requires Bevy's `InputPlugin` and the `FocusPlugin`.
+pub struct TreePlugin;
+
+impl Plugin for TreePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (press_tree_rows, select_focused_row, expand_with_keys),
+        );
+    }
+}
+
+fn push_action(trees: &mut Query<&mut TreeActions>, row: &TreeRow, action: TreeAction) {
+    if let Ok(mut actions) = trees.get_mut(row.tree) {
+        actions.0.push(action);
+    }
+}
+
+fn press_tree_rows(
+    rows: Query<(&Interaction, &TreeRow), Changed<Interaction>>,
+    chevrons: Query<(&Interaction, &TreeChevron), Changed<Interaction>>,
+    all_rows: Query<&TreeRow>,
+    mut trees: Query<&mut TreeActions>,
+) {
+    for (interaction, row) in &rows {
+        if *interaction == Interaction::Pressed {
+            push_action(&mut trees, row, TreeAction::Select(row.index));
+        }
+    }
+    for (interaction, chevron) in &chevrons {
+        if *interaction == Interaction::Pressed
+            && let Ok(row) = all_rows.get(chevron.row)
+        {
+            push_action(&mut trees, row, TreeAction::Toggle(row.index));
+        }
+    }
+}
+
+fn select_focused_row(
+    focused: Res<FocusedEntity>,
+    rows: Query<&TreeRow>,
+    mut trees: Query<&mut TreeActions>,
+) {
+    if !focused.is_changed() {
+        return;
+    }
+    if let Some(row) = focused.0.and_then(|entity| rows.get(entity).ok()) {
+        push_action(&mut trees, row, TreeAction::Select(row.index));
+    }
+}
+
+fn expand_with_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    focused: Res<FocusedEntity>,
+    rows: Query<&TreeRow>,
+    mut trees: Query<&mut TreeActions>,
+) {
+    let Some(row) = focused.0.and_then(|entity| rows.get(entity).ok()) else {
+        return;
+    };
+    let gamepad_pressed = |button| gamepads.iter().any(|pad| pad.just_pressed(button));
+    if keys.just_pressed(KeyCode::ArrowRight) || gamepad_pressed(GamepadButton::DPadRight) {
+        push_action(&mut trees, row, TreeAction::Expand(row.index));
+    } else if keys.just_pressed(KeyCode::ArrowLeft) || gamepad_pressed(GamepadButton::DPadLeft) {
+        push_action(&mut trees, row, TreeAction::Collapse(row.index));
+    }
+}
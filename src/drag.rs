@@ -0,0 +1,505 @@
+use std::fmt;
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_math::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, FocusPolicy, RelativeCursorPosition};
+use bevy_window::{PrimaryWindow, Window};
+
+use crate::modifier::ModifierView;
+
+pub mod prelude {
+    pub use super::{
+        DragCancelled, DragDropPlugin, DragDropView, DragStarted, Draggable, DropTarget, Dropped,
+    };
+}
+
+/// How far the pointer must move while pressed before a drag starts, in logical pixels.
+const DRAG_THRESHOLD: f32 = 4.0;
+/// The ghost preview is drawn above everything else.
+const GHOST_Z_INDEX: i32 = 2000;
+const HIGHLIGHT_COLOR: Srgba = Srgba::new(0.25, 0.55, 1.0, 0.25);
+const HIGHLIGHT_BORDER_COLOR: Srgba = Srgba::rgb(0.25, 0.55, 1.0);
+
+/// Sent when the pointer moves far enough while pressing a [`Draggable`] view.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DragStarted {
+    pub source: Entity,
+}
+
+/// Sent when a drag is released over a [`DropTarget`] accepting its payload.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dropped {
+    /// The dragged view, holding the payload
+    pub source: Entity,
+    /// The view it was dropped on
+    pub target: Entity,
+}
+
+/// Sent when a drag is released outside any accepting target, or cancelled with Escape.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DragCancelled {
+    pub source: Entity,
+}
+
+type BuildPreview = Arc<dyn Fn(&mut Commands) -> Entity + Send + Sync>;
+
+/// Marks a view that can be dragged, carrying its payload as a component.
+#[derive(Component, Clone, Default)]
+pub struct Draggable {
+    /// Builds the ghost following the cursor, a faded copy of the view if `None`
+    preview: Option<BuildPreview>,
+}
+
+impl fmt::Debug for Draggable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Draggable")
+            .field("custom_preview", &self.preview.is_some())
+            .finish()
+    }
+}
+
+type DropHandler = Arc<dyn Fn(&mut World, Dropped) + Send + Sync>;
+
+#[derive(Clone)]
+struct AcceptedPayload {
+    /// Returns whether the dragged entity carries the payload
+    accepts: fn(&World, Entity) -> bool,
+    on_drop: DropHandler,
+}
+
+/// The payload types a view accepts, added with [`DragDropView::drop_target`].
+#[derive(Component, Clone, Default)]
+pub struct DropTarget {
+    payloads: Vec<AcceptedPayload>,
+}
+
+impl fmt::Debug for DropTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DropTarget")
+            .field("payloads", &self.payloads.len())
+            .finish()
+    }
+}
+
+impl DropTarget {
+    fn accepts(&self, world: &World, source: Entity) -> bool {
+        self.payloads
+            .iter()
+            .any(|payload| (payload.accepts)(world, source))
+    }
+}
+
+fn has_payload<P: Component>(world: &World, source: Entity) -> bool {
+    world.get::<P>(source).is_some()
+}
+
+/// A trait for dragging views and dropping them on others, implemented for every view.
+///
+/// Works on the cells of grids and stacks, such as inventory slots.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Slot(usize);
+///
+/// fn slot(index: usize) -> impl Bundle {
+///     TextButton::new()
+///         .draggable(Slot(index))
+///         .drop_target::<Slot>(move |commands, from, _| {
+///             commands.queue(move |world: &mut World| swap_slots(world, from.0, index));
+///         })
+///         .pack()
+/// }
+/// # fn swap_slots(_: &mut World, _: usize, _: usize) {}
+///
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(VGrid::new()).with_children(|grid| {
+///     for index in 0..40 {
+///         grid.spawn(slot(index));
+///     }
+/// });
+/// # }
+/// ```
+pub trait DragDropView: ModifierView {
+    /// Lets the view be dragged, carrying `payload`.
+    ///
+    /// # Arguments
+    /// * `payload` - Inserted on the view, and read by drop targets accepting its type.
+    fn draggable<P: Component + Clone>(self, payload: P) -> Self {
+        self.modifier(move |entity| {
+            entity.insert((payload.clone(), RelativeCursorPosition::default()));
+            if !entity.contains::<Draggable>() {
+                entity.insert(Draggable::default());
+            }
+            if !entity.contains::<Interaction>() {
+                entity.insert(Interaction::default());
+            }
+        })
+    }
+
+    /// Replaces the faded copy of the view following the cursor while it is dragged.
+    ///
+    /// Call after [`draggable`](Self::draggable).
+    fn drag_preview<B: Bundle>(self, build: impl Fn() -> B + Send + Sync + 'static) -> Self {
+        let build: BuildPreview = Arc::new(move |commands| commands.spawn(build()).id());
+        self.modifier(move |entity| {
+            entity.insert(Draggable {
+                preview: Some(build.clone()),
+            });
+        })
+    }
+
+    /// Accepts views dragged with a `P` payload, running `on_drop` when one is dropped here.
+    ///
+    /// A view can accept several payload types.
+    ///
+    /// # Arguments
+    /// * `on_drop` - Receives a copy of the payload and the source and target entities.
+    fn drop_target<P: Component + Clone>(
+        self,
+        on_drop: impl Fn(&mut Commands, P, Dropped) + Send + Sync + 'static,
+    ) -> Self {
+        let on_drop: DropHandler = Arc::new(move |world, dropped| {
+            let Some(payload) = world.get::<P>(dropped.source).cloned() else {
+                return;
+            };
+            on_drop(&mut world.commands(), payload, dropped);
+            world.flush();
+        });
+        self.modifier(move |entity| {
+            let payload = AcceptedPayload {
+                accepts: has_payload::<P>,
+                on_drop: on_drop.clone(),
+            };
+            match entity.get_mut::<DropTarget>() {
+                Some(mut target) => target.payloads.push(payload),
+                None => {
+                    entity.insert((
+                        DropTarget {
+                            payloads: vec![payload],
+                        },
+                        RelativeCursorPosition::default(),
+                    ));
+                }
+            }
+        })
+    }
+}
+
+impl<V: ModifierView> DragDropView for V {}
+
+/// Marks the drop targets accepting the payload of the current drag.
+#[derive(Component)]
+pub(crate) struct ValidDropTarget;
+
+/// The overlay drawn over the hovered drop target, following its bounds.
+#[derive(Component)]
+struct DropHighlight;
+
+/// The pressed or dragged view and its ghost.
+#[derive(Resource, Default)]
+pub(crate) struct DragState {
+    source: Option<Entity>,
+    /// Cursor position when the source was pressed
+    pressed_at: Vec2,
+    /// Cursor position relative to the top left corner of the source
+    grab: Vec2,
+    /// Whether the pointer moved far enough to start dragging
+    active: bool,
+    ghost: Option<Entity>,
+    hovered: Option<Entity>,
+    highlight: Option<Entity>,
+}
+
+impl DragState {
+    /// Returns `true` while a view is being dragged.
+    pub(crate) fn is_dragging(&self) -> bool {
+        self.active
+    }
+}
+
+/// Dragging views onto drop targets with the mouse.
+///
+/// Requires Bevy's `InputPlugin`.
+pub struct DragDropPlugin;
+
+impl Plugin for DragDropPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DragState>()
+            .add_event::<DragStarted>()
+            .add_event::<Dropped>()
+            .add_event::<DragCancelled>()
+            .add_systems(Update, (begin_drags, follow_cursor, end_drags).chain());
+    }
+}
+
+type DragSource = (
+    Entity,
+    &'static Interaction,
+    &'static Draggable,
+    &'static ComputedNode,
+    &'static GlobalTransform,
+);
+
+type DropCandidate = (
+    Entity,
+    &'static RelativeCursorPosition,
+    &'static ComputedNode,
+    &'static GlobalTransform,
+);
+
+type GhostStyle = (
+    Option<&'static BackgroundColor>,
+    Option<&'static ImageNode>,
+    Option<&'static BorderRadius>,
+);
+
+fn cursor(windows: &Query<&Window, With<PrimaryWindow>>) -> Option<Vec2> {
+    windows.single().ok().and_then(Window::cursor_position)
+}
+
+/// Remembers pressed sources and starts dragging once the pointer moved far enough.
+fn begin_drags(
+    mut commands: Commands,
+    mut drag: ResMut<DragState>,
+    (mouse, windows): (
+        Res<ButtonInput<MouseButton>>,
+        Query<&Window, With<PrimaryWindow>>,
+    ),
+    sources: Query<DragSource>,
+    styles: Query<GhostStyle>,
+    mut started: EventWriter<DragStarted>,
+) {
+    let Some(cursor) = cursor(&windows) else {
+        return;
+    };
+    if drag.source.is_none() {
+        let pressed = sources
+            .iter()
+            .find(|(_, interaction, ..)| **interaction == Interaction::Pressed);
+        if let Some((source, _, _, node, transform)) = pressed
+            && mouse.just_pressed(MouseButton::Left)
+        {
+            let top_left = logical_rect(node, transform).min;
+            *drag = DragState {
+                source: Some(source),
+                pressed_at: cursor,
+                grab: cursor - top_left,
+                ..Default::default()
+            };
+        }
+        return;
+    }
+    if drag.active {
+        return;
+    }
+    if !mouse.pressed(MouseButton::Left) {
+        // Released without moving, a plain click
+        *drag = DragState::default();
+        return;
+    }
+    let Some(source) = drag.source else {
+        return;
+    };
+    let Ok((_, _, draggable, node, _)) = sources.get(source) else {
+        *drag = DragState::default();
+        return;
+    };
+    if cursor.distance(drag.pressed_at) < DRAG_THRESHOLD {
+        return;
+    }
+
+    let preview = match &draggable.preview {
+        Some(build) => build(&mut commands),
+        None => {
+            let (background, image, radius) = styles.get(source).unwrap_or_default();
+            let size = node.size() * node.inverse_scale_factor();
+            let mut preview = commands.spawn((
+                Node {
+                    width: Val::Px(size.x),
+                    height: Val::Px(size.y),
+                    ..Default::default()
+                },
+                BackgroundColor(background.map_or(Color::NONE, |b| b.0.with_alpha(0.6))),
+                radius.copied().unwrap_or_default(),
+            ));
+            if let Some(image) = image {
+                preview.insert(ImageNode {
+                    color: image.color.with_alpha(0.6),
+                    ..image.clone()
+                });
+            }
+            preview.id()
+        }
+    };
+    let position = cursor - drag.grab;
+    let ghost = commands
+        .spawn((
+            Name::new("DragGhost"),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(position.x),
+                top: Val::Px(position.y),
+                ..Default::default()
+            },
+            GlobalZIndex(GHOST_Z_INDEX),
+            FocusPolicy::Pass,
+        ))
+        .add_child(preview)
+        .id();
+    drag.active = true;
+    drag.ghost = Some(ghost);
+    started.write(DragStarted { source });
+    commands.queue(move |world: &mut World| mark_valid_targets(world, source));
+}
+
+fn mark_valid_targets(world: &mut World, source: Entity) {
+    let mut targets = world.query::<(Entity, &DropTarget)>();
+    let valid: Vec<Entity> = targets
+        .iter(world)
+        .filter(|(target, drop)| *target != source && drop.accepts(world, source))
+        .map(|(target, _)| target)
+        .collect();
+    for target in valid {
+        world.entity_mut(target).insert(ValidDropTarget);
+    }
+}
+
+/// Moves the ghost with the cursor and highlights the accepting target under it.
+fn follow_cursor(
+    mut commands: Commands,
+    mut drag: ResMut<DragState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut nodes: Query<&mut Node>,
+    targets: Query<DropCandidate, With<ValidDropTarget>>,
+) {
+    if !drag.active {
+        return;
+    }
+    let Some(cursor) = cursor(&windows) else {
+        return;
+    };
+    if let Some(ghost) = drag.ghost
+        && let Ok(mut node) = nodes.get_mut(ghost)
+    {
+        let position = cursor - drag.grab;
+        node.left = Val::Px(position.x);
+        node.top = Val::Px(position.y);
+    }
+
+    // Nested targets take precedence
+    let hovered = targets
+        .iter()
+        .filter(|(_, relative, ..)| relative.mouse_over())
+        .max_by_key(|(_, _, node, _)| node.stack_index())
+        .map(|(target, ..)| target);
+    if hovered != drag.hovered {
+        drag.hovered = hovered;
+        if let Some(highlight) = drag.highlight.take() {
+            commands.entity(highlight).try_despawn();
+        }
+    }
+    let Some((_, _, node, transform)) = drag.hovered.and_then(|target| targets.get(target).ok())
+    else {
+        return;
+    };
+    // A root node rather than a child, so the target's own layout and children are left alone
+    let rect = logical_rect(node, transform);
+    match drag
+        .highlight
+        .and_then(|highlight| nodes.get_mut(highlight).ok())
+    {
+        Some(mut node) => cover(&mut node, rect),
+        None => {
+            let mut node = Node {
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(2.0)),
+                ..Default::default()
+            };
+            cover(&mut node, rect);
+            let highlight = commands
+                .spawn((
+                    DropHighlight,
+                    node,
+                    BackgroundColor(HIGHLIGHT_COLOR.into()),
+                    BorderColor(HIGHLIGHT_BORDER_COLOR.into()),
+                    GlobalZIndex(GHOST_Z_INDEX - 1),
+                    FocusPolicy::Pass,
+                ))
+                .id();
+            drag.highlight = Some(highlight);
+        }
+    }
+}
+
+/// Returns the bounds of a node, in logical pixels.
+fn logical_rect(node: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    let scale = node.inverse_scale_factor();
+    Rect::from_center_size(
+        transform.translation().truncate() * scale,
+        node.size() * scale,
+    )
+}
+
+/// Places an absolutely positioned node over `rect`.
+fn cover(node: &mut Node, rect: Rect) {
+    node.left = Val::Px(rect.min.x);
+    node.top = Val::Px(rect.min.y);
+    node.width = Val::Px(rect.width());
+    node.height = Val::Px(rect.height());
+}
+
+/// Drops on release, or cancels on Escape, a release outside any target or a despawned source.
+pub(crate) fn end_drags(
+    mut commands: Commands,
+    mut drag: ResMut<DragState>,
+    (mouse, keys): (Res<ButtonInput<MouseButton>>, Res<ButtonInput<KeyCode>>),
+    sources: Query<(), With<Draggable>>,
+    valid: Query<Entity, With<ValidDropTarget>>,
+    mut dropped: EventWriter<Dropped>,
+    mut cancelled: EventWriter<DragCancelled>,
+) {
+    let Some(source) = drag.source.filter(|_| drag.active) else {
+        return;
+    };
+    let released = mouse.just_released(MouseButton::Left) || !mouse.pressed(MouseButton::Left);
+    let cancel = keys.just_pressed(KeyCode::Escape) || !sources.contains(source);
+    if !released && !cancel {
+        return;
+    }
+
+    for entity in [drag.ghost, drag.highlight].into_iter().flatten() {
+        commands.entity(entity).try_despawn();
+    }
+    for target in &valid {
+        commands.entity(target).remove::<ValidDropTarget>();
+    }
+    match drag.hovered.filter(|_| !cancel) {
+        Some(target) => {
+            let drop = Dropped { source, target };
+            dropped.write(drop);
+            commands.queue(move |world: &mut World| {
+                let Some(target) = world.get::<DropTarget>(target).cloned() else {
+                    return;
+                };
+                for payload in &target.payloads {
+                    if (payload.accepts)(world, source) {
+                        (payload.on_drop)(world, drop);
+                    }
+                }
+            });
+        }
+        None => {
+            cancelled.write(DragCancelled { source });
+        }
+    }
+    *drag = DragState::default();
+}
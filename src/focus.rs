@@ -0,0 +1,544 @@
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_math::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::modifier::ModifierView;
+
+pub mod prelude {
+    pub use super::{
+        Autofocus, CapturesHorizontalArrows, CapturesVerticalArrows, FocusDirection, FocusGained,
+        FocusLost, FocusPlugin, FocusRing, FocusScope, FocusScopeView, FocusSystems, FocusView,
+        Focusable, FocusedEntity,
+    };
+}
+
+/// Marks a view that can receive keyboard and gamepad focus.
+///
+/// Buttons are focusable by default. Views are visited by Tab in increasing
+/// `order`, ties broken by on-screen position (top to bottom, left to right).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Focusable {
+    /// Tab order, lower values come first
+    pub order: i32,
+    /// Disabled views are skipped by navigation
+    pub disabled: bool,
+}
+
+/// The view that currently has focus, if any.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FocusedEntity(pub Option<Entity>);
+
+/// Marks a view that receives focus when it appears.
+///
+/// Inside a [`FocusScope`] the view is focused when the scope opens, otherwise
+/// it is focused if nothing else has focus yet.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Autofocus;
+
+/// Marks a focusable view that uses the Up and Down arrow keys itself while focused,
+/// such as a number field stepping its value.
+///
+/// Focus still moves away from it with Tab, Left, Right and the D-pad.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CapturesVerticalArrows;
+
+/// Marks a focusable view that uses the Left and Right arrow keys and D-pad
+/// directions itself while focused, such as a tree row expanding its node.
+///
+/// Focus still moves away from it with Tab, Up and Down.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CapturesHorizontalArrows;
+
+/// Groups the focusable views below a container, such as a menu or a modal.
+///
+/// While a trapping scope is open, navigation only visits views inside it. When
+/// the scope is removed or despawned, focus returns to the view that had it
+/// before the scope opened.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusScope {
+    /// Restricts navigation to views inside this scope
+    pub trap: bool,
+    /// Returns focus to the previously focused view when the scope closes
+    pub restore: bool,
+}
+
+impl FocusScope {
+    /// A scope that traps focus and restores it when closed, as used by modals.
+    pub const fn trapped() -> Self {
+        Self {
+            trap: true,
+            restore: true,
+        }
+    }
+
+    /// A scope that only handles autofocus, leaving navigation unrestricted.
+    pub const fn group() -> Self {
+        Self {
+            trap: false,
+            restore: false,
+        }
+    }
+}
+
+impl Default for FocusScope {
+    fn default() -> Self {
+        Self::trapped()
+    }
+}
+
+/// Sent when a view receives focus.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusGained {
+    pub entity: Entity,
+}
+
+/// Sent when a view loses focus.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusLost {
+    pub entity: Entity,
+}
+
+/// Appearance of the outline drawn around the focused view.
+///
+/// The ring replaces the view's own `Outline`, which is restored when focus moves on.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct FocusRing {
+    /// Whether the focus ring is drawn at all
+    pub enabled: bool,
+    /// Outline width
+    pub width: Val,
+    /// Space between the outline and the view's edge
+    pub offset: Val,
+    /// Outline color
+    pub color: Color,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            width: Val::Px(2.0),
+            offset: Val::Px(2.0),
+            color: Srgba::rgb(0.25, 0.55, 1.0).into(),
+        }
+    }
+}
+
+/// A direction used for spatial navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FocusDirection {
+    /// Unit vector in UI space, where `y` grows downwards.
+    fn vector(self) -> Vec2 {
+        match self {
+            FocusDirection::Up => Vec2::NEG_Y,
+            FocusDirection::Down => Vec2::Y,
+            FocusDirection::Left => Vec2::NEG_X,
+            FocusDirection::Right => Vec2::X,
+        }
+    }
+}
+
+/// A trait for views that can take part in focus navigation.
+pub trait FocusView: ModifierView {
+    /// Returns a mutable reference to the focus settings of this view.
+    fn focusable_node(&mut self) -> &mut Focusable;
+
+    /// Sets the Tab order of this view.
+    ///
+    /// # Arguments
+    /// * `order` - Views with lower values are visited first.
+    fn focus_order(mut self, order: i32) -> Self {
+        self.focusable_node().order = order;
+        self
+    }
+
+    /// Excludes this view from focus navigation.
+    fn focus_disabled(mut self, disabled: bool) -> Self {
+        self.focusable_node().disabled = disabled;
+        self
+    }
+
+    /// Focuses this view when it appears, see [`Autofocus`].
+    fn autofocus(self) -> Self {
+        self.modifier(|entity| {
+            entity.insert(Autofocus);
+        })
+    }
+}
+
+/// A trait for containers that can open a [`FocusScope`].
+pub trait FocusScopeView: ModifierView {
+    /// Makes this container a focus scope.
+    ///
+    /// # Arguments
+    /// * `scope` - Trap and restore behavior, e.g. `FocusScope::trapped()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use swoop_ui::prelude::*;
+    /// VStack::new().focus_scope(FocusScope::trapped());
+    /// ```
+    fn focus_scope(self, scope: FocusScope) -> Self {
+        self.modifier(move |entity| {
+            entity.insert(scope);
+        })
+    }
+}
+
+/// System set containing focus navigation, run in `PreUpdate` after UI interaction.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FocusSystems;
+
+/// Keyboard and gamepad focus navigation between focusable views.
+///
+/// Arrow keys and the D-pad move focus spatially, Tab and Shift+Tab follow the
+/// Tab order, and Enter, Space or the gamepad South button activate the focused
+/// view by pressing its `Interaction`, just like a mouse click. Trapping
+/// [`FocusScope`]s restrict navigation to their descendants.
+///
+/// Requires Bevy's `InputPlugin`.
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FocusedEntity>()
+            .init_resource::<FocusRing>()
+            .init_resource::<OpenFocusScopes>()
+            .add_event::<FocusGained>()
+            .add_event::<FocusLost>()
+            .configure_sets(
+                PreUpdate,
+                FocusSystems
+                    .after(bevy_input::InputSystem)
+                    .after(bevy_ui::UiSystem::Focus),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    release_activated,
+                    update_focus_scopes,
+                    navigate_focus,
+                    send_focus_events,
+                    draw_focus_ring,
+                )
+                    .chain()
+                    .in_set(FocusSystems),
+            );
+    }
+}
+
+/// Marks a view pressed by keyboard or gamepad activation, released next frame.
+#[derive(Component)]
+pub(crate) struct ActivatedByFocus;
+
+/// A focus scope that is open, with the view to refocus when it closes.
+struct OpenScope {
+    scope: Entity,
+    trap: bool,
+    restore_to: Option<Entity>,
+}
+
+/// Open focus scopes, innermost last.
+#[derive(Resource, Default)]
+pub(crate) struct OpenFocusScopes(Vec<OpenScope>);
+
+impl OpenFocusScopes {
+    /// The most recently opened scope that traps focus.
+    pub(crate) fn trap(&self) -> Option<Entity> {
+        self.0
+            .iter()
+            .rev()
+            .find(|open| open.trap)
+            .map(|open| open.scope)
+    }
+}
+
+type FocusCandidate = (
+    Entity,
+    &'static Focusable,
+    &'static ComputedNode,
+    &'static GlobalTransform,
+);
+
+/// Returns whether `entity` is `scope` or one of its descendants.
+fn is_within(entity: Entity, scope: Entity, parents: &Query<&ChildOf>) -> bool {
+    entity == scope
+        || parents
+            .iter_ancestors(entity)
+            .any(|ancestor| ancestor == scope)
+}
+
+/// Collects focusable views that are currently on screen, in Tab order.
+fn focus_candidates(
+    views: &Query<FocusCandidate>,
+    trap: Option<Entity>,
+    parents: &Query<&ChildOf>,
+) -> Vec<(Entity, Vec2)> {
+    let mut candidates: Vec<_> = views
+        .iter()
+        // Views hidden with `Display::None` have no size
+        .filter(|(_, focusable, node, _)| !focusable.disabled && node.size() != Vec2::ZERO)
+        .filter(|(entity, ..)| trap.is_none_or(|scope| is_within(*entity, scope, parents)))
+        .map(|(entity, focusable, _, transform)| {
+            (entity, focusable.order, transform.translation().truncate())
+        })
+        .collect();
+    candidates.sort_by(|(_, a_order, a), (_, b_order, b)| {
+        a_order
+            .cmp(b_order)
+            .then(a.y.total_cmp(&b.y))
+            .then(a.x.total_cmp(&b.x))
+    });
+    candidates
+        .into_iter()
+        .map(|(entity, _, center)| (entity, center))
+        .collect()
+}
+
+/// Picks the nearest candidate in `direction`, preferring ones that are well aligned.
+pub(crate) fn nearest_in_direction(
+    from: Vec2,
+    direction: FocusDirection,
+    candidates: impl IntoIterator<Item = (Entity, Vec2)>,
+) -> Option<Entity> {
+    let axis = direction.vector();
+    candidates
+        .into_iter()
+        .filter_map(|(entity, center)| {
+            let delta = center - from;
+            let along = delta.dot(axis);
+            if along <= 0.5 {
+                return None;
+            }
+            let across = (delta - axis * along).length();
+            Some((entity, along + 2.0 * across))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(entity, _)| entity)
+}
+
+fn release_activated(
+    mut commands: Commands,
+    mut pressed: Query<(Entity, &mut Interaction), With<ActivatedByFocus>>,
+) {
+    for (entity, mut interaction) in &mut pressed {
+        if *interaction == Interaction::Pressed {
+            *interaction = Interaction::None;
+        }
+        commands.entity(entity).remove::<ActivatedByFocus>();
+    }
+}
+
+/// Opens newly added scopes, restores focus for closed ones and applies autofocus.
+fn update_focus_scopes(
+    mut focused: ResMut<FocusedEntity>,
+    mut open: ResMut<OpenFocusScopes>,
+    mut closed: RemovedComponents<FocusScope>,
+    opened: Query<(Entity, &FocusScope), Added<FocusScope>>,
+    autofocused: Query<Entity, Added<Autofocus>>,
+    focusables: Query<(Entity, &Focusable, Has<Autofocus>)>,
+    (children, parents): (Query<&Children>, Query<&ChildOf>),
+) {
+    for scope in closed.read() {
+        let Some(index) = open.0.iter().position(|open| open.scope == scope) else {
+            continue;
+        };
+        let closed = open.0.remove(index);
+        let innermost = index == open.0.len();
+        // Closing a scope below another open scope must not steal focus from it
+        let lost_focus = focused
+            .0
+            .is_none_or(|entity| focusables.get(entity).is_err());
+        if (innermost || lost_focus)
+            && let Some(entity) = closed.restore_to
+            && focusables.get(entity).is_ok()
+        {
+            focused.0 = Some(entity);
+        } else if lost_focus {
+            focused.0 = None;
+        }
+    }
+
+    for entity in &autofocused {
+        if focused.0.is_none() && !parents.iter_ancestors(entity).any(|e| opened.contains(e)) {
+            focused.0 = Some(entity);
+        }
+    }
+
+    for (scope, settings) in &opened {
+        open.0.push(OpenScope {
+            scope,
+            trap: settings.trap,
+            restore_to: focused.0.filter(|_| settings.restore),
+        });
+        // Layout has not run for new views yet, so pick by Tab order and spawn order
+        let mut inside: Vec<_> = children
+            .iter_descendants(scope)
+            .filter_map(|entity| focusables.get(entity).ok())
+            .filter(|(_, focusable, _)| !focusable.disabled)
+            .collect();
+        inside.sort_by_key(|(_, focusable, _)| focusable.order);
+        let first = inside
+            .iter()
+            .find(|(_, _, autofocus)| *autofocus)
+            .or(inside.first().filter(|_| settings.trap));
+        if let Some((entity, ..)) = first {
+            focused.0 = Some(*entity);
+        }
+    }
+}
+
+/// Whether a view captures the vertical and horizontal arrows.
+type CapturedArrows = (Has<CapturesVerticalArrows>, Has<CapturesHorizontalArrows>);
+
+fn navigate_focus(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut focused: ResMut<FocusedEntity>,
+    (open, parents, captures): (Res<OpenFocusScopes>, Query<&ChildOf>, Query<CapturedArrows>),
+    views: Query<FocusCandidate>,
+    mut interactions: Query<&mut Interaction>,
+) {
+    let gamepad_pressed = |button| gamepads.iter().any(|pad| pad.just_pressed(button));
+    // Arrows captured by the focused view do not move focus
+    let (vertical_keys, horizontal_keys) = focused
+        .0
+        .and_then(|entity| captures.get(entity).ok())
+        .map_or((true, true), |(vertical, horizontal)| {
+            (!vertical, !horizontal)
+        });
+    let key_pressed = |key| vertical_keys && keys.just_pressed(key);
+    let horizontal_pressed =
+        |key, button| horizontal_keys && (keys.just_pressed(key) || gamepad_pressed(button));
+    let direction = if key_pressed(KeyCode::ArrowUp) || gamepad_pressed(GamepadButton::DPadUp) {
+        Some(FocusDirection::Up)
+    } else if key_pressed(KeyCode::ArrowDown) || gamepad_pressed(GamepadButton::DPadDown) {
+        Some(FocusDirection::Down)
+    } else if horizontal_pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft) {
+        Some(FocusDirection::Left)
+    } else if horizontal_pressed(KeyCode::ArrowRight, GamepadButton::DPadRight) {
+        Some(FocusDirection::Right)
+    } else {
+        None
+    };
+    let tab = keys.just_pressed(KeyCode::Tab);
+    let backwards = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let activate = keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space])
+        || gamepad_pressed(GamepadButton::South);
+
+    if let Some(entity) = focused.0
+        && views.get(entity).is_err()
+    {
+        // The focused view was despawned or is no longer focusable
+        focused.0 = None;
+    }
+    let candidates = focus_candidates(&views, open.trap(), &parents);
+    let current = focused
+        .0
+        .and_then(|entity| candidates.iter().position(|(e, _)| *e == entity));
+    if candidates.is_empty() {
+        return;
+    }
+
+    if tab {
+        let next = match current {
+            Some(index) if backwards => (index + candidates.len() - 1) % candidates.len(),
+            Some(index) => (index + 1) % candidates.len(),
+            None if backwards => candidates.len() - 1,
+            None => 0,
+        };
+        focused.0 = Some(candidates[next].0);
+    } else if let Some(direction) = direction {
+        match current {
+            Some(index) => {
+                let (entity, center) = candidates[index];
+                let others = candidates.iter().copied().filter(|(e, _)| *e != entity);
+                if let Some(next) = nearest_in_direction(center, direction, others) {
+                    focused.0 = Some(next);
+                }
+            }
+            None => focused.0 = Some(candidates[0].0),
+        }
+    } else if activate
+        && let Some(entity) = focused.0
+        && let Ok(mut interaction) = interactions.get_mut(entity)
+    {
+        *interaction = Interaction::Pressed;
+        commands.entity(entity).insert(ActivatedByFocus);
+    }
+}
+
+fn send_focus_events(
+    focused: Res<FocusedEntity>,
+    mut previous: Local<Option<Entity>>,
+    mut lost: EventWriter<FocusLost>,
+    mut gained: EventWriter<FocusGained>,
+) {
+    if !focused.is_changed() || focused.0 == *previous {
+        return;
+    }
+    if let Some(entity) = *previous {
+        lost.write(FocusLost { entity });
+    }
+    if let Some(entity) = focused.0 {
+        gained.write(FocusGained { entity });
+    }
+    *previous = focused.0;
+}
+
+/// The outline a view had before the focus ring replaced it.
+#[derive(Component)]
+struct OutlineUnderRing(Outline);
+
+fn draw_focus_ring(
+    mut commands: Commands,
+    focused: Res<FocusedEntity>,
+    ring: Res<FocusRing>,
+    mut previous: Local<Option<Entity>>,
+    outlines: Query<(Option<&Outline>, Option<&OutlineUnderRing>)>,
+) {
+    if !focused.is_changed() && !ring.is_changed() {
+        return;
+    }
+    let ringed = previous.take();
+    if let Some(entity) = ringed
+        && let Ok(mut entity_commands) = commands.get_entity(entity)
+    {
+        match outlines.get(entity) {
+            Ok((_, Some(under))) => {
+                entity_commands.insert(under.0).remove::<OutlineUnderRing>();
+            }
+            _ => {
+                entity_commands.remove::<Outline>();
+            }
+        }
+    }
+    if let Some(entity) = focused.0
+        && ring.enabled
+        && let Ok(mut entity_commands) = commands.get_entity(entity)
+    {
+        // The view's own outline, unless it is the ring drawn last time
+        let own = match outlines.get(entity) {
+            Ok((_, Some(under))) => Some(under.0),
+            Ok((Some(outline), None)) if ringed != Some(entity) => Some(*outline),
+            _ => None,
+        };
+        if let Some(own) = own {
+            entity_commands.insert(OutlineUnderRing(own));
+        }
+        entity_commands.insert(Outline::new(ring.width, ring.offset, ring.color));
+        *previous = Some(entity);
+    }
+}
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_ecs::component::{Mutable, Tick};
+use bevy_ecs::prelude::*;
+
+use crate::animation::AnimationSystems;
+use crate::binding::{BindingSystems, Bindings, changed_since, write_back};
+use crate::focus::FocusSystems;
+use crate::modifier::ModifierView;
+
+/// Color picked from gradients, a hex code or swatches
+pub mod color_picker;
+/// Field for typing a number within a range
+pub mod number_field;
+/// Choice between a list of options
+pub mod picker;
+/// Slider over a numeric range
+pub mod slider;
+/// Value stepped with minus and plus buttons
+pub mod stepper;
+/// Single line text field
+pub mod text_input;
+/// On/off switch
+pub mod toggle;
+
+pub mod prelude {
+    pub use super::color_picker::{
+        ColorChanged, ColorCommitted, ColorPicker, ColorPickerOptions, ColorPickerState,
+        ColorPickerView,
+    };
+    pub use super::number_field::{NumberField, NumberFieldEdit};
+    pub use super::picker::{Picker, PickerState, PickerView};
+    pub use super::slider::{Slider, SliderState, SliderView};
+    pub use super::stepper::{NumberChanged, NumberState, NumberView, Stepper};
+    pub use super::text_input::{TextInput, TextInputState, TextInputView};
+    pub use super::toggle::{Toggle, ToggleState, ToggleView};
+    pub use super::{InputState, InputView, InputViewPlugin, InputViewSystems};
+}
+
+/// The editable value of an input view, stored as a component.
+pub trait InputState: Component<Mutability = Mutable> {
+    /// The value edited by the user
+    type Value: Clone + PartialEq + Send + Sync + 'static;
+
+    /// Returns the current value.
+    fn value(&self) -> Self::Value;
+
+    /// Replaces the current value.
+    fn set_value(&mut self, value: Self::Value);
+}
+
+/// A trait for views that edit a value, such as toggles and sliders.
+///
+/// The value can be bound to a field of a resource or component. Bindings are
+/// synchronized both ways once per frame using change detection: edits in the
+/// view are written to the field, and changes to the field are shown in the view.
+pub trait InputView: ModifierView {
+    /// The component holding the edited value
+    type State: InputState;
+
+    /// Returns a mutable reference to the state of this view.
+    fn input_node(&mut self) -> &mut Self::State;
+
+    /// Sets the initial value.
+    fn value(mut self, value: <Self::State as InputState>::Value) -> Self {
+        self.input_node().set_value(value);
+        self
+    }
+
+    /// Binds the value to a field of a resource.
+    ///
+    /// # Arguments
+    /// * `field` - Returns the bound field, e.g. `|s: &mut AudioSettings| &mut s.volume`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # #[derive(Resource)]
+    /// # struct AudioSettings {
+    /// #     volume: f32,
+    /// # }
+    /// Slider::new().bind(|s: &mut AudioSettings| &mut s.volume);
+    /// ```
+    fn bind<R: Resource>(
+        self,
+        field: impl Fn(&mut R) -> &mut <Self::State as InputState>::Value + Send + Sync + 'static,
+    ) -> Self {
+        let field = Arc::new(field);
+        self.modifier(move |entity| {
+            let field = field.clone();
+            Bindings::attach(entity, move |world, view, last_run| {
+                let this_run = world.read_change_tick();
+                let Some(mut resource) = world.get_resource_mut::<R>() else {
+                    return;
+                };
+                let changed = changed_since(resource.last_changed(), last_run, this_run);
+                let current = field(resource.bypass_change_detection()).clone();
+                if let Some(value) = sync::<Self::State>(world, view, last_run, current, changed) {
+                    let field = field.clone();
+                    write_back(world, move |world| {
+                        if let Some(mut resource) = world.get_resource_mut::<R>() {
+                            *field(resource.bypass_change_detection()) = value;
+                            resource.set_changed();
+                        }
+                    });
+                }
+            });
+        })
+    }
+
+    /// Binds the value to a field of a component on `source`.
+    ///
+    /// # Arguments
+    /// * `source` - The entity holding the component.
+    /// * `field` - Returns the bound field, e.g. `|p: &mut Player| &mut p.name`.
+    fn bind_component<C: Component<Mutability = Mutable>>(
+        self,
+        source: Entity,
+        field: impl Fn(&mut C) -> &mut <Self::State as InputState>::Value + Send + Sync + 'static,
+    ) -> Self {
+        let field = Arc::new(field);
+        self.modifier(move |entity| {
+            let field = field.clone();
+            Bindings::attach(entity, move |world, view, last_run| {
+                let this_run = world.read_change_tick();
+                let Some(mut component) = world.get_mut::<C>(source) else {
+                    return;
+                };
+                let changed = changed_since(component.last_changed(), last_run, this_run);
+                let current = field(component.bypass_change_detection()).clone();
+                if let Some(value) = sync::<Self::State>(world, view, last_run, current, changed) {
+                    let field = field.clone();
+                    write_back(world, move |world| {
+                        if let Some(mut component) = world.get_mut::<C>(source) {
+                            *field(component.bypass_change_detection()) = value;
+                            component.set_changed();
+                        }
+                    });
+                }
+            });
+        })
+    }
+}
+
+/// Reconciles a view's state with its bound field.
+///
+/// Edits made in the view win over changes to the field in the same frame.
+/// Returns the value to write to the field, if it needs updating.
+fn sync<S: InputState>(
+    world: &mut World,
+    view: Entity,
+    last_run: Option<Tick>,
+    field: S::Value,
+    field_changed: bool,
+) -> Option<S::Value> {
+    let this_run = world.read_change_tick();
+    let mut state = world.get_mut::<S>(view)?;
+    let value = state.value();
+    if value == field {
+        return None;
+    }
+    if last_run.is_some() && changed_since(state.last_changed(), last_run, this_run) {
+        Some(value)
+    } else if field_changed {
+        state.set_value(field);
+        None
+    } else {
+        None
+    }
+}
+
+/// System sets of the input views.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputViewSystems {
+    /// Handles pointer and keyboard input, run in `PreUpdate` after focus navigation
+    Interact,
+    /// Updates the visuals from the state, run in `PostUpdate` after bindings
+    Visuals,
+}
+
+/// Interaction and visuals of [`Toggle`](toggle::Toggle), [`Slider`](slider::Slider),
+/// [`TextInput`](text_input::TextInput), [`Picker`](picker::Picker),
+/// [`Stepper`](stepper::Stepper), [`NumberField`](number_field::NumberField) and
+/// [`ColorPicker`](color_picker::ColorPicker).
+///
+/// Requires Bevy's `InputPlugin` and `TimePlugin`.
+pub struct InputViewPlugin;
+
+impl Plugin for InputViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<stepper::NumberChanged>()
+            .add_event::<color_picker::ColorChanged>()
+            .add_event::<color_picker::ColorCommitted>()
+            .add_systems(Startup, color_picker::add_gradient_images)
+            .configure_sets(PreUpdate, InputViewSystems::Interact.after(FocusSystems))
+            .configure_sets(
+                PostUpdate,
+                InputViewSystems::Visuals
+                    .after(BindingSystems)
+                    .before(AnimationSystems),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    toggle::toggle_on_press,
+                    slider::drag_slider,
+                    slider::step_slider_with_keys,
+                    text_input::focus_on_press,
+                    text_input::edit_text,
+                    picker::cycle_on_press,
+                    stepper::step_on_press,
+                    number_field::focus_on_press,
+                    number_field::edit_number,
+                    color_picker::drag_color_picker,
+                    color_picker::press_color_swatches,
+                    color_picker::commit_color_hex,
+                )
+                    .in_set(InputViewSystems::Interact),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    toggle::update_toggle,
+                    slider::update_slider,
+                    text_input::update_text_input,
+                    picker::update_picker,
+                    stepper::update_stepper,
+                    number_field::update_number_field,
+                    color_picker::update_color_picker,
+                )
+                    .in_set(InputViewSystems::Visuals),
+            );
+    }
+}
@@ -0,0 +1,739 @@
+use bevy_asset::prelude::*;
+use bevy_asset::weak_handle;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::spawn::SpawnIter;
+use bevy_image::{Image, ImageSampler};
+use bevy_input::ButtonState;
+use bevy_input::keyboard::{Key, KeyboardInput};
+use bevy_input::prelude::*;
+use bevy_math::Vec2;
+use bevy_ui::RelativeCursorPosition;
+use bevy_ui::prelude::*;
+
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::FocusedEntity;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::text::TextView;
+use crate::{View, ViewToBundle};
+
+use super::text_input::{TextInput, TextInputState, TextInputView};
+use super::{InputState, InputView};
+
+/// White fading to transparent from left to right, laid over the hue.
+const SATURATION_IMAGE: Handle<Image> = weak_handle!("5c0f7f0e-3b8a-4c55-9a41-61d1b3a7e201");
+/// Transparent fading to black from top to bottom, laid over the saturation.
+const VALUE_IMAGE: Handle<Image> = weak_handle!("5c0f7f0e-3b8a-4c55-9a41-61d1b3a7e202");
+/// Every hue from left to right.
+const HUE_IMAGE: Handle<Image> = weak_handle!("5c0f7f0e-3b8a-4c55-9a41-61d1b3a7e203");
+/// Transparent fading to white from left to right, tinted with the picked color.
+const ALPHA_IMAGE: Handle<Image> = weak_handle!("5c0f7f0e-3b8a-4c55-9a41-61d1b3a7e204");
+
+/// The color of a [`ColorPicker`].
+///
+/// The color is kept as hue, saturation and value, so the hue survives picking greys.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ColorPickerState {
+    pub color: Hsva,
+}
+
+impl ColorPickerState {
+    /// Formats the color in CSS-style hexadecimal notation, with alpha if not opaque.
+    pub fn hex(&self) -> String {
+        Srgba::from(self.color).to_hex()
+    }
+}
+
+impl Default for ColorPickerState {
+    fn default() -> Self {
+        Self {
+            color: Hsva::new(0.0, 0.0, 1.0, 1.0),
+        }
+    }
+}
+
+impl InputState for ColorPickerState {
+    type Value = Color;
+
+    fn value(&self) -> Color {
+        Color::Srgba(self.color.into())
+    }
+
+    fn set_value(&mut self, value: Color) {
+        let mut color = Hsva::from(value);
+        // Hue is undefined for greys, and saturation for black
+        if color.saturation == 0.0 || color.value == 0.0 {
+            color.hue = self.color.hue;
+        }
+        if color.value == 0.0 {
+            color.saturation = self.color.saturation;
+        }
+        self.color = color;
+    }
+}
+
+/// Sent whenever the user changes the color of a [`ColorPicker`], continuously while
+/// dragging.
+///
+/// Values written by bindings or from code do not send it.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct ColorChanged {
+    pub entity: Entity,
+    pub color: Color,
+}
+
+/// Sent when the user settles on a color: on release after dragging, and when a swatch
+/// is pressed or a hex code is entered.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct ColorCommitted {
+    pub entity: Entity,
+    pub color: Color,
+}
+
+/// Which part of the color a pointer area of a [`ColorPicker`] edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ColorPart {
+    SaturationValue,
+    Hue,
+    Alpha,
+}
+
+/// An area of a [`ColorPicker`] edited by pressing or dragging.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ColorPickerArea {
+    part: ColorPart,
+    /// Whether a drag started on this area is in progress
+    dragging: bool,
+}
+
+/// The marker showing the picked position within an area.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ColorPickerThumb(ColorPart);
+
+/// The alpha gradient, tinted with the picked color.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub(crate) struct ColorPickerAlphaFill;
+
+/// Shows the picked color.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub(crate) struct ColorPickerPreview;
+
+/// The text input for hex codes.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub(crate) struct ColorPickerHex;
+
+/// A preset color picked by pressing it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ColorPickerSwatch(Color);
+
+/// Parts shown by a [`ColorPicker`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorPickerOptions {
+    /// Whether the alpha strip is shown
+    pub show_alpha: bool,
+    /// Preset colors shown below the inputs
+    pub swatches: Vec<Color>,
+}
+
+impl Default for ColorPickerOptions {
+    fn default() -> Self {
+        Self {
+            show_alpha: true,
+            swatches: Vec::new(),
+        }
+    }
+}
+
+/// A trait for configuring a [`ColorPicker`].
+pub trait ColorPickerView: View {
+    /// Returns a mutable reference to the options of this color picker.
+    fn color_picker_node(&mut self) -> &mut ColorPickerOptions;
+
+    /// Sets whether the alpha strip is shown.
+    fn show_alpha(mut self, show_alpha: bool) -> Self {
+        self.color_picker_node().show_alpha = show_alpha;
+        self
+    }
+
+    /// Sets the preset colors shown below the inputs.
+    fn swatches<C: Into<Color>>(mut self, swatches: impl IntoIterator<Item = C>) -> Self {
+        self.color_picker_node().swatches = swatches.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// A saturation and value square, hue and alpha strips, a hex code input and optional
+/// swatches for picking a color.
+///
+/// Requires Bevy's `ImagePlugin` for the gradients to show.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_color::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Resource)]
+/// # struct Character {
+/// #     hair_color: Color,
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(
+///     ColorPicker::new()
+///         .swatches([Srgba::RED, Srgba::GREEN, Srgba::BLUE])
+///         .bind(|c: &mut Character| &mut c.hair_color)
+///         .pack(),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColorPicker {
+    /// Name tag for debugging or entity inspection.
+    name: Name,
+
+    /// Layout node stacking the parts in a column.
+    node: Node,
+
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
+    /// The picked color.
+    state: ColorPickerState,
+
+    /// Parts shown below the square.
+    options: ColorPickerOptions,
+
+    /// Visual styling for borders (widths, colors, radius).
+    border: BorderStyle,
+
+    /// Visual background style (fill, gradient, texture).
+    background: BackgroundStyle,
+
+    /// Optional outer shadow to simulate depth or elevation.
+    box_shadow: BoxShadow,
+}
+
+impl Default for ColorPicker {
+    fn default() -> Self {
+        Self {
+            name: Name::new("ColorPicker"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                width: Val::Px(216.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(1.0)),
+                ..Default::default()
+            },
+            class: ViewClass::new("ColorPicker"),
+            modifiers: ViewModifiers::default(),
+            state: ColorPickerState::default(),
+            options: ColorPickerOptions::default(),
+            border: BorderStyle::panel(),
+            background: BackgroundStyle::button(),
+            box_shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl View for ColorPicker {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for ColorPicker {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for ColorPicker {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ViewToBundle for ColorPicker {
+    fn pack(self) -> impl Bundle {
+        let color = self.state.color;
+        let square = (
+            area(ColorPart::SaturationValue, Val::Px(140.0), Display::Flex),
+            BackgroundColor(Hsva::hsv(color.hue, 1.0, 1.0).into()),
+            BorderRadius::all(Val::Px(4.0)),
+            children![
+                overlay(SATURATION_IMAGE, Color::WHITE),
+                overlay(VALUE_IMAGE, Color::WHITE),
+                thumb(ColorPart::SaturationValue, color),
+            ],
+        );
+        let hue = (
+            area(ColorPart::Hue, Val::Px(12.0), Display::Flex),
+            ImageNode::new(HUE_IMAGE),
+            BorderRadius::MAX,
+            children![thumb(ColorPart::Hue, color)],
+        );
+        let alpha_display = if self.options.show_alpha {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        let alpha = (
+            area(ColorPart::Alpha, Val::Px(12.0), alpha_display),
+            BackgroundColor(Srgba::gray(0.8).into()),
+            BorderRadius::MAX,
+            children![
+                (
+                    ColorPickerAlphaFill,
+                    overlay(ALPHA_IMAGE, opaque(color)),
+                    BorderRadius::MAX
+                ),
+                thumb(ColorPart::Alpha, color),
+            ],
+        );
+        let inputs = (
+            Name::new("ColorPickerInputs"),
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            children![
+                (
+                    Name::new("ColorPickerPreview"),
+                    ColorPickerPreview,
+                    Node {
+                        width: Val::Px(28.0),
+                        height: Val::Px(28.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..Default::default()
+                    },
+                    BackgroundColor(color.into()),
+                    BorderColor(Srgba::gray(0.7).into()),
+                    BorderRadius::all(Val::Px(4.0)),
+                ),
+                (
+                    ColorPickerHex,
+                    TextInput::new()
+                        .max_length(9)
+                        .value(self.state.hex())
+                        .font_size(14.0)
+                        .flex_grow(1.0)
+                        .pack(),
+                ),
+            ],
+        );
+        let swatches = (
+            Name::new("ColorPickerSwatches"),
+            Node {
+                display: if self.options.swatches.is_empty() {
+                    Display::None
+                } else {
+                    Display::Flex
+                },
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(6.0),
+                row_gap: Val::Px(6.0),
+                ..Default::default()
+            },
+            Children::spawn(SpawnIter(self.options.swatches.into_iter().map(swatch))),
+        );
+        (
+            self.name,
+            self.node,
+            self.class,
+            self.modifiers,
+            self.state,
+            self.border,
+            self.background,
+            self.box_shadow,
+            children![square, hue, alpha, inputs, swatches,],
+        )
+    }
+}
+
+/// A pressable area of the picker, as wide as the picker.
+fn area(part: ColorPart, height: Val, display: Display) -> impl Bundle {
+    (
+        Name::new("ColorPickerArea"),
+        ColorPickerArea {
+            part,
+            dragging: false,
+        },
+        Node {
+            display,
+            height,
+            ..Default::default()
+        },
+        Button,
+        RelativeCursorPosition::default(),
+    )
+}
+
+/// A gradient image covering its area.
+fn overlay(image: Handle<Image>, tint: Color) -> impl Bundle {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..Default::default()
+        },
+        ImageNode::new(image).with_color(tint),
+    )
+}
+
+fn thumb(part: ColorPart, color: Hsva) -> impl Bundle {
+    let (width, height) = match part {
+        ColorPart::SaturationValue => (14.0, 14.0),
+        ColorPart::Hue | ColorPart::Alpha => (6.0, 16.0),
+    };
+    let mut node = Node {
+        position_type: PositionType::Absolute,
+        width: Val::Px(width),
+        height: Val::Px(height),
+        margin: UiRect {
+            left: Val::Px(-width / 2.0),
+            top: Val::Px(-height / 2.0),
+            ..Default::default()
+        },
+        border: UiRect::all(Val::Px(2.0)),
+        ..Default::default()
+    };
+    place_thumb(&mut node, part, color);
+    (
+        Name::new("ColorPickerThumb"),
+        ColorPickerThumb(part),
+        node,
+        BorderColor(Color::WHITE),
+        BorderRadius::MAX,
+        BoxShadow::new(
+            Srgba::new(0.0, 0.0, 0.0, 0.3).into(),
+            Val::ZERO,
+            Val::Px(1.0),
+            Val::ZERO,
+            Val::Px(2.0),
+        ),
+    )
+}
+
+fn swatch(color: Color) -> impl Bundle {
+    (
+        Name::new("ColorPickerSwatch"),
+        ColorPickerSwatch(color),
+        Node {
+            width: Val::Px(20.0),
+            height: Val::Px(20.0),
+            border: UiRect::all(Val::Px(1.0)),
+            ..Default::default()
+        },
+        Button,
+        BackgroundColor(color),
+        BorderColor(Srgba::gray(0.7).into()),
+        BorderRadius::all(Val::Px(4.0)),
+    )
+}
+
+/// Moves a thumb to where `color` lies within its area.
+fn place_thumb(node: &mut Node, part: ColorPart, color: Hsva) {
+    let (x, y) = match part {
+        ColorPart::SaturationValue => (color.saturation, 1.0 - color.value),
+        ColorPart::Hue => (color.hue / 360.0, 0.5),
+        ColorPart::Alpha => (color.alpha, 0.5),
+    };
+    let (left, top) = (Val::Percent(x * 100.0), Val::Percent(y * 100.0));
+    if node.left != left {
+        node.left = left;
+    }
+    if node.top != top {
+        node.top = top;
+    }
+}
+
+fn opaque(color: Hsva) -> Color {
+    color.with_alpha(1.0).into()
+}
+
+impl ColorPickerView for ColorPicker {
+    fn color_picker_node(&mut self) -> &mut ColorPickerOptions {
+        &mut self.options
+    }
+}
+
+impl InputView for ColorPicker {
+    type State = ColorPickerState;
+
+    fn input_node(&mut self) -> &mut ColorPickerState {
+        &mut self.state
+    }
+}
+
+impl BackgroundView for ColorPicker {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for ColorPicker {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for ColorPicker {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.box_shadow
+    }
+}
+
+impl PositionView for ColorPicker {}
+
+impl AnimationView for ColorPicker {}
+
+/// Builds a gradient image varying along its longest side.
+///
+/// # Arguments
+/// * `pixel` - Returns the sRGB color at a fraction of the way along the gradient.
+fn gradient_image(width: u32, height: u32, pixel: impl Fn(f32) -> [u8; 4]) -> Image {
+    let length = width.max(height);
+    let mut image = Image::default();
+    image.texture_descriptor.size.width = width;
+    image.texture_descriptor.size.height = height;
+    image.data = Some(
+        (0..length)
+            .flat_map(|index| pixel(index as f32 / (length - 1) as f32))
+            .collect(),
+    );
+    image.sampler = ImageSampler::linear();
+    image
+}
+
+/// Adds the gradient images shared by all color pickers.
+pub(crate) fn add_gradient_images(images: Option<ResMut<Assets<Image>>>) {
+    let Some(mut images) = images else {
+        return;
+    };
+    let fade = |t: f32| (t * 255.0).round() as u8;
+    let gradients = [
+        (
+            SATURATION_IMAGE,
+            gradient_image(64, 1, |t| [255, 255, 255, fade(1.0 - t)]),
+        ),
+        (VALUE_IMAGE, gradient_image(1, 64, |t| [0, 0, 0, fade(t)])),
+        (
+            HUE_IMAGE,
+            gradient_image(64, 1, |t| {
+                Srgba::from(Hsva::hsv(t * 360.0, 1.0, 1.0)).to_u8_array()
+            }),
+        ),
+        (
+            ALPHA_IMAGE,
+            gradient_image(64, 1, |t| [255, 255, 255, fade(t)]),
+        ),
+    ];
+    for (handle, image) in gradients {
+        images.insert(&handle, image);
+    }
+}
+
+/// Finds the color picker an entity belongs to.
+fn picker_of(
+    entity: Entity,
+    parents: &Query<&ChildOf>,
+    pickers: &Query<&mut ColorPickerState>,
+) -> Option<Entity> {
+    parents
+        .iter_ancestors(entity)
+        .find(|ancestor| pickers.contains(*ancestor))
+}
+
+pub(crate) fn drag_color_picker(
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut areas: Query<(
+        Entity,
+        &Interaction,
+        &RelativeCursorPosition,
+        &mut ColorPickerArea,
+    )>,
+    parents: Query<&ChildOf>,
+    mut pickers: Query<&mut ColorPickerState>,
+    mut changed: EventWriter<ColorChanged>,
+    mut committed: EventWriter<ColorCommitted>,
+) {
+    // Keyboard and gamepad presses have no pointer to follow
+    let pointer_down = mouse.pressed(MouseButton::Left) || touches.iter().next().is_some();
+    for (entity, interaction, cursor, mut area) in &mut areas {
+        let Some(picker) = picker_of(entity, &parents, &pickers) else {
+            continue;
+        };
+        let Ok(mut state) = pickers.get_mut(picker) else {
+            continue;
+        };
+        if *interaction != Interaction::Pressed || !pointer_down {
+            if area.dragging {
+                area.dragging = false;
+                committed.write(ColorCommitted {
+                    entity: picker,
+                    color: state.value(),
+                });
+            }
+            continue;
+        }
+        if !area.dragging {
+            area.dragging = true;
+        }
+        let Some(position) = cursor.normalized else {
+            continue;
+        };
+        let position = position.clamp(Vec2::ZERO, Vec2::ONE);
+        let mut color = state.color;
+        match area.part {
+            ColorPart::SaturationValue => {
+                color.saturation = position.x;
+                color.value = 1.0 - position.y;
+            }
+            ColorPart::Hue => color.hue = position.x * 360.0,
+            ColorPart::Alpha => color.alpha = position.x,
+        }
+        if color != state.color {
+            state.color = color;
+            changed.write(ColorChanged {
+                entity: picker,
+                color: state.value(),
+            });
+        }
+    }
+}
+
+pub(crate) fn press_color_swatches(
+    swatches: Query<(Entity, &Interaction, &ColorPickerSwatch), Changed<Interaction>>,
+    parents: Query<&ChildOf>,
+    mut pickers: Query<&mut ColorPickerState>,
+    mut changed: EventWriter<ColorChanged>,
+    mut committed: EventWriter<ColorCommitted>,
+) {
+    for (entity, interaction, swatch) in &swatches {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(picker) = picker_of(entity, &parents, &pickers) else {
+            continue;
+        };
+        let Ok(mut state) = pickers.get_mut(picker) else {
+            continue;
+        };
+        state.set_value(swatch.0);
+        let color = state.value();
+        changed.write(ColorChanged {
+            entity: picker,
+            color,
+        });
+        committed.write(ColorCommitted {
+            entity: picker,
+            color,
+        });
+    }
+}
+
+/// Applies typed hex codes on Enter or when the input loses focus.
+///
+/// Codes that do not parse are replaced with the current color.
+pub(crate) fn commit_color_hex(
+    mut keys: EventReader<KeyboardInput>,
+    focused: Res<FocusedEntity>,
+    mut hexes: Query<(Entity, &mut TextInputState), With<ColorPickerHex>>,
+    parents: Query<&ChildOf>,
+    mut pickers: Query<&mut ColorPickerState>,
+    mut events: (EventWriter<ColorChanged>, EventWriter<ColorCommitted>),
+) {
+    let submitted = keys
+        .read()
+        .any(|key| key.state == ButtonState::Pressed && key.logical_key == Key::Enter);
+    for (entity, mut input) in &mut hexes {
+        let has_focus = focused.0 == Some(entity);
+        if !(submitted && has_focus || focused.is_changed() && !has_focus) {
+            continue;
+        }
+        let Some(picker) = picker_of(entity, &parents, &pickers) else {
+            continue;
+        };
+        let Ok(mut state) = pickers.get_mut(picker) else {
+            continue;
+        };
+        if let Ok(typed) = Srgba::hex(input.text.trim())
+            && Color::from(typed) != state.value()
+        {
+            state.set_value(typed.into());
+            let color = state.value();
+            events.0.write(ColorChanged {
+                entity: picker,
+                color,
+            });
+            events.1.write(ColorCommitted {
+                entity: picker,
+                color,
+            });
+        }
+        let shown = state.hex();
+        if input.text != shown {
+            input.text = shown;
+        }
+    }
+}
+
+pub(crate) fn update_color_picker(
+    focused: Res<FocusedEntity>,
+    pickers: Query<(Entity, &ColorPickerState), Changed<ColorPickerState>>,
+    descendants: Query<&Children>,
+    mut backgrounds: Query<(
+        &mut BackgroundColor,
+        Option<&ColorPickerArea>,
+        Has<ColorPickerPreview>,
+    )>,
+    mut thumbs: Query<(&ColorPickerThumb, &mut Node)>,
+    mut fills: Query<&mut ImageNode, With<ColorPickerAlphaFill>>,
+    mut hexes: Query<&mut TextInputState, With<ColorPickerHex>>,
+) {
+    for (picker, state) in &pickers {
+        let color = state.color;
+        for part in descendants.iter_descendants(picker) {
+            if let Ok((mut background, area, preview)) = backgrounds.get_mut(part) {
+                if preview {
+                    background.0 = color.into();
+                } else if area.is_some_and(|area| area.part == ColorPart::SaturationValue) {
+                    background.0 = Hsva::hsv(color.hue, 1.0, 1.0).into();
+                }
+            }
+            if let Ok((thumb, mut node)) = thumbs.get_mut(part) {
+                place_thumb(&mut node, thumb.0, color);
+            }
+            if let Ok(mut fill) = fills.get_mut(part) {
+                fill.color = opaque(color);
+            }
+            if let Ok(mut input) = hexes.get_mut(part)
+                && focused.0 != Some(part)
+            {
+                let shown = state.hex();
+                if input.text != shown {
+                    input.text = shown;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,372 @@
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::keyboard::{Key, KeyboardInput};
+use bevy_text::JustifyText;
+use bevy_ui::prelude::*;
+
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::{CapturesVerticalArrows, FocusView, Focusable, FocusedEntity};
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::text::{TextStyle, TextView};
+use crate::{View, ViewToBundle};
+
+use super::InputView;
+use super::stepper::{NumberChanged, NumberState, NumberView, step_number};
+
+/// Border shown while the typed text is not a number within the range.
+const INVALID_COLOR: Srgba = Srgba::rgb(0.85, 0.2, 0.2);
+
+/// The text being typed into a [`NumberField`].
+///
+/// The value in [`NumberState`] only changes once the text is committed.
+#[derive(Component, Debug, Clone, PartialEq, Default)]
+pub struct NumberFieldEdit {
+    /// The typed text
+    pub text: String,
+    /// Whether the field is being edited
+    pub editing: bool,
+    /// Border color to restore once the text is valid again
+    normal_border: Option<Color>,
+}
+
+impl NumberFieldEdit {
+    /// Returns the typed number if it lies within the range of `state`.
+    pub fn parse(&self, state: &NumberState) -> Option<f32> {
+        self.text
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite() && (state.min..=state.max).contains(value))
+    }
+}
+
+/// Marks the text entity inside a [`NumberField`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct NumberFieldLabel;
+
+/// A field for typing a number, edited with the keyboard while focused.
+///
+/// Typed text is committed with Enter or when the field loses focus, and reverted
+/// with Escape. Committed numbers are clamped to the range, and text that is not a
+/// number is discarded. Up and Down step the value, repeating while held.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Resource)]
+/// # struct Character {
+/// #     age: f32,
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(
+///     NumberField::new()
+///         .range(0.0, 255.0)
+///         .bind(|c: &mut Character| &mut c.age)
+///         .pack(),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NumberField {
+    /// Name tag for debugging or entity inspection.
+    name: Name,
+
+    /// Layout node controlling size and padding.
+    node: Node,
+
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
+    /// Bevy's built-in Button marker for interaction detection.
+    bottom: Button,
+
+    /// Keyboard and gamepad focus settings.
+    focusable: Focusable,
+
+    /// The value and its range.
+    state: NumberState,
+
+    /// The text being typed.
+    edit: NumberFieldEdit,
+
+    /// Visual styling for borders (widths, colors, radius).
+    border: BorderStyle,
+
+    /// Visual background style (fill, gradient, texture).
+    background: BackgroundStyle,
+
+    /// Optional outer shadow to simulate depth or elevation.
+    box_shadow: BoxShadow,
+
+    /// Color, font and size of the text.
+    text: TextStyle,
+}
+
+impl Default for NumberField {
+    fn default() -> Self {
+        Self {
+            name: Name::new("NumberField"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::End,
+                align_items: AlignItems::Center,
+                width: Val::Px(100.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                ..Default::default()
+            },
+            class: ViewClass::new("NumberField"),
+            modifiers: ViewModifiers::default(),
+            bottom: Button,
+            focusable: Focusable::default(),
+            state: NumberState::default(),
+            edit: NumberFieldEdit::default(),
+            border: BorderStyle::button(),
+            background: BackgroundStyle::button(),
+            box_shadow: BoxShadow::default(),
+            text: TextStyle::default(),
+        }
+        .border_color(Srgba::gray(0.6))
+        .text_alignment(JustifyText::Right)
+    }
+}
+
+impl View for NumberField {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for NumberField {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for NumberField {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ViewToBundle for NumberField {
+    fn pack(self) -> impl Bundle {
+        (
+            self.name,
+            self.node,
+            self.class,
+            self.modifiers,
+            self.bottom,
+            self.focusable,
+            CapturesVerticalArrows,
+            self.state,
+            self.edit,
+            self.border,
+            self.background,
+            self.box_shadow,
+            children![(NumberFieldLabel, self.text)],
+        )
+    }
+}
+
+impl NumberView for NumberField {
+    fn number_node(&mut self) -> &mut NumberState {
+        &mut self.state
+    }
+}
+
+impl InputView for NumberField {
+    type State = NumberState;
+
+    fn input_node(&mut self) -> &mut NumberState {
+        &mut self.state
+    }
+}
+
+impl BackgroundView for NumberField {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for NumberField {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for NumberField {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.box_shadow
+    }
+}
+
+impl TextView for NumberField {
+    fn text_node(&mut self) -> &mut TextStyle {
+        &mut self.text
+    }
+}
+
+impl PositionView for NumberField {}
+
+impl AnimationView for NumberField {}
+
+impl FocusView for NumberField {
+    fn focusable_node(&mut self) -> &mut Focusable {
+        &mut self.focusable
+    }
+}
+
+/// Applies the typed text to the value and stops editing.
+///
+/// Text that is not a number leaves the value unchanged.
+fn commit(
+    entity: Entity,
+    state: &mut Mut<NumberState>,
+    edit: &mut NumberFieldEdit,
+    changed: &mut EventWriter<NumberChanged>,
+) {
+    if let Ok(typed) = edit.text.trim().parse::<f32>()
+        && typed.is_finite()
+    {
+        let value = state.constrain(typed);
+        if value != state.value {
+            state.value = value;
+            changed.write(NumberChanged { entity, value });
+        }
+    }
+    edit.text = state.format();
+    edit.editing = false;
+}
+
+pub(crate) fn focus_on_press(
+    mut focused: ResMut<FocusedEntity>,
+    fields: Query<(Entity, Ref<Interaction>), With<NumberFieldEdit>>,
+) {
+    for (entity, interaction) in &fields {
+        if interaction.is_changed()
+            && *interaction == Interaction::Pressed
+            && focused.0 != Some(entity)
+        {
+            focused.0 = Some(entity);
+        }
+    }
+}
+
+pub(crate) fn edit_number(
+    mut keys: EventReader<KeyboardInput>,
+    focused: Res<FocusedEntity>,
+    mut fields: Query<(Entity, &mut NumberState, &mut NumberFieldEdit)>,
+    mut changed: EventWriter<NumberChanged>,
+) {
+    // Fields that lost focus keep what was typed
+    for (entity, mut state, mut edit) in &mut fields {
+        if edit.editing && focused.0 != Some(entity) {
+            commit(entity, &mut state, &mut edit, &mut changed);
+        }
+    }
+
+    let Some((entity, mut state, mut edit)) =
+        focused.0.and_then(|entity| fields.get_mut(entity).ok())
+    else {
+        keys.clear();
+        return;
+    };
+    if !edit.editing {
+        edit.text = state.format();
+        edit.editing = true;
+    }
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Backspace => {
+                edit.text.pop();
+            }
+            Key::Enter => {
+                commit(entity, &mut state, &mut edit, &mut changed);
+                edit.editing = true;
+            }
+            Key::Escape => edit.text = state.format(),
+            // Key repeat steps again while the arrow is held
+            Key::ArrowUp | Key::ArrowDown => {
+                commit(entity, &mut state, &mut edit, &mut changed);
+                let steps = if key.logical_key == Key::ArrowUp {
+                    1
+                } else {
+                    -1
+                };
+                step_number(entity, &mut state, steps, &mut changed);
+                edit.text = state.format();
+                edit.editing = true;
+            }
+            _ => {
+                let Some(text) = &key.text else {
+                    continue;
+                };
+                for character in text.chars() {
+                    let sign = matches!(character, '-' | '+') && edit.text.is_empty();
+                    let point = character == '.' && !edit.text.contains('.');
+                    if character.is_ascii_digit() || sign || point {
+                        edit.text.push(character);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn update_number_field(
+    focused: Res<FocusedEntity>,
+    mut fields: Query<(
+        Entity,
+        Ref<NumberState>,
+        &mut NumberFieldEdit,
+        &mut BorderColor,
+        &Children,
+    )>,
+    mut labels: Query<&mut Text, With<NumberFieldLabel>>,
+) {
+    for (entity, state, mut edit, mut border, children) in &mut fields {
+        if !state.is_changed() && !edit.is_changed() && !focused.is_changed() {
+            continue;
+        }
+        let editing = edit.editing && focused.0 == Some(entity);
+        let shown = if editing {
+            // A trailing bar stands in for the caret
+            format!("{}|", edit.text)
+        } else {
+            state.format()
+        };
+        let mut labels = labels.iter_many_mut(children);
+        while let Some(mut label) = labels.fetch_next() {
+            if label.0 != shown {
+                label.0.clone_from(&shown);
+            }
+        }
+
+        let invalid = editing && edit.parse(&state).is_none();
+        if invalid && edit.normal_border.is_none() {
+            edit.normal_border = Some(border.0);
+            border.0 = INVALID_COLOR.into();
+        } else if !invalid && let Some(normal) = edit.normal_border.take() {
+            border.0 = normal;
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::{FocusView, Focusable};
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::text::{TextStyle, TextView};
+use crate::{View, ViewToBundle};
+
+use super::{InputState, InputView};
+
+/// The options of a [`Picker`] and the index of the selected one.
+#[derive(Component, Debug, Clone, PartialEq, Default)]
+pub struct PickerState {
+    /// Labels of the options
+    pub options: Vec<String>,
+    /// Index of the selected option
+    pub selected: usize,
+}
+
+impl PickerState {
+    /// Returns the label of the selected option, if any.
+    pub fn selected_label(&self) -> Option<&str> {
+        self.options.get(self.selected).map(String::as_str)
+    }
+}
+
+impl InputState for PickerState {
+    type Value = usize;
+
+    fn value(&self) -> usize {
+        self.selected
+    }
+
+    fn set_value(&mut self, value: usize) {
+        self.selected = value;
+    }
+}
+
+/// Marks the text entity inside a [`Picker`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct PickerLabel;
+
+/// Shows one option out of a list and moves to the next one when pressed.
+///
+/// The bound value is the index of the selected option.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Resource)]
+/// # struct GraphicsSettings {
+/// #     quality: usize,
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(
+///     Picker::new()
+///         .options(["Low", "Medium", "High"])
+///         .bind(|s: &mut GraphicsSettings| &mut s.quality)
+///         .pack(),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Picker {
+    /// Name tag for debugging or entity inspection.
+    name: Name,
+
+    /// Layout node controlling size and padding.
+    node: Node,
+
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
+    /// Bevy's built-in Button marker for interaction detection.
+    bottom: Button,
+
+    /// Keyboard and gamepad focus settings.
+    focusable: Focusable,
+
+    /// The options and selection.
+    state: PickerState,
+
+    /// Visual styling for borders (widths, colors, radius).
+    border: BorderStyle,
+
+    /// Visual background style (fill, gradient, texture).
+    background: BackgroundStyle,
+
+    /// Optional outer shadow to simulate depth or elevation.
+    box_shadow: BoxShadow,
+
+    /// Color, font and size of the selected option.
+    text: TextStyle,
+}
+
+impl Default for Picker {
+    fn default() -> Self {
+        Self {
+            name: Name::new("Picker"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                ..Default::default()
+            },
+            class: ViewClass::new("Picker"),
+            modifiers: ViewModifiers::default(),
+            bottom: Button,
+            focusable: Focusable::default(),
+            state: PickerState::default(),
+            border: BorderStyle::button(),
+            background: BackgroundStyle::button(),
+            box_shadow: BoxShadow::default(),
+            text: TextStyle::button(),
+        }
+    }
+}
+
+impl View for Picker {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for Picker {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for Picker {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ViewToBundle for Picker {
+    fn pack(self) -> impl Bundle {
+        (
+            self.name,
+            self.node,
+            self.class,
+            self.modifiers,
+            self.bottom,
+            self.focusable,
+            self.state,
+            self.border,
+            self.background,
+            self.box_shadow,
+            children![(PickerLabel, self.text)],
+        )
+    }
+}
+
+/// A trait for configuring the options of a [`Picker`].
+pub trait PickerView: View {
+    /// Returns a mutable reference to the state of this picker.
+    fn picker_node(&mut self) -> &mut PickerState;
+
+    /// Sets the labels of the options.
+    fn options<S: Into<String>>(mut self, options: impl IntoIterator<Item = S>) -> Self {
+        self.picker_node().options = options.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl PickerView for Picker {
+    fn picker_node(&mut self) -> &mut PickerState {
+        &mut self.state
+    }
+}
+
+impl InputView for Picker {
+    type State = PickerState;
+
+    fn input_node(&mut self) -> &mut PickerState {
+        &mut self.state
+    }
+}
+
+impl BackgroundView for Picker {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for Picker {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for Picker {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.box_shadow
+    }
+}
+
+impl TextView for Picker {
+    fn text_node(&mut self) -> &mut TextStyle {
+        &mut self.text
+    }
+}
+
+impl PositionView for Picker {}
+
+impl AnimationView for Picker {}
+
+impl FocusView for Picker {
+    fn focusable_node(&mut self) -> &mut Focusable {
+        &mut self.focusable
+    }
+}
+
+pub(crate) fn cycle_on_press(
+    mut pickers: Query<(&Interaction, &mut PickerState), Changed<Interaction>>,
+) {
+    for (interaction, mut state) in &mut pickers {
+        if *interaction == Interaction::Pressed && !state.options.is_empty() {
+            state.selected = (state.selected + 1) % state.options.len();
+        }
+    }
+}
+
+pub(crate) fn update_picker(
+    pickers: Query<(&PickerState, &Children), Changed<PickerState>>,
+    mut labels: Query<&mut Text, With<PickerLabel>>,
+) {
+    for (state, children) in &pickers {
+        let shown = state.selected_label().unwrap_or_default();
+        for child in children {
+            if let Ok(mut label) = labels.get_mut(*child)
+                && label.0 != shown
+            {
+                label.0 = shown.to_owned();
+            }
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_ui::RelativeCursorPosition;
+use bevy_ui::prelude::*;
+
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::{
+    ActivatedByFocus, CapturesHorizontalArrows, FocusView, Focusable, FocusedEntity,
+};
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::{View, ViewToBundle};
+
+use super::{InputState, InputView};
+
+/// The value of a [`Slider`] and the range it can take.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SliderState {
+    /// The current value, within `min..=max`
+    pub value: f32,
+    /// Value at the left end of the track
+    pub min: f32,
+    /// Value at the right end of the track
+    pub max: f32,
+    /// Increment values snap to, `0.0` for continuous values
+    pub step: f32,
+    /// Color of the filled part of the track
+    pub fill_color: Color,
+}
+
+impl SliderState {
+    /// Returns where the value lies between `min` and `max`, from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.max > self.min {
+            ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns how far Left and Right move the value: the step, or a tenth
+    /// of the range for continuous values.
+    pub fn key_step(&self) -> f32 {
+        if self.step > 0.0 {
+            self.step
+        } else {
+            (self.max - self.min) / 10.0
+        }
+    }
+
+    /// Clamps `value` to the range and snaps it to the step.
+    pub fn constrain(&self, value: f32) -> f32 {
+        let value = if self.step > 0.0 {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        } else {
+            value
+        };
+        value.clamp(self.min, self.max.max(self.min))
+    }
+}
+
+impl Default for SliderState {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: 0.0,
+            max: 1.0,
+            step: 0.0,
+            fill_color: Srgba::rgb(0.25, 0.55, 1.0).into(),
+        }
+    }
+}
+
+impl InputState for SliderState {
+    type Value = f32;
+
+    fn value(&self) -> f32 {
+        self.value
+    }
+
+    fn set_value(&mut self, value: f32) {
+        self.value = self.constrain(value);
+    }
+}
+
+/// Marks the filled part of a [`Slider`] track.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct SliderFill;
+
+/// Marks the thumb of a [`Slider`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct SliderThumb;
+
+/// A horizontal track for picking a number by pressing or dragging.
+///
+/// While focused, Left and Right or the D-pad move the value by
+/// [`SliderState::key_step`].
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Resource)]
+/// # struct AudioSettings {
+/// #     volume: f32,
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(
+///     Slider::new()
+///         .range(0.0, 100.0)
+///         .bind(|s: &mut AudioSettings| &mut s.volume)
+///         .pack(),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Slider {
+    /// Name tag for debugging or entity inspection.
+    name: Name,
+
+    /// Layout node of the track.
+    node: Node,
+
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
+    /// Bevy's built-in Button marker for interaction detection.
+    bottom: Button,
+
+    /// Keyboard and gamepad focus settings.
+    focusable: Focusable,
+
+    /// Pointer position over the track, used while dragging.
+    cursor: RelativeCursorPosition,
+
+    /// The value and its range.
+    state: SliderState,
+
+    /// Border of the track.
+    border: BorderStyle,
+
+    /// Background of the unfilled track.
+    background: BackgroundStyle,
+
+    /// Outer shadow of the track.
+    box_shadow: BoxShadow,
+
+    /// Color of the thumb.
+    thumb_color: BackgroundColor,
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self {
+            name: Name::new("Slider"),
+            node: Node {
+                width: Val::Px(160.0),
+                height: Val::Px(8.0),
+                margin: UiRect::vertical(Val::Px(6.0)),
+                ..Default::default()
+            },
+            class: ViewClass::new("Slider"),
+            modifiers: ViewModifiers::default(),
+            bottom: Button,
+            focusable: Focusable::default(),
+            cursor: RelativeCursorPosition::default(),
+            state: SliderState::default(),
+            border: BorderStyle::capsule(),
+            background: BackgroundStyle::solid(Srgba::gray(0.8)),
+            box_shadow: BoxShadow::default(),
+            thumb_color: BackgroundColor(Srgba::WHITE.into()),
+        }
+    }
+}
+
+impl View for Slider {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for Slider {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for Slider {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ViewToBundle for Slider {
+    fn pack(self) -> impl Bundle {
+        let percent = self.state.fraction() * 100.0;
+        let fill = (
+            Name::new("SliderFill"),
+            SliderFill,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                width: Val::Percent(percent),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            BackgroundColor(self.state.fill_color),
+            BorderRadius::MAX,
+        );
+        let thumb = (
+            Name::new("SliderThumb"),
+            SliderThumb,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(percent),
+                top: Val::Px(-5.0),
+                width: Val::Px(18.0),
+                height: Val::Px(18.0),
+                margin: UiRect::left(Val::Px(-9.0)),
+                ..Default::default()
+            },
+            self.thumb_color,
+            BorderRadius::MAX,
+            BoxShadow::new(
+                Srgba::new(0.0, 0.0, 0.0, 0.3).into(),
+                Val::ZERO,
+                Val::Px(1.0),
+                Val::ZERO,
+                Val::Px(2.0),
+            ),
+        );
+        (
+            self.name,
+            self.node,
+            self.class,
+            self.modifiers,
+            self.bottom,
+            self.focusable,
+            CapturesHorizontalArrows,
+            self.cursor,
+            self.state,
+            self.border,
+            self.background,
+            self.box_shadow,
+            children![fill, thumb],
+        )
+    }
+}
+
+/// A trait for configuring the range of a [`Slider`].
+pub trait SliderView: View {
+    /// Returns a mutable reference to the state of this slider.
+    fn slider_node(&mut self) -> &mut SliderState;
+
+    /// Sets the values at both ends of the track.
+    ///
+    /// Initial values are clamped to the range, so set it before `.value()`.
+    fn range(mut self, min: f32, max: f32) -> Self {
+        let state = self.slider_node();
+        state.min = min;
+        state.max = max;
+        state.value = state.constrain(state.value);
+        self
+    }
+
+    /// Snaps values to multiples of `step` from the minimum.
+    fn step(mut self, step: f32) -> Self {
+        let state = self.slider_node();
+        state.step = step;
+        state.value = state.constrain(state.value);
+        self
+    }
+
+    /// Sets the color of the filled part of the track.
+    fn fill_color(mut self, color: impl Into<Color>) -> Self {
+        self.slider_node().fill_color = color.into();
+        self
+    }
+}
+
+impl SliderView for Slider {
+    fn slider_node(&mut self) -> &mut SliderState {
+        &mut self.state
+    }
+}
+
+impl InputView for Slider {
+    type State = SliderState;
+
+    fn input_node(&mut self) -> &mut SliderState {
+        &mut self.state
+    }
+}
+
+impl BackgroundView for Slider {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for Slider {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for Slider {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.box_shadow
+    }
+}
+
+impl PositionView for Slider {}
+
+impl AnimationView for Slider {}
+
+impl FocusView for Slider {
+    fn focusable_node(&mut self) -> &mut Focusable {
+        &mut self.focusable
+    }
+}
+
+pub(crate) fn drag_slider(
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut sliders: Query<(
+        &Interaction,
+        &RelativeCursorPosition,
+        &mut SliderState,
+        Has<ActivatedByFocus>,
+    )>,
+) {
+    // Keyboard and gamepad presses have no pointer to follow
+    if !mouse.pressed(MouseButton::Left) && touches.iter().next().is_none() {
+        return;
+    }
+    for (interaction, cursor, mut state, activated) in &mut sliders {
+        if *interaction != Interaction::Pressed || activated {
+            continue;
+        }
+        let Some(position) = cursor.normalized else {
+            continue;
+        };
+        let value = state.constrain(state.min + position.x * (state.max - state.min));
+        if value != state.value {
+            state.value = value;
+        }
+    }
+}
+
+pub(crate) fn step_slider_with_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    focused: Res<FocusedEntity>,
+    mut sliders: Query<&mut SliderState>,
+) {
+    let Some(mut state) = focused.0.and_then(|entity| sliders.get_mut(entity).ok()) else {
+        return;
+    };
+    let gamepad_pressed = |button| gamepads.iter().any(|pad| pad.just_pressed(button));
+    let direction = if keys.just_pressed(KeyCode::ArrowRight)
+        || gamepad_pressed(GamepadButton::DPadRight)
+    {
+        1.0
+    } else if keys.just_pressed(KeyCode::ArrowLeft) || gamepad_pressed(GamepadButton::DPadLeft) {
+        -1.0
+    } else {
+        return;
+    };
+    let value = state.constrain(state.value + direction * state.key_step());
+    if value != state.value {
+        state.value = value;
+    }
+}
+
+pub(crate) fn update_slider(
+    sliders: Query<(&SliderState, &Children), Changed<SliderState>>,
+    mut parts: Query<(
+        &mut Node,
+        &mut BackgroundColor,
+        Has<SliderFill>,
+        Has<SliderThumb>,
+    )>,
+) {
+    for (state, children) in &sliders {
+        let percent = Val::Percent(state.fraction() * 100.0);
+        for child in children {
+            let Ok((mut node, mut color, fill, thumb)) = parts.get_mut(*child) else {
+                continue;
+            };
+            if fill {
+                node.width = percent;
+                color.0 = state.fill_color;
+            } else if thumb {
+                node.left = percent;
+            }
+        }
+    }
+}
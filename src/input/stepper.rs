@@ -0,0 +1,366 @@
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::border::{BorderStyle, BorderView};
+use crate::button::text_button::TextButton;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::text::{TextStyle, TextView};
+use crate::{View, ViewToBundle};
+
+use super::{InputState, InputView};
+
+/// How long a stepper button is held before it starts repeating, in seconds.
+const REPEAT_DELAY: f32 = 0.4;
+/// Time between repeated steps while a stepper button is held, in seconds.
+const REPEAT_INTERVAL: f32 = 0.08;
+
+/// The value of a [`Stepper`] or [`NumberField`](super::number_field::NumberField) and the
+/// range it can take.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct NumberState {
+    /// The current value, within `min..=max`
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    /// Amount added or removed by each step
+    pub step: f32,
+    /// Number of digits shown and kept after the decimal point
+    pub decimals: usize,
+}
+
+impl NumberState {
+    /// Clamps `value` to the range and rounds it to the shown decimals.
+    pub fn constrain(&self, value: f32) -> f32 {
+        let scale = 10f32.powi(self.decimals as i32);
+        ((value * scale).round() / scale).clamp(self.min, self.max.max(self.min))
+    }
+
+    /// Returns the value a number of steps away, within the range.
+    ///
+    /// # Arguments
+    /// * `steps` - Negative to step down, positive to step up.
+    pub fn stepped(&self, steps: i32) -> f32 {
+        self.constrain(self.value + steps as f32 * self.step)
+    }
+
+    /// Formats the value with the shown decimals.
+    pub fn format(&self) -> String {
+        format!("{:.*}", self.decimals, self.value)
+    }
+}
+
+impl Default for NumberState {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: f32::MIN,
+            max: f32::MAX,
+            step: 1.0,
+            decimals: 0,
+        }
+    }
+}
+
+impl InputState for NumberState {
+    type Value = f32;
+
+    fn value(&self) -> f32 {
+        self.value
+    }
+
+    fn set_value(&mut self, value: f32) {
+        self.value = self.constrain(value);
+    }
+}
+
+/// Sent when the user changes the value of a [`Stepper`] or
+/// [`NumberField`](super::number_field::NumberField).
+///
+/// Values written by bindings or from code do not send it.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct NumberChanged {
+    pub entity: Entity,
+    pub value: f32,
+}
+
+/// A trait for configuring the range of numeric inputs.
+pub trait NumberView: View {
+    /// Returns a mutable reference to the number edited by this view.
+    fn number_node(&mut self) -> &mut NumberState;
+
+    /// Sets the smallest and largest values.
+    ///
+    /// Initial values are clamped to the range, so set it before `.value()`.
+    fn range(mut self, min: f32, max: f32) -> Self {
+        let state = self.number_node();
+        state.min = min;
+        state.max = max;
+        state.value = state.constrain(state.value);
+        self
+    }
+
+    /// Sets the amount added or removed by each step.
+    fn step(mut self, step: f32) -> Self {
+        self.number_node().step = step;
+        self
+    }
+
+    /// Sets the number of digits shown and kept after the decimal point.
+    fn decimals(mut self, decimals: usize) -> Self {
+        let state = self.number_node();
+        state.decimals = decimals;
+        state.value = state.constrain(state.value);
+        self
+    }
+}
+
+/// Marks the label showing the value of a [`Stepper`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct StepperLabel;
+
+/// A minus or plus button of a [`Stepper`], repeating while held.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct StepperButton {
+    /// `-1` for the minus button, `1` for the plus button
+    steps: i32,
+    /// How long the button has been held, in seconds
+    held: f32,
+    /// When the next repeated step is due
+    next_repeat: f32,
+}
+
+impl StepperButton {
+    fn new(steps: i32) -> Self {
+        Self {
+            steps,
+            held: 0.0,
+            next_repeat: REPEAT_DELAY,
+        }
+    }
+}
+
+/// A value between a minus and a plus button, stepped by pressing or holding them.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Resource)]
+/// # struct Character {
+/// #     strength: f32,
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(
+///     Stepper::new()
+///         .range(1.0, 20.0)
+///         .bind(|c: &mut Character| &mut c.strength)
+///         .pack(),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stepper {
+    /// Name tag for debugging or entity inspection.
+    name: Name,
+
+    /// Layout node placing the buttons around the value.
+    node: Node,
+
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
+    /// The value and its range.
+    state: NumberState,
+
+    /// Visual styling for borders (widths, colors, radius).
+    border: BorderStyle,
+
+    /// Visual background style (fill, gradient, texture).
+    background: BackgroundStyle,
+
+    /// Optional outer shadow to simulate depth or elevation.
+    box_shadow: BoxShadow,
+
+    /// Color, font and size of the value.
+    text: TextStyle,
+}
+
+impl Default for Stepper {
+    fn default() -> Self {
+        Self {
+            name: Name::new("Stepper"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            class: ViewClass::new("Stepper"),
+            modifiers: ViewModifiers::default(),
+            state: NumberState::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            box_shadow: BoxShadow::default(),
+            text: TextStyle::default(),
+        }
+    }
+}
+
+impl View for Stepper {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for Stepper {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for Stepper {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ViewToBundle for Stepper {
+    fn pack(self) -> impl Bundle {
+        (
+            self.name,
+            self.node,
+            self.class,
+            self.modifiers,
+            self.state,
+            self.border,
+            self.background,
+            self.box_shadow,
+            children![
+                (stepper_button("−"), StepperButton::new(-1)),
+                (StepperLabel, self.text),
+                (stepper_button("+"), StepperButton::new(1)),
+            ],
+        )
+    }
+}
+
+fn stepper_button(label: &str) -> impl Bundle {
+    TextButton::new()
+        .text(label)
+        .frame(Val::Px(28.0), Val::Px(28.0))
+        .border_radius(BorderRadius::all(Val::Px(6.0)))
+        .pack()
+}
+
+impl NumberView for Stepper {
+    fn number_node(&mut self) -> &mut NumberState {
+        &mut self.state
+    }
+}
+
+impl InputView for Stepper {
+    type State = NumberState;
+
+    fn input_node(&mut self) -> &mut NumberState {
+        &mut self.state
+    }
+}
+
+impl BackgroundView for Stepper {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for Stepper {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for Stepper {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.box_shadow
+    }
+}
+
+impl TextView for Stepper {
+    fn text_node(&mut self) -> &mut TextStyle {
+        &mut self.text
+    }
+}
+
+impl PositionView for Stepper {}
+
+impl AnimationView for Stepper {}
+
+/// Changes the value of `state` by a number of steps, reporting the change.
+pub(crate) fn step_number(
+    entity: Entity,
+    state: &mut Mut<NumberState>,
+    steps: i32,
+    changed: &mut EventWriter<NumberChanged>,
+) {
+    let value = state.stepped(steps);
+    if value != state.value {
+        state.value = value;
+        changed.write(NumberChanged { entity, value });
+    }
+}
+
+pub(crate) fn step_on_press(
+    time: Res<Time>,
+    mut buttons: Query<(Ref<Interaction>, &mut StepperButton, &ChildOf)>,
+    mut steppers: Query<&mut NumberState>,
+    mut changed: EventWriter<NumberChanged>,
+) {
+    for (interaction, mut button, stepper) in &mut buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut state) = steppers.get_mut(stepper.parent()) else {
+            continue;
+        };
+        if interaction.is_changed() {
+            button.held = 0.0;
+            button.next_repeat = REPEAT_DELAY;
+            step_number(stepper.parent(), &mut state, button.steps, &mut changed);
+            continue;
+        }
+        button.held += time.delta_secs();
+        while button.held >= button.next_repeat {
+            button.next_repeat += REPEAT_INTERVAL;
+            step_number(stepper.parent(), &mut state, button.steps, &mut changed);
+        }
+    }
+}
+
+pub(crate) fn update_stepper(
+    steppers: Query<(&NumberState, &Children), Changed<NumberState>>,
+    mut labels: Query<&mut Text, With<StepperLabel>>,
+) {
+    for (state, children) in &steppers {
+        let shown = state.format();
+        let mut labels = labels.iter_many_mut(children);
+        while let Some(mut label) = labels.fetch_next() {
+            if label.0 != shown {
+                label.0.clone_from(&shown);
+            }
+        }
+    }
+}
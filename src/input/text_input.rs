@@ -0,0 +1,313 @@
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::keyboard::{Key, KeyboardInput};
+use bevy_text::JustifyText;
+use bevy_ui::prelude::*;
+
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::{FocusView, Focusable, FocusedEntity};
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::text::{TextStyle, TextView};
+use crate::{View, ViewToBundle};
+
+use super::{InputState, InputView};
+
+/// The text of a [`TextInput`].
+#[derive(Component, Debug, Clone, PartialEq, Default)]
+pub struct TextInputState {
+    /// The entered text
+    pub text: String,
+    /// Shown while the text is empty and the input is not focused
+    pub placeholder: String,
+    /// Maximum number of characters, if limited
+    pub max_length: Option<usize>,
+}
+
+impl InputState for TextInputState {
+    type Value = String;
+
+    fn value(&self) -> String {
+        self.text.clone()
+    }
+
+    fn set_value(&mut self, value: String) {
+        self.text = value;
+    }
+}
+
+/// Marks the text entity inside a [`TextInput`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct TextInputLabel;
+
+/// A single line text field, edited with the keyboard while focused.
+///
+/// Pressing the field focuses it. Typed characters are appended and
+/// Backspace removes the last one.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Resource)]
+/// # struct Profile {
+/// #     name: String,
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(
+///     TextInput::new()
+///         .placeholder("Player name")
+///         .bind(|s: &mut Profile| &mut s.name)
+///         .pack(),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextInput {
+    /// Name tag for debugging or entity inspection.
+    name: Name,
+
+    /// Layout node controlling size and padding.
+    node: Node,
+
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
+    /// Bevy's built-in Button marker for interaction detection.
+    bottom: Button,
+
+    /// Keyboard and gamepad focus settings.
+    focusable: Focusable,
+
+    /// The entered text and placeholder.
+    state: TextInputState,
+
+    /// Visual styling for borders (widths, colors, radius).
+    border: BorderStyle,
+
+    /// Visual background style (fill, gradient, texture).
+    background: BackgroundStyle,
+
+    /// Optional outer shadow to simulate depth or elevation.
+    box_shadow: BoxShadow,
+
+    /// Color, font and size of the text.
+    text: TextStyle,
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self {
+            name: Name::new("TextInput"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Center,
+                width: Val::Px(200.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                ..Default::default()
+            },
+            class: ViewClass::new("TextInput"),
+            modifiers: ViewModifiers::default(),
+            bottom: Button,
+            focusable: Focusable::default(),
+            state: TextInputState::default(),
+            border: BorderStyle::button(),
+            background: BackgroundStyle::button(),
+            box_shadow: BoxShadow::default(),
+            text: TextStyle::default(),
+        }
+        .border_color(Srgba::gray(0.6))
+        .text_alignment(JustifyText::Left)
+    }
+}
+
+impl View for TextInput {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for TextInput {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for TextInput {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ViewToBundle for TextInput {
+    fn pack(self) -> impl Bundle {
+        (
+            self.name,
+            self.node,
+            self.class,
+            self.modifiers,
+            self.bottom,
+            self.focusable,
+            self.state,
+            self.border,
+            self.background,
+            self.box_shadow,
+            children![(TextInputLabel, self.text)],
+        )
+    }
+}
+
+/// A trait for configuring a [`TextInput`].
+pub trait TextInputView: View {
+    /// Returns a mutable reference to the state of this text input.
+    fn text_input_node(&mut self) -> &mut TextInputState;
+
+    /// Sets the text shown while the input is empty.
+    fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.text_input_node().placeholder = placeholder.into();
+        self
+    }
+
+    /// Limits the number of characters that can be entered.
+    fn max_length(mut self, max_length: usize) -> Self {
+        self.text_input_node().max_length = Some(max_length);
+        self
+    }
+}
+
+impl TextInputView for TextInput {
+    fn text_input_node(&mut self) -> &mut TextInputState {
+        &mut self.state
+    }
+}
+
+impl InputView for TextInput {
+    type State = TextInputState;
+
+    fn input_node(&mut self) -> &mut TextInputState {
+        &mut self.state
+    }
+}
+
+impl BackgroundView for TextInput {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for TextInput {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for TextInput {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.box_shadow
+    }
+}
+
+impl TextView for TextInput {
+    fn text_node(&mut self) -> &mut TextStyle {
+        &mut self.text
+    }
+}
+
+impl PositionView for TextInput {}
+
+impl AnimationView for TextInput {}
+
+impl FocusView for TextInput {
+    fn focusable_node(&mut self) -> &mut Focusable {
+        &mut self.focusable
+    }
+}
+
+pub(crate) fn focus_on_press(
+    mut focused: ResMut<FocusedEntity>,
+    inputs: Query<(Entity, Ref<Interaction>), With<TextInputState>>,
+) {
+    for (entity, interaction) in &inputs {
+        if interaction.is_changed()
+            && *interaction == Interaction::Pressed
+            && focused.0 != Some(entity)
+        {
+            focused.0 = Some(entity);
+        }
+    }
+}
+
+pub(crate) fn edit_text(
+    mut keys: EventReader<KeyboardInput>,
+    focused: Res<FocusedEntity>,
+    mut inputs: Query<&mut TextInputState>,
+) {
+    let Some(mut state) = focused.0.and_then(|entity| inputs.get_mut(entity).ok()) else {
+        keys.clear();
+        return;
+    };
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        if key.logical_key == Key::Backspace {
+            state.text.pop();
+            continue;
+        }
+        let Some(text) = &key.text else {
+            continue;
+        };
+        for character in text.chars().filter(|c| !c.is_control()) {
+            if state
+                .max_length
+                .is_some_and(|max| state.text.chars().count() >= max)
+            {
+                break;
+            }
+            state.text.push(character);
+        }
+    }
+}
+
+pub(crate) fn update_text_input(
+    focused: Res<FocusedEntity>,
+    inputs: Query<(Entity, Ref<TextInputState>, &Children)>,
+    mut labels: Query<&mut Text, With<TextInputLabel>>,
+) {
+    for (entity, state, children) in &inputs {
+        if !state.is_changed() && !focused.is_changed() {
+            continue;
+        }
+        let has_focus = focused.0 == Some(entity);
+        let shown = if has_focus {
+            // A trailing bar stands in for the caret
+            format!("{}|", state.text)
+        } else if state.text.is_empty() {
+            state.placeholder.clone()
+        } else {
+            state.text.clone()
+        };
+        for child in children {
+            if let Ok(mut label) = labels.get_mut(*child)
+                && label.0 != shown
+            {
+                label.0.clone_from(&shown);
+            }
+        }
+    }
+}
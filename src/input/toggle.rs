@@ -0,0 +1,263 @@
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::animation::AnimationView;
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::{FocusView, Focusable};
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::{View, ViewToBundle};
+
+use super::{InputState, InputView};
+
+/// Whether a [`Toggle`] is on, and the track colors for each state.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ToggleState {
+    /// Whether the toggle is on
+    pub on: bool,
+    /// Track color while on
+    pub on_color: Color,
+    /// Track color while off
+    pub off_color: Color,
+}
+
+impl Default for ToggleState {
+    fn default() -> Self {
+        Self {
+            on: false,
+            on_color: Srgba::rgb(0.2, 0.75, 0.35).into(),
+            off_color: Srgba::gray(0.75).into(),
+        }
+    }
+}
+
+impl InputState for ToggleState {
+    type Value = bool;
+
+    fn value(&self) -> bool {
+        self.on
+    }
+
+    fn set_value(&mut self, value: bool) {
+        self.on = value;
+    }
+}
+
+/// Marks the knob of a [`Toggle`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ToggleKnob;
+
+/// A switch that flips between on and off when clicked or activated.
+///
+/// The knob sits on the left while off and on the right while on.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # #[derive(Resource)]
+/// # struct AudioSettings {
+/// #     muted: bool,
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(Toggle::new().bind(|s: &mut AudioSettings| &mut s.muted).pack());
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Toggle {
+    /// Name tag for debugging or entity inspection.
+    name: Name,
+
+    /// Layout node of the track.
+    node: Node,
+
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
+    /// Bevy's built-in Button marker for interaction detection.
+    bottom: Button,
+
+    /// Keyboard and gamepad focus settings.
+    focusable: Focusable,
+
+    /// Whether the toggle is on, and its colors.
+    state: ToggleState,
+
+    /// Border of the track.
+    border: BorderStyle,
+
+    /// Outer shadow of the track.
+    box_shadow: BoxShadow,
+
+    /// Layout node of the knob.
+    knob: Node,
+
+    /// Color of the knob.
+    knob_color: BackgroundColor,
+}
+
+impl Default for Toggle {
+    fn default() -> Self {
+        Self {
+            name: Name::new("Toggle"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Center,
+                width: Val::Px(44.0),
+                height: Val::Px(24.0),
+                padding: UiRect::all(Val::Px(2.0)),
+                ..Default::default()
+            },
+            class: ViewClass::new("Toggle"),
+            modifiers: ViewModifiers::default(),
+            bottom: Button,
+            focusable: Focusable::default(),
+            state: ToggleState::default(),
+            border: BorderStyle::capsule(),
+            box_shadow: BoxShadow::default(),
+            knob: Node {
+                width: Val::Px(20.0),
+                height: Val::Px(20.0),
+                ..Default::default()
+            },
+            knob_color: BackgroundColor(Srgba::WHITE.into()),
+        }
+    }
+}
+
+impl View for Toggle {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for Toggle {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for Toggle {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl ViewToBundle for Toggle {
+    fn pack(self) -> impl Bundle {
+        let color = if self.state.on {
+            self.state.on_color
+        } else {
+            self.state.off_color
+        };
+        (
+            self.name,
+            self.node,
+            self.class,
+            self.modifiers,
+            self.bottom,
+            self.focusable,
+            self.state,
+            self.border,
+            BackgroundColor(color),
+            self.box_shadow,
+            children![(
+                Name::new("ToggleKnob"),
+                ToggleKnob,
+                self.knob,
+                self.knob_color,
+                BorderRadius::MAX,
+            )],
+        )
+    }
+}
+
+/// A trait for configuring the colors of a [`Toggle`].
+pub trait ToggleView: View {
+    /// Returns a mutable reference to the state of this toggle.
+    fn toggle_node(&mut self) -> &mut ToggleState;
+
+    /// Sets the track color while on.
+    fn on_color(mut self, color: impl Into<Color>) -> Self {
+        self.toggle_node().on_color = color.into();
+        self
+    }
+
+    /// Sets the track color while off.
+    fn off_color(mut self, color: impl Into<Color>) -> Self {
+        self.toggle_node().off_color = color.into();
+        self
+    }
+}
+
+impl ToggleView for Toggle {
+    fn toggle_node(&mut self) -> &mut ToggleState {
+        &mut self.state
+    }
+}
+
+impl InputView for Toggle {
+    type State = ToggleState;
+
+    fn input_node(&mut self) -> &mut ToggleState {
+        &mut self.state
+    }
+}
+
+impl BorderView for Toggle {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for Toggle {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.box_shadow
+    }
+}
+
+impl PositionView for Toggle {}
+
+impl AnimationView for Toggle {}
+
+impl FocusView for Toggle {
+    fn focusable_node(&mut self) -> &mut Focusable {
+        &mut self.focusable
+    }
+}
+
+pub(crate) fn toggle_on_press(
+    mut toggles: Query<(&Interaction, &mut ToggleState), Changed<Interaction>>,
+) {
+    for (interaction, mut state) in &mut toggles {
+        if *interaction == Interaction::Pressed {
+            state.on = !state.on;
+        }
+    }
+}
+
+pub(crate) fn update_toggle(
+    mut toggles: Query<(&ToggleState, &mut Node, &mut BackgroundColor), Changed<ToggleState>>,
+) {
+    for (state, mut node, mut background) in &mut toggles {
+        if state.on {
+            node.justify_content = JustifyContent::End;
+            background.0 = state.on_color;
+        } else {
+            node.justify_content = JustifyContent::Start;
+            background.0 = state.off_color;
+        }
+    }
+}
@@ -1,8 +1,7 @@
 //! **Swoop UI** is a modular, ergonomic layout toolkit built on top of Bevy UI.
 //! It introduces expressive layout containers like `HStack`, `VStack`, `HGrid`, and `VGrid`,
 //! supporting fluent syntax for padding, spacing, border, and background styling.
-//! Now only some packaged candies are generated, no additional functions, maybe they will be added later,
-//! a plugin is reserved, but it has not been used yet
+//! Views can also be styled from stylesheet assets, see [`style`] and `SwoopUiPlugin`.
 //!
 //! Most methods implement Bundle and can be generated directly.
 //! However, some packaging requires multiple levels,
@@ -31,6 +30,8 @@
 //! - Contents are left-aligned using `JustifyContent::Start`.
 //!
 //! Because the impl Bundle is implemented, it can be directly generated using commands.spawn
+//!
+//! For a bar whose titles open dropdown menus, see [`MenuBar`](menu::menu_bar::MenuBar).
 
 use std::borrow::Cow;
 use std::fmt::Debug;
@@ -39,6 +40,23 @@ use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_ui::prelude::*;
 
+use animation::AnimationPlugin;
+use binding::BindingPlugin;
+use container::disclosure::DisclosurePlugin;
+use container::lazy::LazyPlugin;
+use container::reorder::ReorderPlugin;
+use container::split::SplitPlugin;
+use container::table::TablePlugin;
+use container::tree::TreePlugin;
+use drag::DragDropPlugin;
+use focus::FocusPlugin;
+use input::InputViewPlugin;
+use menu::MenuPlugin;
+use navigation::NavigationPlugin;
+use style::StyleSheetPlugin;
+use toast::ToastPlugin;
+use window_panel::WindowPanelPlugin;
+
 // Background UI trait
 pub mod background;
 // Border UI trait
@@ -47,11 +65,35 @@ pub mod border;
 pub mod shadow;
 // Absolute positioning
 pub mod position;
+// Stylesheets and view classes
+pub mod style;
+// Deferred view modifiers
+pub mod modifier;
+// Implicit property animations
+pub mod animation;
+// Keyboard and gamepad focus
+pub mod focus;
+// Reactive bindings to ECS data
+pub mod binding;
+// Screen navigation with a back stack
+pub mod navigation;
+// UI roots scoped to Bevy states
+pub mod scope;
+// Context menus, menu bars and menu panels
+pub mod menu;
+// Toast notifications
+pub mod toast;
+// Drag and drop between views
+pub mod drag;
+// Floating, draggable and resizable panels
+pub mod window_panel;
 
 /// Button
 pub mod button;
 /// Layouts and containers
 pub mod container;
+/// Toggles, sliders, text inputs and pickers
+pub mod input;
 /// Text
 pub mod text;
 
@@ -62,12 +104,25 @@ pub mod prelude {
     pub use super::shadow::{BoxShadowView, TextShadowView};
     pub use super::{SwoopUiPlugin, View, ViewToBundle};
 
+    pub use super::animation::prelude::*;
+    pub use super::binding::prelude::*;
+    pub use super::drag::prelude::*;
+    pub use super::focus::prelude::*;
+    pub use super::input::prelude::*;
+    pub use super::menu::prelude::*;
+    pub use super::modifier::{ModifierView, ViewModifiers};
+    pub use super::navigation::prelude::*;
+    pub use super::scope::prelude::*;
+    pub use super::style::prelude::*;
+    pub use super::toast::prelude::*;
+    pub use super::window_panel::prelude::*;
+
     pub use super::button::prelude::*;
     pub use super::container::prelude::*;
     pub use super::text::prelude::*;
 }
 
-/// Reserved for future addition of system functions
+/// Registers the systems backing swoop-ui features such as stylesheets
 pub struct SwoopUiPlugin;
 
 /// Provides a builder-style trait for configuring UI elements
@@ -227,5 +282,27 @@ pub trait ViewToBundle: View {
 }
 
 impl Plugin for SwoopUiPlugin {
-    fn build(&self, _: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            StyleSheetPlugin,
+            AnimationPlugin,
+            FocusPlugin,
+            BindingPlugin,
+            InputViewPlugin,
+            NavigationPlugin,
+            MenuPlugin,
+            ToastPlugin,
+            DragDropPlugin,
+            WindowPanelPlugin,
+            // Containers
+            (
+                LazyPlugin,
+                TablePlugin,
+                TreePlugin,
+                ReorderPlugin,
+                SplitPlugin,
+                DisclosurePlugin,
+            ),
+        ));
+    }
 }
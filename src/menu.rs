@@ -0,0 +1,747 @@
+use std::fmt;
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_math::prelude::*;
+use bevy_text::prelude::*;
+use bevy_time::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, RelativeCursorPosition};
+use bevy_window::{PrimaryWindow, Window};
+
+use crate::View;
+use crate::focus::{FocusScope, Focusable, FocusedEntity};
+use crate::modifier::ModifierView;
+use crate::text::TextView;
+use crate::text::swoop_text::SText;
+
+/// A bar of dropdown menus
+pub mod menu_bar;
+
+pub mod prelude {
+    pub use super::menu_bar::{Menu, MenuBar, MenuBarView};
+    pub use super::{ContextMenu, ContextMenuView, MenuAction, MenuItems, MenuPanel, MenuPlugin};
+}
+
+/// Runs when a menu item is chosen, with the entity the menu was opened for.
+pub type MenuAction = Arc<dyn Fn(&mut Commands, Entity) + Send + Sync>;
+
+/// How long a view must be touched before its context menu opens, in seconds.
+const LONG_PRESS_SECS: f32 = 0.5;
+/// Menus are drawn above the rest of the UI.
+const MENU_Z_INDEX: i32 = 1000;
+const HIGHLIGHT_COLOR: Srgba = Srgba::rgb(0.85, 0.9, 1.0);
+const DISABLED_TEXT_COLOR: Srgba = Srgba::rgb(0.6, 0.6, 0.6);
+
+/// Decides whether a menu item is enabled or checked, evaluated each time its menu opens.
+type MenuCondition = Arc<dyn Fn(&World) -> bool + Send + Sync>;
+
+#[derive(Clone)]
+enum MenuEntryKind {
+    Action(MenuAction),
+    Submenu(Arc<MenuItems>),
+    Separator,
+}
+
+#[derive(Clone)]
+struct MenuEntry {
+    label: String,
+    kind: MenuEntryKind,
+    /// Accelerator shown after the label, such as `Ctrl+S`
+    shortcut: Option<String>,
+    enabled: Option<MenuCondition>,
+    checked: Option<MenuCondition>,
+}
+
+impl MenuEntry {
+    fn new(label: String, kind: MenuEntryKind) -> Self {
+        Self {
+            label,
+            kind,
+            shortcut: None,
+            enabled: None,
+            checked: None,
+        }
+    }
+}
+
+/// The entries of a menu, built with [`item`](Self::item), [`separator`](Self::separator)
+/// and [`submenu`](Self::submenu).
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # fn drop_item(_: &mut Commands, _: Entity) {}
+/// # fn move_to_bank(_: &mut Commands, _: Entity) {}
+/// # fn move_to_stash(_: &mut Commands, _: Entity) {}
+/// MenuItems::new()
+///     .item("Drop", |commands, slot| drop_item(commands, slot))
+///     .separator()
+///     .submenu("Move to", |menu| menu.item("Bank", move_to_bank).item("Stash", move_to_stash));
+/// ```
+#[derive(Clone, Default)]
+pub struct MenuItems {
+    entries: Vec<MenuEntry>,
+}
+
+impl fmt::Debug for MenuItems {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MenuItems")
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+impl MenuItems {
+    /// Creates an empty menu.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of entries, separators included.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the menu has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds an item running `action` when chosen.
+    ///
+    /// # Arguments
+    /// * `label` - The text of the item.
+    /// * `action` - Receives the entity the menu was opened for.
+    pub fn item(
+        mut self,
+        label: impl Into<String>,
+        action: impl Fn(&mut Commands, Entity) + Send + Sync + 'static,
+    ) -> Self {
+        self.entries.push(MenuEntry::new(
+            label.into(),
+            MenuEntryKind::Action(Arc::new(action)),
+        ));
+        self
+    }
+
+    /// Adds a line between two groups of items.
+    pub fn separator(mut self) -> Self {
+        self.entries
+            .push(MenuEntry::new(String::new(), MenuEntryKind::Separator));
+        self
+    }
+
+    /// Adds an item opening a nested menu when hovered or pressed.
+    pub fn submenu(
+        mut self,
+        label: impl Into<String>,
+        build: impl FnOnce(MenuItems) -> MenuItems,
+    ) -> Self {
+        self.entries.push(MenuEntry::new(
+            label.into(),
+            MenuEntryKind::Submenu(Arc::new(build(MenuItems::new()))),
+        ));
+        self
+    }
+
+    /// Shows a keyboard accelerator such as `"Ctrl+S"` after the last added item.
+    ///
+    /// The accelerator is only displayed, the keys still need to be handled by the app.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.shortcut = Some(shortcut.into());
+        }
+        self
+    }
+
+    /// Greys out the last added item so it cannot be chosen.
+    pub fn disabled(self, disabled: bool) -> Self {
+        self.enabled_if(move |_| !disabled)
+    }
+
+    /// Enables the last added item only while `condition` holds when the menu opens.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # fn undo(_: &mut Commands, _: Entity) {}
+    /// # #[derive(Resource)]
+    /// # struct History(Vec<String>);
+    /// # impl History {
+    /// #     fn can_undo(&self) -> bool {
+    /// #         !self.0.is_empty()
+    /// #     }
+    /// # }
+    /// # let menu = MenuItems::new();
+    /// menu.item("Undo", undo).enabled_if(|world| world.resource::<History>().can_undo())
+    /// # ;
+    /// ```
+    pub fn enabled_if(
+        mut self,
+        condition: impl Fn(&World) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.enabled = Some(Arc::new(condition));
+        }
+        self
+    }
+
+    /// Shows a check mark before the last added item.
+    pub fn checked(self, checked: bool) -> Self {
+        self.checked_if(move |_| checked)
+    }
+
+    /// Shows a check mark before the last added item while `condition` holds when the menu opens.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # fn toggle_grid(_: &mut Commands, _: Entity) {}
+    /// # #[derive(Resource)]
+    /// # struct Grid {
+    /// #     visible: bool,
+    /// # }
+    /// # let menu = MenuItems::new();
+    /// menu.item("Show Grid", toggle_grid).checked_if(|world| world.resource::<Grid>().visible)
+    /// # ;
+    /// ```
+    pub fn checked_if(
+        mut self,
+        condition: impl Fn(&World) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.checked = Some(Arc::new(condition));
+        }
+        self
+    }
+}
+
+/// The menu opened on a view by right-click, long-press, the context menu key or
+/// the gamepad Start button.
+#[derive(Component, Clone)]
+pub struct ContextMenu(pub Arc<MenuItems>);
+
+impl fmt::Debug for ContextMenu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ContextMenu").field(&self.0).finish()
+    }
+}
+
+/// A trait for attaching a [`ContextMenu`], implemented for every view.
+pub trait ContextMenuView: ModifierView {
+    /// Opens a floating menu at the cursor when the view is right-clicked, at the finger
+    /// when it is long-pressed on a touch screen, or next to it when the context menu
+    /// key or gamepad Start button is pressed while it has focus.
+    ///
+    /// The menu closes on an outside click, Escape, the gamepad East button or when an
+    /// item is chosen.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # fn on_drop(_: &mut Commands, _: Entity) {}
+    /// # fn move_to_bank(_: &mut Commands, _: Entity) {}
+    /// TextButton::new().context_menu(|menu| {
+    ///     menu.item("Drop", on_drop)
+    ///         .separator()
+    ///         .submenu("Move to", |menu| menu.item("Bank", move_to_bank))
+    /// });
+    /// ```
+    fn context_menu(self, build: impl FnOnce(MenuItems) -> MenuItems) -> Self {
+        let menu = ContextMenu(Arc::new(build(MenuItems::new())));
+        self.modifier(move |entity| {
+            entity.insert((menu.clone(), RelativeCursorPosition::default()));
+            // Tracks touches held down for long-press
+            if !entity.contains::<Interaction>() {
+                entity.insert(Interaction::default());
+            }
+        })
+    }
+}
+
+impl<V: ModifierView> ContextMenuView for V {}
+
+/// A menu that is open, spawned as a root node above the rest of the UI.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MenuPanel {
+    /// The entity the menu was opened for, passed to item actions
+    pub target: Entity,
+    /// The menu this submenu was opened from
+    parent: Option<Entity>,
+    /// The item of `parent` that opened this submenu
+    opener: Option<Entity>,
+    /// Nesting level, 0 for the outermost menu
+    depth: usize,
+    /// Right edge to place a submenu against when there is no room after its parent
+    flip_x: Option<f32>,
+}
+
+/// An entry of an open [`MenuPanel`].
+#[derive(Component, Clone)]
+struct MenuItemButton {
+    panel: Entity,
+    entry: MenuEntry,
+    enabled: bool,
+}
+
+/// The check mark column of a menu item.
+#[derive(Component)]
+struct MenuCheckMark;
+
+/// Spawns a menu with its top left corner at `position`, in logical pixels.
+///
+/// `opener` is the view the menu drops down from, if any.
+pub(crate) fn open_menu(
+    commands: &mut Commands,
+    items: &MenuItems,
+    target: Entity,
+    opener: Option<Entity>,
+    position: Vec2,
+) -> Entity {
+    let panel = MenuPanel {
+        target,
+        parent: None,
+        opener,
+        depth: 0,
+        flip_x: None,
+    };
+    spawn_panel(commands, items, panel, position)
+}
+
+fn spawn_panel(
+    commands: &mut Commands,
+    items: &MenuItems,
+    panel: MenuPanel,
+    position: Vec2,
+) -> Entity {
+    let border = Color::from(Srgba::gray(0.75));
+    let entity = commands
+        .spawn((
+            Name::new("Menu"),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(position.x),
+                top: Val::Px(position.y),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                min_width: Val::Px(160.0),
+                padding: UiRect::all(Val::Px(4.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                ..Default::default()
+            },
+            BackgroundColor(Srgba::WHITE.into()),
+            BorderColor(border),
+            BorderRadius::all(Val::Px(4.0)),
+            BoxShadow::new(
+                Srgba::new(0.0, 0.0, 0.0, 0.25).into(),
+                Val::Px(0.0),
+                Val::Px(2.0),
+                Val::Px(0.0),
+                Val::Px(8.0),
+            ),
+            GlobalZIndex(MENU_Z_INDEX + panel.depth as i32),
+            FocusScope::trapped(),
+            RelativeCursorPosition::default(),
+            panel,
+        ))
+        .id();
+
+    let checkable = items.entries.iter().any(|entry| entry.checked.is_some());
+    for entry in &items.entries {
+        if matches!(entry.kind, MenuEntryKind::Separator) {
+            commands.spawn((
+                Node {
+                    height: Val::Px(1.0),
+                    margin: UiRect::vertical(Val::Px(4.0)),
+                    ..Default::default()
+                },
+                BackgroundColor(border),
+                ChildOf(entity),
+            ));
+            continue;
+        }
+        let item = commands
+            .spawn((
+                Name::new(entry.label.clone()),
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(12.0),
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                    ..Default::default()
+                },
+                Button,
+                Focusable::default(),
+                BackgroundColor(Color::NONE),
+                BorderRadius::all(Val::Px(2.0)),
+                MenuItemButton {
+                    panel: entity,
+                    entry: entry.clone(),
+                    enabled: true,
+                },
+                ChildOf(entity),
+            ))
+            .id();
+        if checkable {
+            commands.spawn((
+                SText::new().width(Val::Px(16.0)),
+                MenuCheckMark,
+                ChildOf(item),
+            ));
+        }
+        commands.spawn((
+            SText::new().text(entry.label.clone()).flex_grow(1.0),
+            ChildOf(item),
+        ));
+        if let Some(shortcut) = &entry.shortcut {
+            commands.spawn((
+                SText::new()
+                    .text(shortcut.clone())
+                    .text_color(DISABLED_TEXT_COLOR),
+                ChildOf(item),
+            ));
+        }
+        if matches!(entry.kind, MenuEntryKind::Submenu(_)) {
+            commands.spawn((SText::new().text("▸"), ChildOf(item)));
+        }
+    }
+    commands.queue(move |world: &mut World| apply_item_states(world, entity));
+    entity
+}
+
+/// Evaluates whether the items of `panel` are enabled and checked.
+fn apply_item_states(world: &mut World, panel: Entity) {
+    let Some(items) = world.get::<Children>(panel).map(|c| c.to_vec()) else {
+        return;
+    };
+    for item in items {
+        let Some(button) = world.get::<MenuItemButton>(item) else {
+            continue;
+        };
+        let entry = button.entry.clone();
+        let enabled = entry.enabled.is_none_or(|enabled| enabled(world));
+        let checked = entry.checked.is_some_and(|checked| checked(world));
+
+        if let Some(mut button) = world.get_mut::<MenuItemButton>(item) {
+            button.enabled = enabled;
+        }
+        if let Some(mut focusable) = world.get_mut::<Focusable>(item) {
+            focusable.disabled = !enabled;
+        }
+        let parts = world
+            .get::<Children>(item)
+            .map(|c| c.to_vec())
+            .unwrap_or_default();
+        for part in parts {
+            if checked && world.get::<MenuCheckMark>(part).is_some() {
+                world.entity_mut(part).insert(Text::new("✓"));
+            }
+            if !enabled && let Some(mut color) = world.get_mut::<TextColor>(part) {
+                color.0 = DISABLED_TEXT_COLOR.into();
+            }
+        }
+    }
+}
+
+/// Closes `panel` along with the submenus opened from it.
+fn close_panel(commands: &mut Commands, panel: Entity, panels: &Query<(Entity, &MenuPanel)>) {
+    for (child, _) in panels.iter().filter(|(_, p)| p.parent == Some(panel)) {
+        close_panel(commands, child, panels);
+    }
+    commands.entity(panel).try_despawn();
+}
+
+fn close_all(commands: &mut Commands, panels: &Query<(Entity, &MenuPanel)>) {
+    for (panel, _) in panels {
+        commands.entity(panel).try_despawn();
+    }
+}
+
+/// Returns the bounds of a node, in logical pixels.
+fn logical_rect(node: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    let scale = node.inverse_scale_factor();
+    Rect::from_center_size(
+        transform.translation().truncate() * scale,
+        node.size() * scale,
+    )
+}
+
+/// Context menus on views, [`MenuBar`](menu_bar::MenuBar)s and the menus they open.
+///
+/// Requires Bevy's `InputPlugin` and `TimePlugin`.
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                close_menus,
+                open_context_menus,
+                menu_bar::press_menu_titles,
+                menu_bar::hover_menu_titles,
+                menu_bar::switch_menus_with_keys,
+                press_menu_items,
+                hover_submenus,
+                highlight_menu_items,
+                menu_bar::highlight_menu_titles,
+                clamp_menus,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Closes menus on an outside click, and the innermost one on Escape or gamepad East.
+fn close_menus(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    panels: Query<(Entity, &MenuPanel)>,
+    cursors: Query<&RelativeCursorPosition, With<MenuPanel>>,
+) {
+    if panels.is_empty() {
+        return;
+    }
+    if mouse.any_just_pressed([MouseButton::Left, MouseButton::Right])
+        && !cursors.iter().any(RelativeCursorPosition::mouse_over)
+    {
+        close_all(&mut commands, &panels);
+    } else if (keys.just_pressed(KeyCode::Escape)
+        || gamepads
+            .iter()
+            .any(|pad| pad.just_pressed(GamepadButton::East)))
+        && let Some((innermost, _)) = panels.iter().max_by_key(|(_, panel)| panel.depth)
+    {
+        close_panel(&mut commands, innermost, &panels);
+    }
+}
+
+type ContextMenuTarget = (
+    Entity,
+    &'static ContextMenu,
+    &'static RelativeCursorPosition,
+    &'static Interaction,
+    &'static ComputedNode,
+    &'static GlobalTransform,
+);
+
+/// Opens context menus on right-click, long-press, the context menu key and gamepad Start.
+fn open_context_menus(
+    mut commands: Commands,
+    (mouse, keys, gamepads): (
+        Res<ButtonInput<MouseButton>>,
+        Res<ButtonInput<KeyCode>>,
+        Query<&Gamepad>,
+    ),
+    (time, focused, touches): (Res<Time>, Res<FocusedEntity>, Res<Touches>),
+    windows: Query<&Window, With<PrimaryWindow>>,
+    views: Query<ContextMenuTarget>,
+    (panels, parents): (Query<(Entity, &MenuPanel)>, Query<&ChildOf>),
+    mut long_press: Local<Option<(Entity, f32)>>,
+) {
+    let cursor = windows.single().ok().and_then(Window::cursor_position);
+    let rect_of = |entity| {
+        views
+            .get(entity)
+            .ok()
+            .map(|(.., node, transform)| logical_rect(node, transform))
+    };
+
+    let mut opened = None;
+    if mouse.just_pressed(MouseButton::Right)
+        && let Some(cursor) = cursor
+    {
+        // Nested views with their own menu take precedence
+        opened = views
+            .iter()
+            .filter(|(_, _, relative, ..)| relative.mouse_over())
+            .max_by_key(|(.., node, _)| node.stack_index())
+            .map(|(entity, ..)| (entity, cursor));
+    }
+
+    let gamepad_pressed = gamepads
+        .iter()
+        .any(|pad| pad.just_pressed(GamepadButton::Start));
+    if opened.is_none()
+        && (keys.just_pressed(KeyCode::ContextMenu) || gamepad_pressed)
+        && let Some(focused) = focused.0
+    {
+        // The focused view, or the closest ancestor with a menu
+        opened = std::iter::once(focused)
+            .chain(parents.iter_ancestors(focused))
+            .find(|entity| views.contains(*entity))
+            .and_then(|entity| rect_of(entity).map(|rect| (entity, rect.center())));
+    }
+
+    // Long-press is for touch screens, a held mouse button does not count
+    let touch = touches.first_pressed_position();
+    let pressed = views
+        .iter()
+        .find(|(.., interaction, _, _)| **interaction == Interaction::Pressed)
+        .filter(|_| touch.is_some())
+        .map(|(entity, ..)| entity);
+    match (pressed, long_press.as_mut()) {
+        (Some(entity), Some((held, elapsed))) if *held == entity => {
+            let before = *elapsed;
+            *elapsed += time.delta_secs();
+            if before < LONG_PRESS_SECS && *elapsed >= LONG_PRESS_SECS && opened.is_none() {
+                let position = touch.or_else(|| rect_of(entity).map(|rect| rect.center()));
+                opened = position.map(|position| (entity, position));
+            }
+        }
+        _ => *long_press = pressed.map(|entity| (entity, 0.0)),
+    }
+
+    if let Some((target, position)) = opened
+        && let Ok((_, menu, ..)) = views.get(target)
+        && !menu.0.is_empty()
+    {
+        close_all(&mut commands, &panels);
+        open_menu(&mut commands, &menu.0, target, None, position);
+    }
+}
+
+/// Runs the action of pressed items and opens submenus.
+fn press_menu_items(
+    mut commands: Commands,
+    items: Query<(Entity, &Interaction, &MenuItemButton), Changed<Interaction>>,
+    panels: Query<(Entity, &MenuPanel)>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+) {
+    for (entity, interaction, item) in &items {
+        if *interaction != Interaction::Pressed || !item.enabled {
+            continue;
+        }
+        let Ok((_, panel)) = panels.get(item.panel) else {
+            continue;
+        };
+        match &item.entry.kind {
+            MenuEntryKind::Action(action) => {
+                action(&mut commands, panel.target);
+                close_all(&mut commands, &panels);
+                return;
+            }
+            MenuEntryKind::Submenu(items) => {
+                open_submenu(&mut commands, entity, item.panel, items, &panels, &nodes);
+            }
+            MenuEntryKind::Separator => {}
+        }
+    }
+}
+
+/// Opens the submenu under the cursor and closes the ones it replaces.
+fn hover_submenus(
+    mut commands: Commands,
+    items: Query<(Entity, &Interaction, &MenuItemButton), Changed<Interaction>>,
+    panels: Query<(Entity, &MenuPanel)>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+) {
+    for (entity, interaction, item) in &items {
+        if *interaction != Interaction::Hovered {
+            continue;
+        }
+        match &item.entry.kind {
+            MenuEntryKind::Submenu(items) if item.enabled => {
+                open_submenu(&mut commands, entity, item.panel, items, &panels, &nodes);
+            }
+            _ => {
+                for (child, _) in panels.iter().filter(|(_, p)| p.parent == Some(item.panel)) {
+                    close_panel(&mut commands, child, &panels);
+                }
+            }
+        }
+    }
+}
+
+/// Opens `items` next to the `opener` item, replacing any other submenu of `parent`.
+///
+/// Does nothing if the submenu is already open.
+fn open_submenu(
+    commands: &mut Commands,
+    opener: Entity,
+    parent: Entity,
+    items: &Arc<MenuItems>,
+    panels: &Query<(Entity, &MenuPanel)>,
+    nodes: &Query<(&ComputedNode, &GlobalTransform)>,
+) {
+    let Ok((_, parent_panel)) = panels.get(parent) else {
+        return;
+    };
+    if panels.iter().any(|(_, p)| p.opener == Some(opener)) {
+        return;
+    }
+    for (child, _) in panels.iter().filter(|(_, p)| p.parent == Some(parent)) {
+        close_panel(commands, child, panels);
+    }
+    let Ok((node, transform)) = nodes.get(opener) else {
+        return;
+    };
+    let rect = logical_rect(node, transform);
+    let panel = MenuPanel {
+        target: parent_panel.target,
+        parent: Some(parent),
+        opener: Some(opener),
+        depth: parent_panel.depth + 1,
+        flip_x: Some(rect.min.x),
+    };
+    spawn_panel(commands, items, panel, Vec2::new(rect.max.x, rect.min.y));
+}
+
+fn highlight_menu_items(
+    focused: Res<FocusedEntity>,
+    mut items: Query<(Entity, &Interaction, &MenuItemButton, &mut BackgroundColor)>,
+) {
+    for (entity, interaction, item, mut background) in &mut items {
+        let highlighted =
+            item.enabled && (*interaction != Interaction::None || focused.0 == Some(entity));
+        let color = if highlighted {
+            HIGHLIGHT_COLOR.into()
+        } else {
+            Color::NONE
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+/// Keeps menus inside the window once their size is known.
+fn clamp_menus(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut panels: Query<(&mut Node, &MenuPanel, &ComputedNode), Changed<ComputedNode>>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let bounds = Vec2::new(window.width(), window.height());
+    for (mut node, panel, computed) in &mut panels {
+        let (Val::Px(left), Val::Px(top)) = (node.left, node.top) else {
+            continue;
+        };
+        let size = computed.size() * computed.inverse_scale_factor();
+        let mut position = Vec2::new(left, top);
+        if position.x + size.x > bounds.x {
+            position.x = match panel.flip_x {
+                Some(right) => right - size.x,
+                None => bounds.x - size.x,
+            };
+        }
+        position.y = position.y.min(bounds.y - size.y);
+        let position = position.max(Vec2::ZERO);
+        if position != Vec2::new(left, top) {
+            node.left = Val::Px(position.x);
+            node.top = Val::Px(position.y);
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::fmt;
+use std::sync::Arc;
+
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_math::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::ComputedNode;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
+use crate::border::{BorderStyle, BorderView};
+use crate::focus::{Focusable, FocusedEntity};
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::text::TextView;
+use crate::text::swoop_text::SText;
+
+use super::{HIGHLIGHT_COLOR, MenuItems, MenuPanel, close_all, logical_rect, open_menu};
+
+/// A top-level menu of a [`MenuBar`], spawned as the title that drops it down.
+#[derive(Component, Clone)]
+pub struct Menu(pub Arc<MenuItems>);
+
+impl fmt::Debug for Menu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Menu").field(&self.0).finish()
+    }
+}
+
+/// A trait for views holding the top-level menus of a menu bar.
+pub trait MenuBarView: ModifierView {
+    /// Adds a top-level menu after the existing ones.
+    ///
+    /// Item actions receive the menu bar entity.
+    ///
+    /// # Arguments
+    /// * `title` - The text shown in the bar.
+    /// * `build` - Adds the items of the dropdown.
+    fn menu(self, title: impl Into<String>, build: impl FnOnce(MenuItems) -> MenuItems) -> Self {
+        let title = title.into();
+        let menu = Menu(Arc::new(build(MenuItems::new())));
+        self.modifier(move |entity| {
+            entity.with_children(|bar| {
+                bar.spawn((
+                    Name::new(title.clone()),
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                        ..Default::default()
+                    },
+                    Button,
+                    Focusable::default(),
+                    BackgroundColor(Color::NONE),
+                    BorderRadius::all(Val::Px(4.0)),
+                    menu.clone(),
+                ))
+                .with_child(SText::new().text(title.clone()));
+            });
+        })
+    }
+}
+
+/// A horizontal bar of dropdown menus, as found at the top of editors and tools.
+///
+/// Click a title to open its menu, then hover other titles or press Left and Right
+/// to switch between them.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # fn save(_: &mut Commands, _: Entity) {}
+/// # fn save_as(_: &mut Commands, _: Entity) {}
+/// # fn open_level_1(_: &mut Commands, _: Entity) {}
+/// # fn toggle_grid(_: &mut Commands, _: Entity) {}
+/// # #[derive(Resource)]
+/// # struct Grid {
+/// #     visible: bool,
+/// # }
+/// MenuBar::new()
+///     .menu("File", |menu| {
+///         menu.item("Save", save).shortcut("Ctrl+S")
+///             .item("Save As...", save_as).shortcut("Ctrl+Shift+S")
+///             .separator()
+///             .submenu("Recent", |menu| menu.item("level_1.scn", open_level_1))
+///     })
+///     .menu("View", |menu| {
+///         menu.item("Show Grid", toggle_grid)
+///             .checked_if(|world| world.resource::<Grid>().visible)
+///     });
+/// ```
+#[derive(Bundle, Debug, Clone)]
+pub struct MenuBar {
+    /// Named "MenuBar" in the hierarchy
+    name: Name,
+    /// Row of menu titles with a little padding
+    node: Node,
+    /// Matched by `MenuBar` selectors and any added classes
+    class: ViewClass,
+    /// Spawns the menu titles once the bar is spawned
+    modifiers: ViewModifiers,
+    /// Border around the bar, none by default
+    border: BorderStyle,
+    /// Light gray fill of the bar
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+}
+
+impl Default for MenuBar {
+    fn default() -> Self {
+        Self {
+            name: Name::new("MenuBar"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(2.0),
+                padding: UiRect::all(Val::Px(2.0)),
+                ..Default::default()
+            },
+            class: ViewClass::new("MenuBar"),
+            modifiers: ViewModifiers::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::solid(Srgba::gray(0.93)),
+            shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl View for MenuBar {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for MenuBar {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for MenuBar {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl MenuBarView for MenuBar {}
+
+impl BackgroundView for MenuBar {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for MenuBar {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for MenuBar {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for MenuBar {}
+
+impl AnimationView for MenuBar {}
+
+impl BindingView for MenuBar {}
+
+type MenuTitle = (
+    Entity,
+    &'static Menu,
+    &'static ChildOf,
+    &'static ComputedNode,
+    &'static GlobalTransform,
+);
+
+/// Titles pressed, hovered or left by the pointer this frame.
+type ChangedTitle = (Changed<Interaction>, With<Menu>);
+
+/// Returns the title whose dropdown is open, if any.
+fn open_title(panels: &Query<(Entity, &MenuPanel)>, titles: &Query<MenuTitle>) -> Option<Entity> {
+    panels
+        .iter()
+        .filter_map(|(_, panel)| panel.opener.filter(|_| panel.depth == 0))
+        .find(|opener| titles.contains(*opener))
+}
+
+/// Replaces the open menus with the dropdown of `title`.
+fn drop_down(
+    commands: &mut Commands,
+    title: Entity,
+    panels: &Query<(Entity, &MenuPanel)>,
+    titles: &Query<MenuTitle>,
+) {
+    let Ok((_, menu, bar, node, transform)) = titles.get(title) else {
+        return;
+    };
+    let rect = logical_rect(node, transform);
+    close_all(commands, panels);
+    open_menu(
+        commands,
+        &menu.0,
+        bar.parent(),
+        Some(title),
+        Vec2::new(rect.min.x, rect.max.y),
+    );
+}
+
+/// Opens or closes the dropdown of pressed titles.
+pub(super) fn press_menu_titles(
+    mut commands: Commands,
+    pressed: Query<(Entity, &Interaction), ChangedTitle>,
+    panels: Query<(Entity, &MenuPanel)>,
+    titles: Query<MenuTitle>,
+) {
+    for (title, interaction) in &pressed {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if open_title(&panels, &titles) == Some(title) {
+            close_all(&mut commands, &panels);
+        } else {
+            drop_down(&mut commands, title, &panels, &titles);
+        }
+    }
+}
+
+/// Switches to the dropdown of a hovered title while another one of the same bar is open.
+pub(super) fn hover_menu_titles(
+    mut commands: Commands,
+    hovered: Query<(Entity, &Interaction, &ChildOf), ChangedTitle>,
+    panels: Query<(Entity, &MenuPanel)>,
+    titles: Query<MenuTitle>,
+) {
+    let Some(open) = open_title(&panels, &titles) else {
+        return;
+    };
+    for (title, interaction, bar) in &hovered {
+        let same_bar = titles
+            .get(open)
+            .is_ok_and(|(_, _, open_bar, ..)| open_bar.parent() == bar.parent());
+        if *interaction == Interaction::Hovered && title != open && same_bar {
+            drop_down(&mut commands, title, &panels, &titles);
+        }
+    }
+}
+
+/// Switches to the previous or next dropdown with Left and Right, unless a submenu is open.
+pub(super) fn switch_menus_with_keys(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    panels: Query<(Entity, &MenuPanel)>,
+    titles: Query<MenuTitle>,
+    children: Query<&Children>,
+) {
+    let gamepad_pressed = |button| gamepads.iter().any(|pad| pad.just_pressed(button));
+    let step = if keys.just_pressed(KeyCode::ArrowLeft) || gamepad_pressed(GamepadButton::DPadLeft)
+    {
+        -1
+    } else if keys.just_pressed(KeyCode::ArrowRight) || gamepad_pressed(GamepadButton::DPadRight) {
+        1
+    } else {
+        return;
+    };
+    if panels.iter().any(|(_, panel)| panel.depth > 0) {
+        return;
+    }
+    let Some((open, bar)) = open_title(&panels, &titles)
+        .and_then(|open| titles.get(open).ok())
+        .map(|(open, _, bar, ..)| (open, bar.parent()))
+    else {
+        return;
+    };
+    let siblings: Vec<Entity> = children
+        .get(bar)
+        .map(|children| {
+            children
+                .iter()
+                .filter(|child| titles.contains(*child))
+                .collect()
+        })
+        .unwrap_or_default();
+    let Some(index) = siblings.iter().position(|title| *title == open) else {
+        return;
+    };
+    let next = (index as isize + step).rem_euclid(siblings.len() as isize) as usize;
+    drop_down(&mut commands, siblings[next], &panels, &titles);
+}
+
+/// Highlights titles that are hovered, focused or dropped down.
+pub(super) fn highlight_menu_titles(
+    focused: Res<FocusedEntity>,
+    panels: Query<(Entity, &MenuPanel)>,
+    mut titles: Query<(Entity, &Interaction, &mut BackgroundColor), With<Menu>>,
+) {
+    for (title, interaction, mut background) in &mut titles {
+        let open = panels
+            .iter()
+            .any(|(_, panel)| panel.depth == 0 && panel.opener == Some(title));
+        let highlighted = open || *interaction != Interaction::None || focused.0 == Some(title);
+        let color = if highlighted {
+            HIGHLIGHT_COLOR.into()
+        } else {
+            Color::NONE
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use bevy_ecs::component::HookContext;
+use bevy_ecs::prelude::*;
+use bevy_ecs::world::DeferredWorld;
+use bevy_state::prelude::*;
+
+use crate::View;
+
+type Modifier = Arc<dyn Fn(&mut EntityWorldMut) + Send + Sync>;
+
+/// Deferred changes recorded on a view by modifiers such as `.animation()`.
+///
+/// Views are plain bundles, so modifiers that need extra components or
+/// relationships record a closure here instead. The closures run once,
+/// right after the view is spawned, and the component then removes itself.
+/// Views without modifiers keep an empty `ViewModifiers`.
+#[derive(Component, Clone, Default)]
+#[component(on_insert = apply_view_modifiers)]
+pub struct ViewModifiers(Vec<Modifier>);
+
+impl ViewModifiers {
+    /// Records a closure to run against the spawned entity.
+    pub fn push(&mut self, modifier: impl Fn(&mut EntityWorldMut) + Send + Sync + 'static) {
+        self.0.push(Arc::new(modifier));
+    }
+
+    /// Records a bundle to insert into the spawned entity.
+    pub fn insert(&mut self, bundle: impl Bundle + Clone) {
+        self.push(move |entity| {
+            entity.insert(bundle.clone());
+        });
+    }
+
+    /// Returns `true` if no modifiers were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Lets a view record modifiers that run once it is spawned.
+///
+/// Extension traits such as `AnimationView` build on this trait.
+pub trait ModifierView: View {
+    /// Provides mutable access to the modifiers applied once the view is spawned.
+    ///
+    /// Used by modifiers that need extra components, such as `.animation()`.
+    fn modifiers_node(&mut self) -> &mut ViewModifiers;
+
+    /// Records a change to apply to the entity right after it is spawned.
+    ///
+    /// # Arguments
+    /// * `modifier` - A closure receiving the spawned entity.
+    fn modifier(mut self, modifier: impl Fn(&mut EntityWorldMut) + Send + Sync + 'static) -> Self {
+        self.modifiers_node().push(modifier);
+        self
+    }
+
+    /// Despawns the view when the app leaves `state`.
+    ///
+    /// Requires state scoped entities to be enabled for `S`, which
+    /// [`add_swoop_screen`](crate::scope::SwoopScreenExt::add_swoop_screen) does.
+    ///
+    /// # Arguments
+    /// * `state` - The state the view belongs to, e.g. `GameState::Menu`.
+    fn scoped_to<S: States>(mut self, state: S) -> Self {
+        self.modifiers_node().insert(StateScoped(state));
+        self
+    }
+}
+
+impl Debug for ViewModifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ViewModifiers").field(&self.0.len()).finish()
+    }
+}
+
+fn apply_view_modifiers(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let Some(modifiers) = world.get::<ViewModifiers>(entity) else {
+        return;
+    };
+    if modifiers.is_empty() {
+        return;
+    }
+
+    let modifiers = modifiers.0.clone();
+    world.commands().queue(move |world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+        entity.remove::<ViewModifiers>();
+        for modifier in &modifiers {
+            modifier(&mut entity);
+        }
+    });
+}
@@ -0,0 +1,475 @@
+use std::fmt;
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_ecs::component::HookContext;
+use bevy_ecs::prelude::*;
+use bevy_ecs::world::DeferredWorld;
+use bevy_input::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::View;
+use crate::animation::AnimationView;
+use crate::animation::transition::TransitionExt;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::{BindingView, set_visible};
+use crate::border::{BorderStyle, BorderView};
+use crate::container::reorder::{self, ReorderState};
+use crate::drag::{self, DragState};
+use crate::focus::{FocusedEntity, OpenFocusScopes};
+use crate::menu::MenuPanel;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::prelude::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+
+pub mod prelude {
+    pub use super::{
+        NavigationExt, NavigationHost, NavigationPlugin, NavigationStack, NavigationStackView,
+        Screen, ScreenStack,
+    };
+}
+
+/// A page of UI shown by a [`NavigationStack`], such as a main or options menu.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// struct OptionsScreen;
+///
+/// impl Screen for OptionsScreen {
+///     fn view(&self) -> impl Bundle {
+///         (VStack::new(), children![TextButton::new().text("Controls").pack()])
+///     }
+/// }
+///
+/// # fn open_options(mut commands: Commands) {
+/// commands.push_screen(OptionsScreen);
+/// # }
+/// ```
+pub trait Screen: Send + Sync + 'static {
+    /// Builds the root view of the screen.
+    ///
+    /// Called again when returning to a screen that was not kept alive.
+    fn view(&self) -> impl Bundle;
+
+    /// Keeps the screen spawned but hidden while other screens are pushed on top,
+    /// preserving its state.
+    fn keep_alive(&self) -> bool {
+        false
+    }
+}
+
+type BuildScreen = Arc<dyn Fn(&mut World, Entity) -> Entity + Send + Sync>;
+
+struct ScreenEntry {
+    build: BuildScreen,
+    keep_alive: bool,
+    /// Root entity of the screen, while spawned
+    entity: Option<Entity>,
+    /// The view focused when another screen was pushed on top
+    focus: Option<Entity>,
+}
+
+impl ScreenEntry {
+    fn new<S: Screen>(screen: S) -> Self {
+        let keep_alive = screen.keep_alive();
+        Self {
+            build: Arc::new(move |world, host| world.spawn((screen.view(), ChildOf(host))).id()),
+            keep_alive,
+            entity: None,
+            focus: None,
+        }
+    }
+}
+
+/// The back stack of the [`NavigationStack`], topmost screen last.
+///
+/// Change it with [`NavigationExt::push_screen`] and [`NavigationExt::pop_screen`].
+#[derive(Resource, Default)]
+pub struct ScreenStack {
+    entries: Vec<ScreenEntry>,
+}
+
+impl fmt::Debug for ScreenStack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScreenStack")
+            .field("len", &self.entries.len())
+            .field("top", &self.top())
+            .finish()
+    }
+}
+
+impl ScreenStack {
+    /// Returns the number of screens on the stack.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether no screen was pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns whether there is a screen to go back to.
+    pub fn can_go_back(&self) -> bool {
+        self.entries.len() > 1
+    }
+
+    /// Returns the root entity of the visible screen, if spawned.
+    pub fn top(&self) -> Option<Entity> {
+        self.entries.last().and_then(|entry| entry.entity)
+    }
+}
+
+/// Screen navigation through [`Commands`].
+pub trait NavigationExt {
+    /// Shows `screen` on top of the current one, which is hidden or despawned.
+    fn push_screen<S: Screen>(&mut self, screen: S);
+
+    /// Returns to the previous screen, if any.
+    fn pop_screen(&mut self);
+}
+
+impl NavigationExt for Commands<'_, '_> {
+    fn push_screen<S: Screen>(&mut self, screen: S) {
+        self.queue(move |world: &mut World| push_screen(world, ScreenEntry::new(screen)));
+    }
+
+    fn pop_screen(&mut self) {
+        self.queue(pop_screen);
+    }
+}
+
+/// Returns the container screens are spawned into.
+fn host(world: &mut World) -> Option<Entity> {
+    world
+        .query_filtered::<Entity, With<NavigationHost>>()
+        .iter(world)
+        .next()
+}
+
+fn push_screen(world: &mut World, entry: ScreenEntry) {
+    let focus = world
+        .get_resource::<FocusedEntity>()
+        .and_then(|focused| focused.0);
+    let mut stack = world.resource_mut::<ScreenStack>();
+    let covered = stack.entries.last_mut().and_then(|top| {
+        top.focus = focus;
+        let entity = if top.keep_alive {
+            top.entity
+        } else {
+            top.entity.take()
+        };
+        entity.map(|entity| (entity, top.keep_alive))
+    });
+    stack.entries.push(entry);
+
+    if let Some((entity, keep_alive)) = covered
+        && let Ok(mut entity) = world.get_entity_mut(entity)
+    {
+        if keep_alive {
+            set_visible(&mut entity, false);
+        } else {
+            let entity = entity.id();
+            world.commands().entity(entity).despawn_with_transition();
+            world.flush();
+        }
+    }
+    show_top(world);
+}
+
+fn pop_screen(world: &mut World) {
+    let mut stack = world.resource_mut::<ScreenStack>();
+    if !stack.can_go_back() {
+        return;
+    }
+    let popped = stack.entries.pop().and_then(|entry| entry.entity);
+    let focus = stack.entries.last_mut().and_then(|top| top.focus.take());
+
+    if let Some(entity) = popped
+        && world.get_entity(entity).is_ok()
+    {
+        world.commands().entity(entity).despawn_with_transition();
+        world.flush();
+    }
+    show_top(world);
+
+    if let Some(focus) = focus
+        && world.get_entity(focus).is_ok()
+        && let Some(mut focused) = world.get_resource_mut::<FocusedEntity>()
+    {
+        focused.0 = Some(focus);
+    }
+}
+
+/// Shows the topmost screen, building it if it is not spawned.
+fn show_top(world: &mut World) {
+    let Some(host) = host(world) else {
+        // Built once a `NavigationStack` is spawned
+        return;
+    };
+    let Some(top) = world.resource::<ScreenStack>().entries.last() else {
+        return;
+    };
+    let (build, entity) = (top.build.clone(), top.entity);
+    match entity {
+        Some(entity) if world.get_entity(entity).is_ok() => {
+            set_visible(&mut world.entity_mut(entity), true);
+        }
+        _ => {
+            let entity = build(world, host);
+            if let Some(top) = world.resource_mut::<ScreenStack>().entries.last_mut() {
+                top.entity = Some(entity);
+            }
+        }
+    }
+}
+
+/// Marks the container of the [`ScreenStack`].
+///
+/// Screens are spawned into the host, so they are despawned along with it. The stack is
+/// kept, and its topmost screen is built again when a new host is spawned.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[component(on_remove = forget_screens)]
+pub struct NavigationHost {
+    /// Pops the top screen when Escape or the gamepad East button is pressed,
+    /// unless a menu, a trapping focus scope or a drag takes the press first
+    pub back_on_cancel: bool,
+}
+
+impl Default for NavigationHost {
+    fn default() -> Self {
+        Self {
+            back_on_cancel: true,
+        }
+    }
+}
+
+/// Forgets the screens spawned into a host that is going away.
+fn forget_screens(mut world: DeferredWorld, _: HookContext) {
+    if let Some(mut stack) = world.get_resource_mut::<ScreenStack>() {
+        for entry in &mut stack.entries {
+            entry.entity = None;
+        }
+    }
+}
+
+/// A trait for configuring a [`NavigationStack`].
+pub trait NavigationStackView: ModifierView {
+    /// Returns a mutable reference to the navigation settings of this view.
+    fn navigation_node(&mut self) -> &mut NavigationHost;
+
+    /// Sets whether Escape and the gamepad East button go back.
+    fn back_on_cancel(mut self, enabled: bool) -> Self {
+        self.navigation_node().back_on_cancel = enabled;
+        self
+    }
+
+    /// Pushes the first screen when the view is spawned.
+    fn root_screen<S: Screen>(self, screen: S) -> Self {
+        let screen = Arc::new(screen);
+        self.modifier(move |entity| {
+            let screen = screen.clone();
+            entity.world_scope(|world| {
+                if world
+                    .get_resource::<ScreenStack>()
+                    .is_some_and(ScreenStack::is_empty)
+                {
+                    push_screen(world, ScreenEntry::new(SharedScreen(screen)));
+                }
+            });
+        })
+    }
+}
+
+/// Lets a screen passed to [`NavigationStackView::root_screen`] be pushed by a `Fn` modifier.
+struct SharedScreen<S>(Arc<S>);
+
+impl<S: Screen> Screen for SharedScreen<S> {
+    fn view(&self) -> impl Bundle {
+        self.0.view()
+    }
+
+    fn keep_alive(&self) -> bool {
+        self.0.keep_alive()
+    }
+}
+
+/// A container showing the topmost screen of the [`ScreenStack`].
+///
+/// Only one `NavigationStack` should exist at a time.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # struct MainMenu;
+/// # impl Screen for MainMenu {
+/// #     fn view(&self) -> impl Bundle {
+/// #         VStack::new()
+/// #     }
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn(NavigationStack::new().frame(Val::Percent(100.0), Val::Percent(100.0)).root_screen(MainMenu));
+/// # }
+/// ```
+#[derive(Bundle, Debug, Clone)]
+pub struct NavigationStack {
+    /// Named "NavigationStack" in the hierarchy
+    name: Name,
+    /// Column holding the screen on top of the stack
+    node: Node,
+    /// Matched by `NavigationStack` selectors and any added classes
+    class: ViewClass,
+    /// Changes applied once the stack is spawned
+    modifiers: ViewModifiers,
+    /// Navigation settings
+    host: NavigationHost,
+    /// Border around the shown screen
+    border: BorderStyle,
+    /// Fill behind the shown screen
+    background: BackgroundStyle,
+    /// Drop shadow, none by default
+    shadow: BoxShadow,
+}
+
+impl Default for NavigationStack {
+    fn default() -> Self {
+        Self {
+            name: Name::new("NavigationStack"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Stretch,
+                ..Default::default()
+            },
+            class: ViewClass::new("NavigationStack"),
+            modifiers: ViewModifiers::default(),
+            host: NavigationHost::default(),
+            border: BorderStyle::default(),
+            background: BackgroundStyle::default(),
+            shadow: BoxShadow::default(),
+        }
+    }
+}
+
+impl View for NavigationStack {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for NavigationStack {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for NavigationStack {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl NavigationStackView for NavigationStack {
+    fn navigation_node(&mut self) -> &mut NavigationHost {
+        &mut self.host
+    }
+}
+
+impl BackgroundView for NavigationStack {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for NavigationStack {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for NavigationStack {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for NavigationStack {}
+
+impl AnimationView for NavigationStack {}
+
+impl BindingView for NavigationStack {}
+
+/// Screen navigation with a back stack, see [`NavigationStack`].
+///
+/// Requires Bevy's `InputPlugin`.
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenStack>().add_systems(
+            Update,
+            (
+                show_pushed_screens,
+                // Escape must see drags before it cancels them
+                navigate_back
+                    .before(drag::end_drags)
+                    .before(reorder::drop_rows),
+            ),
+        );
+    }
+}
+
+/// Shows screens pushed before their `NavigationStack` was spawned, or rebuilds the
+/// topmost screen when the stack is spawned again.
+fn show_pushed_screens(world: &mut World, hosts: &mut QueryState<(), Added<NavigationHost>>) {
+    if hosts.iter(world).next().is_none() {
+        return;
+    }
+    let top = world.resource::<ScreenStack>().top();
+    if top.is_none_or(|top| world.get_entity(top).is_err()) {
+        show_top(world);
+    }
+}
+
+/// State that handles Escape before back navigation does.
+type CancelTakers<'w> = (
+    Option<Res<'w, OpenFocusScopes>>,
+    Option<Res<'w, DragState>>,
+    Option<Res<'w, ReorderState>>,
+);
+
+fn navigate_back(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    stack: Res<ScreenStack>,
+    hosts: Query<&NavigationHost>,
+    menus: Query<(), With<MenuPanel>>,
+    (scopes, drag, reorder): CancelTakers,
+) {
+    // Escape closes open menus and cancels drags first, and stays inside modal scopes
+    let busy = !menus.is_empty()
+        || scopes.is_some_and(|scopes| scopes.trap().is_some())
+        || drag.is_some_and(|drag| drag.is_dragging())
+        || reorder.is_some_and(|reorder| reorder.is_dragging());
+    if !stack.can_go_back() || !hosts.iter().any(|host| host.back_on_cancel) || busy {
+        return;
+    }
+    if keys.just_pressed(KeyCode::Escape)
+        || gamepads
+            .iter()
+            .any(|pad| pad.just_pressed(GamepadButton::East))
+    {
+        commands.pop_screen();
+    }
+}
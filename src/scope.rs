@@ -0,0 +1,79 @@
+use std::marker::PhantomData;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_state::prelude::*;
+
+pub mod prelude {
+    pub use super::SwoopScreenExt;
+}
+
+/// Registers UI roots that live as long as a Bevy state.
+pub trait SwoopScreenExt {
+    /// Spawns the view returned by `build` when entering `state`, and despawns it
+    /// when leaving.
+    ///
+    /// `build` is a system returning the root view, so it can read resources and
+    /// assets. Systems with parameters must name their return type, or return
+    /// `impl Bundle + use<>`. Call this after the state is added with `init_state`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_color::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_state::prelude::*;
+    /// # use swoop_ui::prelude::*;
+    /// # #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Menu,
+    /// # }
+    /// # struct Theme {
+    /// #     background: Color,
+    /// # }
+    /// # #[derive(Resource)]
+    /// # struct Settings {
+    /// #     theme: Theme,
+    /// # }
+    /// # let mut app = App::new();
+    /// fn build_menu(settings: Res<Settings>) -> VStack {
+    ///     VStack::new().background_color(settings.theme.background)
+    /// }
+    ///
+    /// app.init_state::<GameState>()
+    ///     .add_swoop_screen(GameState::Menu, build_menu);
+    /// ```
+    fn add_swoop_screen<S, B, M>(
+        &mut self,
+        state: S,
+        build: impl IntoSystem<(), B, M>,
+    ) -> &mut Self
+    where
+        S: States,
+        B: Bundle;
+}
+
+/// Marks states whose scoped entities are already enabled.
+#[derive(Resource)]
+struct ScopedEntitiesEnabled<S>(PhantomData<fn(S)>);
+
+impl SwoopScreenExt for App {
+    fn add_swoop_screen<S, B, M>(&mut self, state: S, build: impl IntoSystem<(), B, M>) -> &mut Self
+    where
+        S: States,
+        B: Bundle,
+    {
+        if !self.world().contains_resource::<ScopedEntitiesEnabled<S>>() {
+            self.enable_state_scoped_entities::<S>()
+                .insert_resource(ScopedEntitiesEnabled::<S>(PhantomData));
+        }
+        let scope = state.clone();
+        self.add_systems(
+            OnEnter(state),
+            build.pipe(move |In(view): In<B>, mut commands: Commands| {
+                commands.spawn((view, StateScoped(scope.clone())));
+            }),
+        )
+    }
+}
@@ -0,0 +1,411 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use bevy_app::prelude::*;
+use bevy_asset::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypePath;
+use bevy_text::prelude::*;
+use bevy_ui::prelude::*;
+
+/// Stylesheet text format parser
+pub mod parser;
+
+/// Asset loader for stylesheet files
+pub mod loader;
+
+pub mod prelude {
+    pub use super::loader::{StyleSheetLoader, StyleSheetLoaderError};
+    pub use super::parser::{Declaration, ParseError, Selector, StyleRule};
+    pub use super::{ClassView, StyleSheet, StyleSheetPlugin, StyleSheets, ViewClass};
+}
+
+use crate::View;
+use crate::animation::AnimationSystems;
+use loader::StyleSheetLoader;
+use parser::{Declaration, StyleRule};
+
+/// Identifies a view for stylesheet matching.
+///
+/// Every view carries its type name (e.g. `HStack`, `TextButton`) and a list of
+/// user supplied classes added with [`ClassView::class`].
+#[derive(Component, Debug, Clone)]
+pub struct ViewClass {
+    /// The view type name used by type selectors
+    kind: &'static str,
+    /// Classes used by `.class` selectors
+    classes: Vec<Cow<'static, str>>,
+}
+
+impl ViewClass {
+    /// Creates a class list for the given view type name.
+    pub fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            classes: Vec::new(),
+        }
+    }
+
+    /// Returns the view type name.
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Returns the classes attached to this view.
+    pub fn classes(&self) -> &[Cow<'static, str>] {
+        &self.classes
+    }
+
+    /// Adds a class, ignoring duplicates.
+    pub fn add(&mut self, class: impl Into<Cow<'static, str>>) {
+        let class = class.into();
+        if !self.contains(&class) {
+            self.classes.push(class);
+        }
+    }
+
+    /// Removes a class if present.
+    pub fn remove(&mut self, class: &str) {
+        self.classes.retain(|c| c != class);
+    }
+
+    /// Returns `true` if the class is attached to this view.
+    pub fn contains(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c == class)
+    }
+}
+
+/// Lets a view carry stylesheet classes.
+///
+/// Views without a `ViewClass` are left untouched by stylesheets.
+pub trait ClassView: View {
+    /// Provides mutable access to the underlying `ViewClass` component.
+    ///
+    /// Used by stylesheets to match type and class selectors.
+    fn class_node(&mut self) -> &mut ViewClass;
+
+    /// Adds a stylesheet class to this instance.
+    ///
+    /// Matches `.class` selectors in loaded stylesheets.
+    ///
+    /// # Arguments
+    /// * `class` - The class name, provided as a `&str`, `String`, or `Cow`.
+    fn class(mut self, class: impl Into<Cow<'static, str>>) -> Self {
+        self.class_node().add(class);
+        self
+    }
+}
+
+/// A parsed stylesheet asset.
+///
+/// Stylesheets are written in a small CSS subset, for example:
+///
+/// ```css
+/// HStack.toolbar, #MainHeader {
+///     padding: 4px 16px;
+///     background-color: #1a1a33;
+///     border-radius: 8px;
+/// }
+///
+/// TextButton { font-size: 18px; text-color: white; }
+/// ```
+#[derive(Asset, TypePath, Debug, Clone, Default)]
+pub struct StyleSheet {
+    rules: Vec<StyleRule>,
+}
+
+impl StyleSheet {
+    /// Parses a stylesheet from its text source.
+    pub fn parse(source: &str) -> Result<Self, parser::ParseError> {
+        parser::parse(source).map(|rules| Self { rules })
+    }
+
+    /// Returns the rules in declaration order.
+    pub fn rules(&self) -> &[StyleRule] {
+        &self.rules
+    }
+}
+
+/// The stylesheets currently applied to all views, in increasing priority.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct StyleSheets(pub Vec<Handle<StyleSheet>>);
+
+impl StyleSheets {
+    /// Appends a stylesheet, giving it priority over previously added ones.
+    pub fn push(&mut self, sheet: Handle<StyleSheet>) {
+        self.0.push(sheet);
+    }
+}
+
+/// Loads stylesheet assets and applies them to views.
+///
+/// Views pick up matching rules when spawned or when their classes change,
+/// and all views are restyled whenever a stylesheet is (re)loaded. Properties
+/// no rule declares anymore go back to the values the view was built with.
+pub struct StyleSheetPlugin;
+
+impl Plugin for StyleSheetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<StyleSheet>()
+            .init_asset_loader::<StyleSheetLoader>()
+            .init_resource::<StyleSheets>()
+            .add_systems(
+                PostUpdate,
+                apply_style_sheets
+                    .before(AnimationSystems)
+                    .before(bevy_ui::UiSystem::Prepare),
+            );
+    }
+}
+
+/// Values a stylesheet overwrote on an entity, as they were before it was first styled.
+///
+/// Restored before the entity is restyled, so declarations removed from a reloaded
+/// sheet, or belonging to a removed class, stop applying.
+#[derive(Component, Debug, Clone)]
+struct UnstyledValues {
+    declarations: Vec<Declaration>,
+    position_type: Option<PositionType>,
+}
+
+impl UnstyledValues {
+    /// Remembers `original` unless a value was already saved for the same property.
+    fn save(&mut self, original: Declaration) {
+        let property = std::mem::discriminant(&original);
+        if !self
+            .declarations
+            .iter()
+            .any(|saved| std::mem::discriminant(saved) == property)
+        {
+            self.declarations.push(original);
+        }
+    }
+}
+
+type StyleTarget = (
+    Option<&'static mut Node>,
+    Option<&'static mut BorderRadius>,
+    Option<&'static mut BorderColor>,
+    Option<&'static mut BackgroundColor>,
+    Option<&'static mut TextFont>,
+    Option<&'static mut TextColor>,
+    Option<&'static mut UnstyledValues>,
+);
+
+fn apply_style_sheets(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<StyleSheet>>,
+    sheets: Res<StyleSheets>,
+    assets: Res<Assets<StyleSheet>>,
+    views: Query<(Entity, Ref<ViewClass>, Option<&Name>)>,
+    children: Query<&Children>,
+    mut targets: Query<StyleTarget>,
+) {
+    let reload = sheets.is_changed()
+        || events.read().any(|event| {
+            sheets.0.iter().any(|sheet| match event {
+                AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+                    *id == sheet.id()
+                }
+                _ => false,
+            })
+        });
+
+    let mut rules: Vec<&StyleRule> = sheets
+        .0
+        .iter()
+        .filter_map(|sheet| assets.get(sheet))
+        .flat_map(StyleSheet::rules)
+        .collect();
+    if rules.is_empty() && !reload {
+        return;
+    }
+    rules.sort_by_key(|rule| rule.specificity());
+
+    // Values saved this frame for entities styled for the first time
+    let mut unstyled = HashMap::new();
+    for (entity, class, name) in &views {
+        if !reload && !class.is_changed() {
+            continue;
+        }
+        restore_unstyled(entity, &views, &children, &mut targets);
+        let name = name.map(Name::as_str);
+        for rule in rules.iter().filter(|rule| rule.matches(&class, name)) {
+            for declaration in rule.declarations() {
+                apply_declaration(entity, declaration, &children, &mut targets, &mut unstyled);
+            }
+        }
+    }
+    for (entity, values) in unstyled {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.try_insert(values);
+        }
+    }
+}
+
+/// Restores the values stylesheets overwrote on a view and on its non-view children.
+fn restore_unstyled(
+    entity: Entity,
+    views: &Query<(Entity, Ref<ViewClass>, Option<&Name>)>,
+    children: &Query<&Children>,
+    targets: &mut Query<StyleTarget>,
+) {
+    if let Ok((
+        mut node,
+        mut radius,
+        mut border_color,
+        mut background,
+        mut font,
+        mut text_color,
+        Some(unstyled),
+    )) = targets.get_mut(entity)
+    {
+        for original in &unstyled.declarations {
+            write_declaration(
+                original,
+                (
+                    node.as_deref_mut(),
+                    radius.as_deref_mut(),
+                    border_color.as_deref_mut(),
+                    background.as_deref_mut(),
+                    font.as_deref_mut(),
+                    text_color.as_deref_mut(),
+                ),
+            );
+        }
+        // Restoring `top`, `left`, ... made the node absolutely positioned again
+        if let (Some(node), Some(position_type)) = (node.as_mut(), unstyled.position_type) {
+            node.position_type = position_type;
+        }
+    }
+    for child in children.get(entity).into_iter().flatten() {
+        if !views.contains(*child) {
+            restore_unstyled(*child, views, children, targets);
+        }
+    }
+}
+
+fn apply_declaration(
+    entity: Entity,
+    declaration: &Declaration,
+    children: &Query<&Children>,
+    targets: &mut Query<StyleTarget>,
+    unstyled: &mut HashMap<Entity, UnstyledValues>,
+) {
+    let Ok((
+        mut node,
+        mut radius,
+        mut border_color,
+        mut background,
+        mut font,
+        mut text_color,
+        saved,
+    )) = targets.get_mut(entity)
+    else {
+        return;
+    };
+
+    if matches!(
+        declaration,
+        Declaration::FontSize(_) | Declaration::TextColor(_)
+    ) && font.is_none()
+    {
+        // A view without text of its own passes font and color on to its labels
+        for child in children.get(entity).into_iter().flatten() {
+            apply_declaration(*child, declaration, children, targets, unstyled);
+        }
+        return;
+    }
+
+    let original = match declaration {
+        Declaration::FontSize(_) => font
+            .as_ref()
+            .map(|font| Declaration::FontSize(font.font_size)),
+        Declaration::TextColor(_) => text_color
+            .as_ref()
+            .map(|color| Declaration::TextColor(color.0)),
+        Declaration::BorderRadius(_) => radius
+            .as_ref()
+            .map(|radius| Declaration::BorderRadius(**radius)),
+        Declaration::BorderColor(_) => border_color
+            .as_ref()
+            .map(|color| Declaration::BorderColor(color.0)),
+        Declaration::BackgroundColor(_) => background
+            .as_ref()
+            .map(|color| Declaration::BackgroundColor(color.0)),
+        layout => node.as_ref().map(|node| layout.read_from_node(node)),
+    };
+    if let Some(original) = original {
+        let position_type = node.as_ref().map(|node| node.position_type);
+        match saved {
+            Some(mut saved) => saved.save(original),
+            None => unstyled
+                .entry(entity)
+                .or_insert_with(|| UnstyledValues {
+                    declarations: Vec::new(),
+                    position_type,
+                })
+                .save(original),
+        }
+    }
+
+    write_declaration(
+        declaration,
+        (
+            node.as_deref_mut(),
+            radius.as_deref_mut(),
+            border_color.as_deref_mut(),
+            background.as_deref_mut(),
+            font.as_deref_mut(),
+            text_color.as_deref_mut(),
+        ),
+    );
+}
+
+type StyleValues<'a> = (
+    Option<&'a mut Node>,
+    Option<&'a mut BorderRadius>,
+    Option<&'a mut BorderColor>,
+    Option<&'a mut BackgroundColor>,
+    Option<&'a mut TextFont>,
+    Option<&'a mut TextColor>,
+);
+
+/// Writes a declaration to the matching component, if the entity has it.
+fn write_declaration(
+    declaration: &Declaration,
+    (node, radius, border_color, background, font, text_color): StyleValues,
+) {
+    match declaration {
+        Declaration::FontSize(size) => {
+            if let Some(font) = font {
+                font.font_size = *size;
+            }
+        }
+        Declaration::TextColor(color) => {
+            if let Some(text_color) = text_color {
+                text_color.0 = *color;
+            }
+        }
+        Declaration::BorderRadius(value) => {
+            if let Some(radius) = radius {
+                *radius = *value;
+            }
+        }
+        Declaration::BorderColor(color) => {
+            if let Some(border_color) = border_color {
+                border_color.0 = *color;
+            }
+        }
+        Declaration::BackgroundColor(color) => {
+            if let Some(background) = background {
+                background.0 = *color;
+            }
+        }
+        layout => {
+            if let Some(node) = node {
+                layout.apply_to_node(node);
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use bevy_asset::io::Reader;
+use bevy_asset::{AssetLoader, LoadContext};
+
+use super::StyleSheet;
+use super::parser::ParseError;
+
+/// Loads `.css` files containing the stylesheet subset as [`StyleSheet`] assets.
+#[derive(Debug, Default)]
+pub struct StyleSheetLoader;
+
+/// Errors that can occur while loading a stylesheet.
+#[derive(Debug)]
+pub enum StyleSheetLoaderError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not valid UTF-8
+    Utf8(std::str::Utf8Error),
+    /// The file contents could not be parsed
+    Parse(ParseError),
+}
+
+impl fmt::Display for StyleSheetLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read stylesheet: {err}"),
+            Self::Utf8(err) => write!(f, "stylesheet is not valid UTF-8: {err}"),
+            Self::Parse(err) => write!(f, "could not parse stylesheet: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for StyleSheetLoaderError {}
+
+impl AssetLoader for StyleSheetLoader {
+    type Asset = StyleSheet;
+    type Settings = ();
+    type Error = StyleSheetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(StyleSheetLoaderError::Io)?;
+        let source = std::str::from_utf8(&bytes).map_err(StyleSheetLoaderError::Utf8)?;
+        StyleSheet::parse(source).map_err(StyleSheetLoaderError::Parse)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["css"]
+    }
+}
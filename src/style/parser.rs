@@ -0,0 +1,697 @@
+use std::fmt;
+
+use bevy_color::prelude::*;
+use bevy_ui::prelude::*;
+
+use super::ViewClass;
+
+/// An error produced while parsing a stylesheet, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column number, counted in characters
+    pub column: usize,
+    /// Human readable description of the problem
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A compound selector such as `HStack`, `.primary`, `#MainHeader` or `HStack.toolbar`.
+///
+/// All parts present in the selector must match the view.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selector {
+    /// View type name, e.g. `HStack` or `TextButton`
+    pub kind: Option<String>,
+    /// Classes added with `View::class`
+    pub classes: Vec<String>,
+    /// Value of the entity's `Name` component
+    pub name: Option<String>,
+}
+
+impl Selector {
+    /// Returns `true` if a view with the given class list and name matches this selector.
+    pub fn matches(&self, class: &ViewClass, name: Option<&str>) -> bool {
+        self.kind.as_deref().is_none_or(|kind| kind == class.kind())
+            && self.classes.iter().all(|c| class.contains(c))
+            && self.name.as_deref().is_none_or(|n| Some(n) == name)
+    }
+
+    /// CSS-like specificity: names beat classes, classes beat type names.
+    pub fn specificity(&self) -> (usize, usize, usize) {
+        (
+            self.name.is_some() as usize,
+            self.classes.len(),
+            self.kind.is_some() as usize,
+        )
+    }
+}
+
+/// A single property assignment inside a rule.
+///
+/// Property names match the builder methods of the view traits,
+/// written in kebab-case (`background-color` for `background_color`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Declaration {
+    Padding(UiRect),
+    Margin(UiRect),
+    Border(UiRect),
+    Width(Val),
+    Height(Val),
+    MinWidth(Val),
+    MaxWidth(Val),
+    MinHeight(Val),
+    MaxHeight(Val),
+    RowGap(Val),
+    ColumnGap(Val),
+    FlexGrow(f32),
+    FlexShrink(f32),
+    Top(Val),
+    Left(Val),
+    Right(Val),
+    Bottom(Val),
+    BorderRadius(BorderRadius),
+    BorderColor(Color),
+    BackgroundColor(Color),
+    FontSize(f32),
+    TextColor(Color),
+}
+
+impl Declaration {
+    /// Applies layout declarations to a `Node`; other declarations are ignored.
+    pub fn apply_to_node(&self, node: &mut Node) {
+        match *self {
+            Declaration::Padding(rect) => node.padding = rect,
+            Declaration::Margin(rect) => node.margin = rect,
+            Declaration::Border(rect) => node.border = rect,
+            Declaration::Width(val) => node.width = val,
+            Declaration::Height(val) => node.height = val,
+            Declaration::MinWidth(val) => node.min_width = val,
+            Declaration::MaxWidth(val) => node.max_width = val,
+            Declaration::MinHeight(val) => node.min_height = val,
+            Declaration::MaxHeight(val) => node.max_height = val,
+            Declaration::RowGap(val) => node.row_gap = val,
+            Declaration::ColumnGap(val) => node.column_gap = val,
+            Declaration::FlexGrow(grow) => node.flex_grow = grow,
+            Declaration::FlexShrink(shrink) => node.flex_shrink = shrink,
+            Declaration::Top(val) => {
+                node.position_type = PositionType::Absolute;
+                node.top = val;
+            }
+            Declaration::Left(val) => {
+                node.position_type = PositionType::Absolute;
+                node.left = val;
+            }
+            Declaration::Right(val) => {
+                node.position_type = PositionType::Absolute;
+                node.right = val;
+            }
+            Declaration::Bottom(val) => {
+                node.position_type = PositionType::Absolute;
+                node.bottom = val;
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns this layout declaration with the value currently set on `node`.
+    ///
+    /// Other declarations are returned unchanged.
+    pub fn read_from_node(&self, node: &Node) -> Self {
+        match self {
+            Declaration::Padding(_) => Declaration::Padding(node.padding),
+            Declaration::Margin(_) => Declaration::Margin(node.margin),
+            Declaration::Border(_) => Declaration::Border(node.border),
+            Declaration::Width(_) => Declaration::Width(node.width),
+            Declaration::Height(_) => Declaration::Height(node.height),
+            Declaration::MinWidth(_) => Declaration::MinWidth(node.min_width),
+            Declaration::MaxWidth(_) => Declaration::MaxWidth(node.max_width),
+            Declaration::MinHeight(_) => Declaration::MinHeight(node.min_height),
+            Declaration::MaxHeight(_) => Declaration::MaxHeight(node.max_height),
+            Declaration::RowGap(_) => Declaration::RowGap(node.row_gap),
+            Declaration::ColumnGap(_) => Declaration::ColumnGap(node.column_gap),
+            Declaration::FlexGrow(_) => Declaration::FlexGrow(node.flex_grow),
+            Declaration::FlexShrink(_) => Declaration::FlexShrink(node.flex_shrink),
+            Declaration::Top(_) => Declaration::Top(node.top),
+            Declaration::Left(_) => Declaration::Left(node.left),
+            Declaration::Right(_) => Declaration::Right(node.right),
+            Declaration::Bottom(_) => Declaration::Bottom(node.bottom),
+            other => other.clone(),
+        }
+    }
+}
+
+/// A selector together with the declarations it applies.
+///
+/// Comma separated selector lists are split into one rule per selector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleRule {
+    selector: Selector,
+    declarations: Vec<Declaration>,
+}
+
+impl StyleRule {
+    /// Returns the selector of this rule.
+    pub fn selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    /// Returns the declarations in source order.
+    pub fn declarations(&self) -> &[Declaration] {
+        &self.declarations
+    }
+
+    /// Returns `true` if the rule applies to a view with the given class list and name.
+    pub fn matches(&self, class: &ViewClass, name: Option<&str>) -> bool {
+        self.selector.matches(class, name)
+    }
+
+    /// Returns the specificity of the rule's selector.
+    pub fn specificity(&self) -> (usize, usize, usize) {
+        self.selector.specificity()
+    }
+}
+
+/// Parses stylesheet source into a list of rules.
+pub fn parse(source: &str) -> Result<Vec<StyleRule>, ParseError> {
+    let mut cursor = Cursor::new(source);
+    let mut rules = Vec::new();
+
+    loop {
+        cursor.skip_trivia()?;
+        if cursor.peek().is_none() {
+            return Ok(rules);
+        }
+
+        let mut selectors = vec![cursor.selector()?];
+        cursor.skip_trivia()?;
+        while cursor.eat(',') {
+            cursor.skip_trivia()?;
+            selectors.push(cursor.selector()?);
+            cursor.skip_trivia()?;
+        }
+        cursor.expect('{')?;
+        let declarations = cursor.declarations()?;
+
+        rules.extend(selectors.into_iter().map(|selector| StyleRule {
+            selector,
+            declarations: declarations.clone(),
+        }));
+    }
+}
+
+struct Cursor<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            chars: source.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+
+    fn error_at(&self, (line, column): (usize, usize), message: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(c) => Err(self.error(format!("expected `{expected}`, found `{c}`"))),
+            None => Err(self.error(format!("expected `{expected}`, found end of file"))),
+        }
+    }
+
+    /// Skips whitespace and `/* ... */` comments.
+    fn skip_trivia(&mut self) -> Result<(), ParseError> {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.bump();
+                }
+                Some('/') => {
+                    let start = self.position();
+                    self.bump();
+                    if !self.eat('*') {
+                        return Err(self.error_at(start, "unexpected `/`"));
+                    }
+                    loop {
+                        match self.bump() {
+                            Some('*') if self.eat('/') => break,
+                            Some(_) => {}
+                            None => return Err(self.error_at(start, "unterminated comment")),
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, ParseError> {
+        let mut ident = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                ident.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        if ident.is_empty() {
+            return Err(match self.peek() {
+                Some(c) => self.error(format!("expected identifier, found `{c}`")),
+                None => self.error("expected identifier, found end of file"),
+            });
+        }
+        Ok(ident)
+    }
+
+    fn selector(&mut self) -> Result<Selector, ParseError> {
+        let mut selector = Selector::default();
+        if self.peek().is_some_and(char::is_alphabetic) {
+            selector.kind = Some(self.ident()?);
+        }
+        loop {
+            match self.peek() {
+                Some('.') => {
+                    self.bump();
+                    selector.classes.push(self.ident()?);
+                }
+                Some('#') => {
+                    let start = self.position();
+                    self.bump();
+                    if selector.name.is_some() {
+                        return Err(self.error_at(start, "selector has more than one name"));
+                    }
+                    selector.name = Some(self.ident()?);
+                }
+                _ => break,
+            }
+        }
+        if selector == Selector::default() {
+            return Err(match self.peek() {
+                Some(c) => self.error(format!("expected selector, found `{c}`")),
+                None => self.error("expected selector, found end of file"),
+            });
+        }
+        Ok(selector)
+    }
+
+    /// Parses declarations up to and including the closing `}`.
+    fn declarations(&mut self) -> Result<Vec<Declaration>, ParseError> {
+        let mut declarations = Vec::new();
+        loop {
+            self.skip_trivia()?;
+            if self.eat('}') {
+                return Ok(declarations);
+            }
+            if self.peek().is_none() {
+                return Err(self.error("expected `}`, found end of file"));
+            }
+
+            let property_start = self.position();
+            let property = self.ident()?;
+            self.skip_trivia()?;
+            self.expect(':')?;
+            self.skip_trivia()?;
+
+            let value_start = self.position();
+            let mut value = String::new();
+            while let Some(c) = self.peek() {
+                if c == ';' || c == '}' {
+                    break;
+                }
+                value.push(c);
+                self.bump();
+            }
+            self.eat(';');
+
+            let value = value.trim();
+            if value.is_empty() {
+                return Err(self.error_at(value_start, format!("missing value for `{property}`")));
+            }
+            let declaration = parse_declaration(&property, value).map_err(|err| match err {
+                ValueError::UnknownProperty => {
+                    self.error_at(property_start, format!("unknown property `{property}`"))
+                }
+                ValueError::Invalid(message) => self.error_at(
+                    value_start,
+                    format!("invalid value `{value}` for `{property}`: {message}"),
+                ),
+            })?;
+            declarations.push(declaration);
+        }
+    }
+}
+
+enum ValueError {
+    UnknownProperty,
+    Invalid(String),
+}
+
+fn parse_declaration(property: &str, value: &str) -> Result<Declaration, ValueError> {
+    Ok(match property {
+        "padding" => Declaration::Padding(parse_rect(value)?),
+        "margin" => Declaration::Margin(parse_rect(value)?),
+        "border" => Declaration::Border(parse_rect(value)?),
+        "width" => Declaration::Width(parse_val(value)?),
+        "height" => Declaration::Height(parse_val(value)?),
+        "min-width" => Declaration::MinWidth(parse_val(value)?),
+        "max-width" => Declaration::MaxWidth(parse_val(value)?),
+        "min-height" => Declaration::MinHeight(parse_val(value)?),
+        "max-height" => Declaration::MaxHeight(parse_val(value)?),
+        "row-gap" => Declaration::RowGap(parse_val(value)?),
+        "column-gap" => Declaration::ColumnGap(parse_val(value)?),
+        "flex-grow" => Declaration::FlexGrow(parse_number(value)?),
+        "flex-shrink" => Declaration::FlexShrink(parse_number(value)?),
+        "top" => Declaration::Top(parse_val(value)?),
+        "left" => Declaration::Left(parse_val(value)?),
+        "right" => Declaration::Right(parse_val(value)?),
+        "bottom" => Declaration::Bottom(parse_val(value)?),
+        "border-radius" => Declaration::BorderRadius(parse_radius(value)?),
+        "border-color" => Declaration::BorderColor(parse_color(value)?),
+        "background-color" => Declaration::BackgroundColor(parse_color(value)?),
+        "font-size" => Declaration::FontSize(parse_font_size(value)?),
+        "text-color" => Declaration::TextColor(parse_color(value)?),
+        _ => return Err(ValueError::UnknownProperty),
+    })
+}
+
+fn parse_number(value: &str) -> Result<f32, ValueError> {
+    value
+        .parse()
+        .map_err(|_| ValueError::Invalid("expected a number".into()))
+}
+
+fn parse_font_size(value: &str) -> Result<f32, ValueError> {
+    parse_number(value.strip_suffix("px").unwrap_or(value))
+}
+
+type Unit = fn(f32) -> Val;
+
+fn parse_val(value: &str) -> Result<Val, ValueError> {
+    if value == "auto" {
+        return Ok(Val::Auto);
+    }
+    const UNITS: [(&str, Unit); 6] = [
+        ("px", Val::Px),
+        ("%", Val::Percent),
+        ("vw", Val::Vw),
+        ("vh", Val::Vh),
+        ("vmin", Val::VMin),
+        ("vmax", Val::VMax),
+    ];
+    for (suffix, unit) in UNITS {
+        if let Some(number) = value.strip_suffix(suffix) {
+            return parse_number(number).map(unit);
+        }
+    }
+    match parse_number(value) {
+        Ok(0.0) => Ok(Val::Px(0.0)),
+        _ => Err(ValueError::Invalid(
+            "expected a length such as `10px`, `50%` or `auto`".into(),
+        )),
+    }
+}
+
+fn parse_vals(value: &str) -> Result<Vec<Val>, ValueError> {
+    let vals = value
+        .split_whitespace()
+        .map(parse_val)
+        .collect::<Result<Vec<_>, _>>()?;
+    if vals.is_empty() || vals.len() > 4 {
+        return Err(ValueError::Invalid("expected one to four lengths".into()));
+    }
+    Ok(vals)
+}
+
+/// CSS shorthand order: top, right, bottom, left.
+fn parse_rect(value: &str) -> Result<UiRect, ValueError> {
+    let vals = parse_vals(value)?;
+    let (top, right, bottom, left) = match vals[..] {
+        [all] => (all, all, all, all),
+        [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+        [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+        [top, right, bottom, left] => (top, right, bottom, left),
+        _ => unreachable!(),
+    };
+    Ok(UiRect::new(left, right, top, bottom))
+}
+
+/// CSS shorthand order: top-left, top-right, bottom-right, bottom-left.
+fn parse_radius(value: &str) -> Result<BorderRadius, ValueError> {
+    let vals = parse_vals(value)?;
+    let (top_left, top_right, bottom_right, bottom_left) = match vals[..] {
+        [all] => (all, all, all, all),
+        [a, b] => (a, b, a, b),
+        [a, b, c] => (a, b, c, b),
+        [a, b, c, d] => (a, b, c, d),
+        _ => unreachable!(),
+    };
+    Ok(BorderRadius::new(
+        top_left,
+        top_right,
+        bottom_right,
+        bottom_left,
+    ))
+}
+
+fn parse_color(value: &str) -> Result<Color, ValueError> {
+    if value.starts_with('#') {
+        return Srgba::hex(value)
+            .map(Color::from)
+            .map_err(|_| ValueError::Invalid("expected `#rgb`, `#rrggbb` or `#rrggbbaa`".into()));
+    }
+    if let Some((function, args)) = value
+        .strip_suffix(')')
+        .and_then(|rest| rest.split_once('('))
+        && matches!(function, "rgb" | "rgba")
+    {
+        let channels = args
+            .split(',')
+            .map(|c| parse_number(c.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        if channels[..channels.len().min(3)]
+            .iter()
+            .any(|c| !(0.0..=255.0).contains(c))
+        {
+            return Err(ValueError::Invalid(
+                "color channels must be between 0 and 255".into(),
+            ));
+        }
+        return match (function, &channels[..]) {
+            ("rgb", &[r, g, b]) => Ok(Color::srgb_u8(r as u8, g as u8, b as u8)),
+            ("rgba", &[r, g, b, a]) if (0.0..=1.0).contains(&a) => {
+                Ok(Color::srgba(r / 255.0, g / 255.0, b / 255.0, a))
+            }
+            ("rgba", &[_, _, _, _]) => {
+                Err(ValueError::Invalid("alpha must be between 0 and 1".into()))
+            }
+            ("rgb", _) => Err(ValueError::Invalid("`rgb` expects three channels".into())),
+            _ => Err(ValueError::Invalid("`rgba` expects four channels".into())),
+        };
+    }
+    Ok(match value {
+        "white" => Srgba::WHITE.into(),
+        "black" => Srgba::BLACK.into(),
+        "red" => Srgba::RED.into(),
+        "green" => Srgba::GREEN.into(),
+        "blue" => Srgba::BLUE.into(),
+        "gray" | "grey" => Srgba::gray(0.5).into(),
+        "transparent" | "none" => Srgba::NONE.into(),
+        _ => return Err(ValueError::Invalid("unknown color".into())),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(source: &str) -> ParseError {
+        parse(source).expect_err("source should not parse")
+    }
+
+    #[test]
+    fn parses_selectors_and_declarations() {
+        let rules = parse(
+            "/* toolbar */\n\
+             HStack.toolbar.dark, #MainHeader {\n\
+                 padding: 4px 16px;\n\
+                 background-color: #1a1a33;\n\
+                 border-radius: 8px 50%;\n\
+             }\n\
+             .title { font-size: 18px; text-color: rgb(255, 0, 0); width: auto }",
+        )
+        .unwrap();
+
+        assert_eq!(rules.len(), 3);
+        assert_eq!(
+            *rules[0].selector(),
+            Selector {
+                kind: Some("HStack".into()),
+                classes: vec!["toolbar".into(), "dark".into()],
+                name: None,
+            }
+        );
+        assert_eq!(rules[1].selector().name.as_deref(), Some("MainHeader"));
+        assert_eq!(rules[0].declarations(), rules[1].declarations());
+        assert_eq!(
+            rules[0].declarations(),
+            [
+                Declaration::Padding(UiRect::axes(Val::Px(16.0), Val::Px(4.0))),
+                Declaration::BackgroundColor(Srgba::hex("1a1a33").unwrap().into()),
+                Declaration::BorderRadius(BorderRadius::new(
+                    Val::Px(8.0),
+                    Val::Percent(50.0),
+                    Val::Px(8.0),
+                    Val::Percent(50.0),
+                )),
+            ]
+        );
+        assert_eq!(
+            rules[2].declarations(),
+            [
+                Declaration::FontSize(18.0),
+                Declaration::TextColor(Color::srgb_u8(255, 0, 0)),
+                Declaration::Width(Val::Auto),
+            ]
+        );
+    }
+
+    #[test]
+    fn specificity_orders_names_classes_then_types() {
+        let rules = parse("HStack {} .a.b {} #Name {}").unwrap();
+        let [kind, classes, name] = [0, 1, 2].map(|i| rules[i].specificity());
+        assert!(kind < classes && classes < name);
+    }
+
+    #[test]
+    fn empty_source_has_no_rules() {
+        assert_eq!(parse("  /* nothing */\n").unwrap(), []);
+    }
+
+    #[test]
+    fn reports_unknown_property_position() {
+        let err = error("HStack {\n    padding: 4px;\n    colour: red;\n}");
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(err.message, "unknown property `colour`");
+    }
+
+    #[test]
+    fn reports_invalid_value_position() {
+        let err = error("#Header { width: wide; }");
+        assert_eq!((err.line, err.column), (1, 18));
+        assert!(err.message.starts_with("invalid value `wide` for `width`"));
+    }
+
+    #[test]
+    fn reports_missing_brace_at_end_of_file() {
+        let err = error("VStack {\n  margin: 0");
+        assert_eq!((err.line, err.column), (2, 12));
+        assert_eq!(err.message, "expected `}`, found end of file");
+    }
+
+    #[test]
+    fn reports_unterminated_comment_at_its_start() {
+        let err = error("HStack {}\n  /* open");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "unterminated comment");
+    }
+
+    #[test]
+    fn reports_missing_selector() {
+        let err = error("HStack, { }");
+        assert_eq!((err.line, err.column), (1, 9));
+        assert_eq!(err.message, "expected selector, found `{`");
+    }
+
+    #[test]
+    fn rejects_out_of_range_color_channels() {
+        let err = error("HStack {\n  background-color: rgb(300, -5, 12);\n}");
+        assert_eq!((err.line, err.column), (2, 21));
+        assert!(
+            err.message
+                .ends_with("color channels must be between 0 and 255")
+        );
+
+        let err = error("HStack { text-color: rgba(0, 0, 0, 2) }");
+        assert!(err.message.ends_with("alpha must be between 0 and 1"));
+    }
+
+    #[test]
+    fn rejects_wrong_color_channel_count() {
+        let err = error("HStack { text-color: rgb(1, 2, 3, 0.5) }");
+        assert_eq!((err.line, err.column), (1, 22));
+        assert!(err.message.ends_with("`rgb` expects three channels"));
+
+        let err = error("HStack { text-color: rgba(1, 2, 3) }");
+        assert!(err.message.ends_with("`rgba` expects four channels"));
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let err = error("/* ünïcödé */ HStack { top: x }");
+        assert_eq!((err.line, err.column), (1, 29));
+    }
+}
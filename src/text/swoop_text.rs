@@ -4,10 +4,14 @@ use bevy_math::prelude::*;
 use bevy_ui::prelude::*;
 
 use crate::View;
+use crate::animation::AnimationView;
 use crate::background::BackgroundStyle;
+use crate::binding::BindingView;
 use crate::border::{BorderStyle, BorderView};
+use crate::modifier::{ModifierView, ViewModifiers};
 use crate::prelude::{BackgroundView, PositionView};
 use crate::shadow::{BoxShadowView, TextShadowView};
+use crate::style::{ClassView, ViewClass};
 
 use super::{TextStyle, TextView};
 
@@ -26,6 +30,12 @@ pub struct SwoopText {
     /// Layout and sizing node (e.g., width, height, margin).
     node: Node,
 
+    /// Type name and classes matched by stylesheets.
+    class: ViewClass,
+
+    /// Deferred changes recorded by modifiers.
+    modifiers: ViewModifiers,
+
     /// Border styling (width, color, radius, etc.).
     border: BorderStyle,
 
@@ -50,6 +60,8 @@ impl Default for SwoopText {
             node: Node {
                 ..Default::default()
             },
+            class: ViewClass::new("SwoopText"),
+            modifiers: ViewModifiers::default(),
             border: BorderStyle::default(),
             background: BackgroundStyle::default(),
             box_shadow: BoxShadow::default(),
@@ -72,6 +84,18 @@ impl View for SwoopText {
     }
 }
 
+impl ModifierView for SwoopText {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for SwoopText {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
 impl BorderView for SwoopText {
     fn border_node(&mut self) -> &mut BorderStyle {
         &mut self.border
@@ -103,3 +127,7 @@ impl TextShadowView for SwoopText {
 }
 
 impl PositionView for SwoopText {}
+
+impl AnimationView for SwoopText {}
+
+impl BindingView for SwoopText {}
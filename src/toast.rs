@@ -0,0 +1,411 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::spawn::SpawnWith;
+use bevy_text::prelude::*;
+use bevy_time::prelude::*;
+use bevy_ui::prelude::*;
+
+use crate::animation::AnimationView;
+use crate::animation::transition::{Transition, TransitionExt};
+use crate::background::BackgroundView;
+use crate::border::BorderView;
+use crate::button::text_button::TextButton;
+use crate::container::stack::StackView;
+use crate::container::stack::h_stack::HStack;
+use crate::container::stack::v_stack::VStack;
+use crate::shadow::BoxShadowView;
+use crate::text::TextView;
+use crate::text::swoop_text::SText;
+use crate::{View, ViewToBundle};
+
+pub mod prelude {
+    pub use super::{Toast, ToastAction, ToastAnchor, ToastExt, ToastLevel, ToastPlugin, Toasts};
+}
+
+/// Runs when an action button of a toast is pressed, before the toast is dismissed.
+pub type ToastAction = Arc<dyn Fn(&mut Commands) + Send + Sync>;
+
+/// Toasts are drawn above the rest of the UI, but below open menus.
+const TOAST_Z_INDEX: i32 = 900;
+
+/// How important a [`Toast`] is, shown by the color of its leading edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastLevel {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    fn color(self) -> Srgba {
+        match self {
+            ToastLevel::Info => Srgba::rgb(0.25, 0.55, 1.0),
+            ToastLevel::Success => Srgba::rgb(0.2, 0.7, 0.35),
+            ToastLevel::Warning => Srgba::rgb(0.95, 0.65, 0.1),
+            ToastLevel::Error => Srgba::rgb(0.9, 0.25, 0.25),
+        }
+    }
+}
+
+/// A short notification shown by [`ToastExt::toast`].
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # fn retry_upload(_: &mut Commands) {}
+/// # fn notify(mut commands: Commands) {
+/// commands.toast(Toast::info("Saved"));
+/// commands.toast(Toast::error("Upload failed").persistent().action("Retry", retry_upload));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Toast {
+    message: String,
+    level: ToastLevel,
+    /// Seconds before the toast is dismissed, `None` to use [`Toasts::duration`]
+    duration: Option<f32>,
+    persistent: bool,
+    actions: Vec<(String, ToastAction)>,
+}
+
+impl fmt::Debug for Toast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Toast")
+            .field("message", &self.message)
+            .field("level", &self.level)
+            .field("duration", &self.duration)
+            .field("persistent", &self.persistent)
+            .field("actions", &self.actions.len())
+            .finish()
+    }
+}
+
+impl Toast {
+    /// Creates a toast showing `message`.
+    pub fn new(level: ToastLevel, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            level,
+            duration: None,
+            persistent: false,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Creates an informational toast.
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Info, message)
+    }
+
+    /// Creates a toast reporting that something succeeded.
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Success, message)
+    }
+
+    /// Creates a toast warning about something.
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Warning, message)
+    }
+
+    /// Creates a toast reporting an error.
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Error, message)
+    }
+
+    /// Dismisses the toast after `seconds` instead of [`Toasts::duration`].
+    pub fn duration(mut self, seconds: f32) -> Self {
+        self.duration = Some(seconds);
+        self
+    }
+
+    /// Keeps the toast until it is closed or one of its actions is pressed.
+    pub fn persistent(mut self) -> Self {
+        self.persistent = true;
+        self
+    }
+
+    /// Adds a button running `action`, then dismissing the toast.
+    ///
+    /// # Arguments
+    /// * `label` - The text of the button.
+    /// * `action` - The function to run, e.g. `|commands| commands.send_event(Undo)`.
+    pub fn action(
+        mut self,
+        label: impl Into<String>,
+        action: impl Fn(&mut Commands) + Send + Sync + 'static,
+    ) -> Self {
+        self.actions.push((label.into(), Arc::new(action)));
+        self
+    }
+}
+
+/// The window corner or edge toasts are stacked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastAnchor {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    #[default]
+    BottomRight,
+}
+
+/// Settings and queue of the toasts shown by the [`ToastPlugin`].
+///
+/// Toasts beyond `max_visible` wait in the queue until a visible one is dismissed.
+#[derive(Resource, Debug)]
+pub struct Toasts {
+    /// Where toasts are stacked, the oldest one closest to the anchor
+    pub anchor: ToastAnchor,
+    /// The maximum number of toasts shown at once
+    pub max_visible: usize,
+    /// Seconds before toasts without their own duration are dismissed
+    pub duration: f32,
+    /// Distance from the window edges
+    pub margin: Val,
+    pending: VecDeque<Toast>,
+    visible: Vec<Entity>,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self {
+            anchor: ToastAnchor::default(),
+            max_visible: 3,
+            duration: 4.0,
+            margin: Val::Px(16.0),
+            pending: VecDeque::new(),
+            visible: Vec::new(),
+        }
+    }
+}
+
+impl Toasts {
+    /// Queues `toast`, showing it as soon as there is room.
+    pub fn push(&mut self, toast: Toast) {
+        self.pending.push_back(toast);
+    }
+
+    /// Returns the number of toasts on screen.
+    pub fn visible_count(&self) -> usize {
+        self.visible.len()
+    }
+
+    /// Returns the number of toasts waiting to be shown.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+/// Showing toasts through [`Commands`].
+pub trait ToastExt {
+    /// Queues `toast` in the [`Toasts`] resource.
+    fn toast(&mut self, toast: Toast);
+}
+
+impl ToastExt for Commands<'_, '_> {
+    fn toast(&mut self, toast: Toast) {
+        self.queue(move |world: &mut World| world.resource_mut::<Toasts>().push(toast));
+    }
+}
+
+/// The root node toasts are stacked in.
+#[derive(Component)]
+struct ToastContainer;
+
+/// A toast on screen.
+#[derive(Component)]
+struct ToastEntry {
+    /// Seconds left before dismissal, `None` for persistent toasts
+    remaining: Option<f32>,
+}
+
+/// A button of a toast, closing it after running its action, if any.
+#[derive(Component)]
+struct ToastButton(Option<ToastAction>);
+
+/// Positions the toast container against `anchor`.
+fn apply_anchor(node: &mut Node, anchor: ToastAnchor, margin: Val) {
+    let (top, bottom) = match anchor {
+        ToastAnchor::TopLeft | ToastAnchor::TopCenter | ToastAnchor::TopRight => {
+            (margin, Val::Auto)
+        }
+        _ => (Val::Auto, margin),
+    };
+    let (left, right, align) = match anchor {
+        ToastAnchor::TopLeft | ToastAnchor::BottomLeft => (margin, Val::Auto, AlignItems::Start),
+        ToastAnchor::TopCenter | ToastAnchor::BottomCenter => {
+            (Val::Px(0.0), Val::Px(0.0), AlignItems::Center)
+        }
+        ToastAnchor::TopRight | ToastAnchor::BottomRight => (Val::Auto, margin, AlignItems::End),
+    };
+    node.position_type = PositionType::Absolute;
+    node.top = top;
+    node.bottom = bottom;
+    node.left = left;
+    node.right = right;
+    node.align_items = align;
+    node.flex_direction = if bottom == Val::Auto {
+        FlexDirection::Column
+    } else {
+        FlexDirection::ColumnReverse
+    };
+}
+
+fn toast_card(toast: Toast, default_duration: f32) -> impl Bundle {
+    let card = HStack::new()
+        .align_items(AlignItems::Center)
+        .column_gap(Val::Px(12.0))
+        .padding(UiRect::axes(Val::Px(14.0), Val::Px(10.0)))
+        .min_width(Val::Px(240.0))
+        .max_width(Val::Px(420.0))
+        .background_color(Srgba::gray(0.15))
+        .border(UiRect::left(Val::Px(4.0)))
+        .border_color(toast.level.color())
+        .border_radius(BorderRadius::all(Val::Px(6.0)))
+        .add_shadow(ShadowStyle {
+            color: Srgba::new(0.0, 0.0, 0.0, 0.3).into(),
+            x_offset: Val::Px(0.0),
+            y_offset: Val::Px(4.0),
+            spread_radius: Val::Px(0.0),
+            blur_radius: Val::Px(12.0),
+        })
+        .transition(Transition::fade());
+    let remaining = (!toast.persistent).then(|| toast.duration.unwrap_or(default_duration));
+    let Toast {
+        message, actions, ..
+    } = toast;
+    (
+        card,
+        Children::spawn(SpawnWith(move |card: &mut ChildSpawner| {
+            card.spawn(
+                SText::new()
+                    .text(message)
+                    .text_color(Color::WHITE)
+                    .text_alignment(JustifyText::Left)
+                    .text_linebreak(LineBreak::WordBoundary)
+                    .flex_grow(1.0)
+                    .flex_shrink(1.0),
+            );
+            for (label, action) in actions {
+                card.spawn((toast_button(label), ToastButton(Some(action))));
+            }
+            card.spawn((toast_button("✕".into()), ToastButton(None)));
+        })),
+        ToastEntry { remaining },
+        Interaction::default(),
+    )
+}
+
+fn toast_button(label: String) -> impl Bundle {
+    TextButton::new()
+        .text(label)
+        .text_color(Color::WHITE)
+        .background_color(Srgba::gray(0.25))
+        .border_radius(BorderRadius::all(Val::Px(4.0)))
+        .padding(UiRect::axes(Val::Px(8.0), Val::Px(4.0)))
+        .pack()
+}
+
+/// Notifications stacked in a corner of the window, see [`Toasts`].
+///
+/// Requires the `TimePlugin`. Toasts fade in and out when the `AnimationPlugin` is added.
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Toasts>().add_systems(
+            Update,
+            (press_toast_buttons, expire_toasts, show_toasts).chain(),
+        );
+    }
+}
+
+/// Spawns queued toasts while there is room and keeps the container anchored.
+fn show_toasts(
+    mut commands: Commands,
+    mut toasts: ResMut<Toasts>,
+    mut containers: Query<(Entity, &mut Node), With<ToastContainer>>,
+    entries: Query<(), With<ToastEntry>>,
+) {
+    if toasts
+        .visible
+        .iter()
+        .any(|entity| !entries.contains(*entity))
+    {
+        toasts.visible.retain(|entity| entries.contains(*entity));
+    }
+    let container = match containers.single_mut() {
+        Ok((container, mut node)) => {
+            if toasts.is_changed() {
+                apply_anchor(&mut node, toasts.anchor, toasts.margin);
+            }
+            container
+        }
+        Err(_) if toasts.pending.is_empty() => return,
+        Err(_) => {
+            let mut stack = VStack::new().row_gap(Val::Px(8.0));
+            apply_anchor(stack.node_node(), toasts.anchor, toasts.margin);
+            commands
+                .spawn((
+                    stack.name("Toasts"),
+                    ToastContainer,
+                    GlobalZIndex(TOAST_Z_INDEX),
+                ))
+                .id()
+        }
+    };
+
+    while toasts.visible.len() < toasts.max_visible
+        && let Some(toast) = toasts.pending.pop_front()
+    {
+        let card = toast_card(toast, toasts.duration);
+        let entity = commands.spawn((card, ChildOf(container))).id();
+        toasts.visible.push(entity);
+    }
+}
+
+/// Counts down visible toasts, pausing while the pointer is over them.
+fn expire_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut entries: Query<(Entity, &mut ToastEntry, &Interaction)>,
+) {
+    for (entity, mut entry, interaction) in &mut entries {
+        let Some(remaining) = entry.remaining.as_mut() else {
+            continue;
+        };
+        if *interaction != Interaction::None {
+            continue;
+        }
+        *remaining -= time.delta_secs();
+        if *remaining <= 0.0 {
+            entry.remaining = None;
+            commands.entity(entity).despawn_with_transition();
+        }
+    }
+}
+
+fn press_toast_buttons(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &ToastButton, &ChildOf), Changed<Interaction>>,
+) {
+    for (interaction, button, toast) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(action) = &button.0 {
+            action(&mut commands);
+        }
+        commands.entity(toast.parent()).despawn_with_transition();
+    }
+}
@@ -0,0 +1,616 @@
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_math::prelude::*;
+use bevy_text::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, FocusPolicy, RelativeCursorPosition};
+use bevy_window::{PrimaryWindow, Window};
+
+use crate::animation::AnimationView;
+use crate::animation::transition::TransitionExt;
+use crate::background::{BackgroundStyle, BackgroundView};
+use crate::binding::BindingView;
+use crate::border::{BorderStyle, BorderView};
+use crate::button::text_button::TextButton;
+use crate::container::stack::StackView;
+use crate::container::stack::h_stack::HStack;
+use crate::focus::FocusScopeView;
+use crate::modifier::{ModifierView, ViewModifiers};
+use crate::position::PositionView;
+use crate::shadow::BoxShadowView;
+use crate::style::{ClassView, ViewClass};
+use crate::text::TextView;
+use crate::text::swoop_text::SText;
+use crate::{View, ViewToBundle};
+
+pub mod prelude {
+    pub use super::{
+        Minimized, WindowPanel, WindowPanelOptions, WindowPanelPlugin, WindowPanelView,
+    };
+}
+
+/// Thickness of the edges a panel is resized from, in logical pixels.
+const EDGE_THICKNESS: f32 = 6.0;
+/// Size of the corners a panel is resized from, in logical pixels.
+const CORNER_SIZE: f32 = 12.0;
+
+/// Title and window controls of a [`WindowPanel`].
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct WindowPanelOptions {
+    /// Text shown in the title bar
+    pub title: String,
+    /// Whether the title bar has a close button
+    pub closable: bool,
+    /// Whether the title bar has a minimize button
+    pub minimizable: bool,
+    /// Whether the panel can be resized from its edges and corners
+    pub resizable: bool,
+    /// The smallest size the panel can be resized to, in logical pixels
+    pub min_size: Vec2,
+}
+
+impl Default for WindowPanelOptions {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            closable: true,
+            minimizable: false,
+            resizable: true,
+            min_size: Vec2::new(120.0, 60.0),
+        }
+    }
+}
+
+/// Marks a [`WindowPanel`] collapsed to its title bar.
+///
+/// Remembers the size constraints to restore once the panel is expanded again.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Minimized {
+    height: Val,
+    max_height: Val,
+}
+
+/// The title bar a panel is dragged by.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+struct PanelTitleBar {
+    panel: Entity,
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum PanelButton {
+    Minimize(Entity),
+    Close(Entity),
+}
+
+/// An edge or corner a panel is resized from.
+///
+/// `x` and `y` are `-1` for the left or top side, `1` for the right or bottom side
+/// and `0` when the handle does not resize along that axis.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+struct ResizeHandle {
+    panel: Entity,
+    x: i8,
+    y: i8,
+}
+
+/// A trait for configuring the title bar and controls of a [`WindowPanel`].
+pub trait WindowPanelView: View {
+    /// Returns a mutable reference to the options of this panel.
+    fn window_panel_node(&mut self) -> &mut WindowPanelOptions;
+
+    /// Sets the text shown in the title bar.
+    fn title(mut self, title: impl Into<String>) -> Self {
+        self.window_panel_node().title = title.into();
+        self
+    }
+
+    /// Sets whether the title bar has a close button.
+    fn closable(mut self, closable: bool) -> Self {
+        self.window_panel_node().closable = closable;
+        self
+    }
+
+    /// Sets whether the title bar has a minimize button.
+    fn minimizable(mut self, minimizable: bool) -> Self {
+        self.window_panel_node().minimizable = minimizable;
+        self
+    }
+
+    /// Sets whether the panel can be resized from its edges and corners.
+    fn resizable(mut self, resizable: bool) -> Self {
+        self.window_panel_node().resizable = resizable;
+        self
+    }
+
+    /// Sets the smallest size the panel can be resized to, in logical pixels.
+    fn min_size(mut self, width: f32, height: f32) -> Self {
+        self.window_panel_node().min_size = Vec2::new(width, height);
+        self
+    }
+}
+
+/// A floating panel with a title bar, like a desktop window.
+///
+/// Drag the title bar to move it and its edges or corners to resize it. Pressing
+/// anywhere on a panel brings it in front of its sibling panels. The panel is
+/// absolutely positioned, so its place is set with [`PositionView`] and updated
+/// in its `Node` while it is dragged. Its children are laid out below the title bar.
+///
+/// # Example
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use swoop_ui::prelude::*;
+/// # fn inspector_rows() -> VStack {
+/// #     VStack::new()
+/// # }
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     WindowPanel::new()
+///         .title("Inspector")
+///         .minimizable(true)
+///         .position(Val::Px(24.0), Val::Px(80.0))
+///         .frame(Val::Px(280.0), Val::Px(360.0)),
+///     children![inspector_rows()],
+/// ));
+/// # }
+/// ```
+#[derive(Bundle, Debug, Clone)]
+pub struct WindowPanel {
+    /// Named "WindowPanel" in the hierarchy
+    name: Name,
+    /// Absolutely positioned 320x240 column, moved and resized by dragging
+    node: Node,
+    /// Matched by `WindowPanel` selectors and any added classes
+    class: ViewClass,
+    /// Changes applied once the panel is spawned
+    modifiers: ViewModifiers,
+    /// One pixel panel outline with rounded corners
+    border: BorderStyle,
+    /// White fill of the panel
+    background: BackgroundStyle,
+    /// Soft shadow below the panel
+    shadow: BoxShadow,
+    /// Title and window controls
+    options: WindowPanelOptions,
+    /// Order among sibling panels, raised when the panel is pressed
+    z_index: ZIndex,
+    /// Detects presses anywhere on the panel, even on its children
+    cursor: RelativeCursorPosition,
+}
+
+impl Default for WindowPanel {
+    fn default() -> Self {
+        Self {
+            name: Name::new("WindowPanel"),
+            node: Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                position_type: PositionType::Absolute,
+                left: Val::Px(40.0),
+                top: Val::Px(40.0),
+                width: Val::Px(320.0),
+                height: Val::Px(240.0),
+                border: UiRect::all(Val::Px(1.0)),
+                overflow: Overflow::clip(),
+                ..Default::default()
+            },
+            class: ViewClass::new("WindowPanel"),
+            modifiers: ViewModifiers::default(),
+            border: BorderStyle::panel(),
+            background: BackgroundStyle::solid(Color::WHITE),
+            shadow: BoxShadow(vec![ShadowStyle {
+                color: Srgba::new(0.0, 0.0, 0.0, 0.25).into(),
+                x_offset: Val::Px(0.0),
+                y_offset: Val::Px(4.0),
+                spread_radius: Val::Px(0.0),
+                blur_radius: Val::Px(16.0),
+            }]),
+            options: WindowPanelOptions::default(),
+            z_index: ZIndex::default(),
+            cursor: RelativeCursorPosition::default(),
+        }
+    }
+}
+
+impl View for WindowPanel {
+    fn name_node(&mut self) -> &mut Name {
+        &mut self.name
+    }
+
+    fn node_node(&mut self) -> &mut Node {
+        &mut self.node
+    }
+}
+
+impl ModifierView for WindowPanel {
+    fn modifiers_node(&mut self) -> &mut ViewModifiers {
+        &mut self.modifiers
+    }
+}
+
+impl ClassView for WindowPanel {
+    fn class_node(&mut self) -> &mut ViewClass {
+        &mut self.class
+    }
+}
+
+impl WindowPanelView for WindowPanel {
+    fn window_panel_node(&mut self) -> &mut WindowPanelOptions {
+        &mut self.options
+    }
+}
+
+impl BackgroundView for WindowPanel {
+    fn background_node(&mut self) -> &mut BackgroundStyle {
+        &mut self.background
+    }
+}
+
+impl BorderView for WindowPanel {
+    fn border_node(&mut self) -> &mut BorderStyle {
+        &mut self.border
+    }
+}
+
+impl BoxShadowView for WindowPanel {
+    fn box_shadow_node(&mut self) -> &mut BoxShadow {
+        &mut self.shadow
+    }
+}
+
+impl PositionView for WindowPanel {}
+
+impl AnimationView for WindowPanel {}
+
+impl FocusScopeView for WindowPanel {}
+
+impl BindingView for WindowPanel {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PanelDragMode {
+    Move,
+    Resize { x: i8, y: i8 },
+}
+
+/// The panel being moved or resized.
+#[derive(Resource, Default)]
+struct PanelDrag {
+    panel: Option<Entity>,
+    mode: Option<PanelDragMode>,
+    /// Cursor position when the drag started
+    start_cursor: Vec2,
+    /// Bounds of the panel when the drag started, relative to its parent
+    start_rect: Rect,
+}
+
+/// Moving, resizing, stacking and closing [`WindowPanel`]s.
+///
+/// Requires Bevy's `InputPlugin`. Closed panels fade out when the `AnimationPlugin` is added
+/// and they have a transition.
+pub struct WindowPanelPlugin;
+
+impl Plugin for WindowPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PanelDrag>().add_systems(
+            Update,
+            (
+                build_window_panels,
+                bring_panels_to_front,
+                press_panel_buttons,
+                start_panel_drags,
+                drag_panels,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Returns the bounds of a node, in logical pixels.
+fn logical_rect(node: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    let scale = node.inverse_scale_factor();
+    Rect::from_center_size(
+        transform.translation().truncate() * scale,
+        node.size() * scale,
+    )
+}
+
+/// Spawns the title bar and resize handles of new panels.
+fn build_window_panels(
+    mut commands: Commands,
+    panels: Query<(Entity, &WindowPanelOptions), Added<WindowPanelOptions>>,
+) {
+    for (panel, options) in &panels {
+        let title_bar = commands
+            .spawn((
+                HStack::from_name("TitleBar")
+                    .align_items(AlignItems::Center)
+                    .column_gap(Val::Px(4.0))
+                    .padding(UiRect::axes(Val::Px(8.0), Val::Px(4.0)))
+                    .flex_shrink(0.0)
+                    .background_color(Srgba::gray(0.9)),
+                Interaction::default(),
+                PanelTitleBar { panel },
+            ))
+            .with_children(|bar| {
+                bar.spawn(
+                    SText::new()
+                        .text(options.title.clone())
+                        .text_alignment(JustifyText::Left)
+                        .flex_grow(1.0),
+                );
+                if options.minimizable {
+                    bar.spawn((panel_button("–"), PanelButton::Minimize(panel)));
+                }
+                if options.closable {
+                    bar.spawn((panel_button("✕"), PanelButton::Close(panel)));
+                }
+            })
+            .id();
+        commands.entity(panel).insert_children(0, &[title_bar]);
+
+        if !options.resizable {
+            continue;
+        }
+        commands.entity(panel).with_children(|panel_node| {
+            for y in -1..=1 {
+                for x in -1..=1 {
+                    if x != 0 || y != 0 {
+                        panel_node.spawn(resize_handle(panel, x, y));
+                    }
+                }
+            }
+        });
+    }
+}
+
+fn panel_button(label: &str) -> impl Bundle {
+    TextButton::new()
+        .text(label)
+        .background_color(Color::NONE)
+        .border_radius(BorderRadius::all(Val::Px(4.0)))
+        .padding(UiRect::axes(Val::Px(6.0), Val::Px(0.0)))
+        .pack()
+}
+
+/// Builds the invisible handle resizing `panel` from the edge or corner at `x` and `y`.
+fn resize_handle(panel: Entity, x: i8, y: i8) -> impl Bundle {
+    let mut node = Node {
+        position_type: PositionType::Absolute,
+        ..Default::default()
+    };
+    // Corners are larger than edges, and edges span the side between the corners
+    let (width, height) = match (x, y) {
+        (0, _) => (Val::Auto, Val::Px(EDGE_THICKNESS)),
+        (_, 0) => (Val::Px(EDGE_THICKNESS), Val::Auto),
+        _ => (Val::Px(CORNER_SIZE), Val::Px(CORNER_SIZE)),
+    };
+    node.width = width;
+    node.height = height;
+    let span = Val::Px(CORNER_SIZE);
+    match x {
+        -1 => node.left = Val::Px(0.0),
+        1 => node.right = Val::Px(0.0),
+        _ => (node.left, node.right) = (span, span),
+    }
+    match y {
+        -1 => node.top = Val::Px(0.0),
+        1 => node.bottom = Val::Px(0.0),
+        _ => (node.top, node.bottom) = (span, span),
+    }
+    (
+        Name::new("ResizeHandle"),
+        node,
+        ZIndex(1),
+        Interaction::default(),
+        FocusPolicy::Block,
+        ResizeHandle { panel, x, y },
+    )
+}
+
+type PanelStacking = (
+    Entity,
+    &'static RelativeCursorPosition,
+    &'static ComputedNode,
+    &'static mut ZIndex,
+    Option<&'static ChildOf>,
+);
+
+/// Raises the topmost panel under the cursor above its sibling panels when pressed.
+fn bring_panels_to_front(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut panels: Query<PanelStacking, With<WindowPanelOptions>>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Some((pressed, parent)) = panels
+        .iter()
+        .filter(|(_, cursor, ..)| cursor.mouse_over())
+        .max_by_key(|(_, _, node, ..)| node.stack_index())
+        .map(|(panel, _, _, _, parent)| (panel, parent.map(ChildOf::parent)))
+    else {
+        return;
+    };
+    let (highest, shared) = panels
+        .iter()
+        .filter(|(panel, .., sibling)| *panel != pressed && sibling.map(ChildOf::parent) == parent)
+        .fold((i32::MIN, false), |(highest, _), (.., z_index, _)| {
+            (highest.max(z_index.0), true)
+        });
+    if let Ok((.., mut z_index, _)) = panels.get_mut(pressed)
+        && shared
+        && z_index.0 <= highest
+    {
+        z_index.0 = highest + 1;
+    }
+}
+
+/// Closes or minimizes panels whose title bar buttons were pressed.
+fn press_panel_buttons(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &PanelButton), Changed<Interaction>>,
+    mut panels: Query<(&mut Node, Option<&Minimized>)>,
+    title_bars: Query<(&PanelTitleBar, &ComputedNode)>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let panel = match *button {
+            PanelButton::Close(panel) => {
+                commands.entity(panel).despawn_with_transition();
+                continue;
+            }
+            PanelButton::Minimize(panel) => panel,
+        };
+        let Ok((mut node, minimized)) = panels.get_mut(panel) else {
+            continue;
+        };
+        if let Some(minimized) = minimized {
+            node.height = minimized.height;
+            node.max_height = minimized.max_height;
+            commands.entity(panel).remove::<Minimized>();
+            continue;
+        }
+        let title_height = title_bars
+            .iter()
+            .find(|(title_bar, _)| title_bar.panel == panel)
+            .map(|(_, computed)| computed.size().y * computed.inverse_scale_factor())
+            .unwrap_or_default();
+        commands.entity(panel).insert(Minimized {
+            height: node.height,
+            max_height: node.max_height,
+        });
+        node.height = Val::Auto;
+        node.max_height = Val::Px(title_height);
+    }
+}
+
+/// Starts moving a panel by its title bar or resizing it by one of its handles.
+fn start_panel_drags(
+    mut drag: ResMut<PanelDrag>,
+    (mouse, windows): (
+        Res<ButtonInput<MouseButton>>,
+        Query<&Window, With<PrimaryWindow>>,
+    ),
+    title_bars: Query<(&Interaction, &PanelTitleBar)>,
+    handles: Query<(&Interaction, &ResizeHandle)>,
+    panels: Query<(
+        &ComputedNode,
+        &GlobalTransform,
+        Option<&ChildOf>,
+        Has<Minimized>,
+    )>,
+    parents: Query<(&ComputedNode, &GlobalTransform)>,
+) {
+    if !mouse.pressed(MouseButton::Left) {
+        *drag = PanelDrag::default();
+        return;
+    }
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let pressed = |interaction: &Interaction| *interaction == Interaction::Pressed;
+    let target = handles
+        .iter()
+        .find(|(interaction, _)| pressed(interaction))
+        .map(|(_, handle)| {
+            let mode = PanelDragMode::Resize {
+                x: handle.x,
+                y: handle.y,
+            };
+            (handle.panel, mode)
+        })
+        .or_else(|| {
+            title_bars
+                .iter()
+                .find(|(interaction, _)| pressed(interaction))
+                .map(|(_, title_bar)| (title_bar.panel, PanelDragMode::Move))
+        });
+    let Some((panel, mode)) = target else {
+        return;
+    };
+    let Some(cursor) = windows.single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+    let Ok((node, transform, parent, minimized)) = panels.get(panel) else {
+        return;
+    };
+    if minimized && mode != PanelDragMode::Move {
+        return;
+    }
+
+    // Offsets of an absolute node are relative to the padding box of its parent
+    let origin = parent
+        .and_then(|parent| parents.get(parent.parent()).ok())
+        .map(|(parent, transform)| {
+            let border = parent.border();
+            logical_rect(parent, transform).min
+                + Vec2::new(border.left, border.top) * parent.inverse_scale_factor()
+        })
+        .unwrap_or_default();
+    let rect = logical_rect(node, transform);
+    *drag = PanelDrag {
+        panel: Some(panel),
+        mode: Some(mode),
+        start_cursor: cursor,
+        start_rect: Rect::from_corners(rect.min - origin, rect.max - origin),
+    };
+}
+
+/// Moves or resizes the dragged panel to follow the cursor.
+fn drag_panels(
+    drag: Res<PanelDrag>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut panels: Query<(&mut Node, &WindowPanelOptions)>,
+) {
+    let (Some(panel), Some(mode)) = (drag.panel, drag.mode) else {
+        return;
+    };
+    let Some(cursor) = windows.single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+    let Ok((mut node, options)) = panels.get_mut(panel) else {
+        return;
+    };
+    let delta = cursor - drag.start_cursor;
+    let start = drag.start_rect;
+    let (mut min, mut max) = (start.min, start.max);
+    match mode {
+        PanelDragMode::Move => {
+            min += delta;
+            max += delta;
+        }
+        PanelDragMode::Resize { x, y } => {
+            // The opposite edge stays in place and the size never drops below the minimum
+            let size = options.min_size;
+            match x {
+                -1 => min.x = (min.x + delta.x).min(max.x - size.x),
+                1 => max.x = (max.x + delta.x).max(min.x + size.x),
+                _ => {}
+            }
+            match y {
+                -1 => min.y = (min.y + delta.y).min(max.y - size.y),
+                1 => max.y = (max.y + delta.y).max(min.y + size.y),
+                _ => {}
+            }
+        }
+    }
+
+    let (left, top) = (Val::Px(min.x), Val::Px(min.y));
+    if node.left != left || node.top != top {
+        node.left = left;
+        node.top = top;
+    }
+    if let PanelDragMode::Resize { .. } = mode {
+        let (width, height) = (Val::Px(max.x - min.x), Val::Px(max.y - min.y));
+        if node.width != width || node.height != height {
+            node.width = width;
+            node.height = height;
+        }
+    }
+}
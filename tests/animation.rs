@@ -0,0 +1,183 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::TimeUpdateStrategy;
+use bevy_ui::prelude::*;
+use swoop_ui::prelude::*;
+
+const STEP: f32 = 0.1;
+
+/// An app whose clock advances by `STEP` seconds every frame.
+fn app() -> bevy_app::App {
+    let mut app = common::app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        STEP,
+    )));
+    app
+}
+
+fn width(app: &bevy_app::App, entity: Entity) -> f32 {
+    match app.world().get::<Node>(entity).unwrap().width {
+        Val::Px(width) => width,
+        other => panic!("unexpected width {other:?}"),
+    }
+}
+
+fn set_width(app: &mut bevy_app::App, entity: Entity, width: f32) {
+    app.world_mut().get_mut::<Node>(entity).unwrap().width = Val::Px(width);
+}
+
+fn assert_near(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn changes_tween_from_the_displayed_value() {
+    let mut app = app();
+    let view = app
+        .world_mut()
+        .spawn(
+            VStack::new()
+                .width(Val::Px(100.0))
+                .animation(Animation::linear(1.0)),
+        )
+        .id();
+    app.update();
+    assert_near(width(&app, view), 100.0);
+
+    set_width(&mut app, view, 200.0);
+    for expected in [110.0, 120.0, 130.0] {
+        app.update();
+        assert_near(width(&app, view), expected);
+    }
+
+    // Retargeting mid-flight starts from what is on screen
+    set_width(&mut app, view, 30.0);
+    app.update();
+    assert_near(width(&app, view), 130.0 - 10.0);
+
+    for _ in 0..12 {
+        app.update();
+    }
+    assert_near(width(&app, view), 30.0);
+}
+
+#[test]
+fn zero_duration_snaps() {
+    let mut app = app();
+    let view = app
+        .world_mut()
+        .spawn(
+            VStack::new()
+                .width(Val::Px(100.0))
+                .animation(Animation::linear(0.0)),
+        )
+        .id();
+    app.update();
+    set_width(&mut app, view, 200.0);
+    app.update();
+    assert_near(width(&app, view), 200.0);
+}
+
+#[test]
+fn eased_colors_settle_on_the_target() {
+    let mut app = app();
+    let view = app
+        .world_mut()
+        .spawn(
+            VStack::new()
+                .background_color(Color::BLACK)
+                .animation(Animation::ease_in_out(0.5)),
+        )
+        .id();
+    app.update();
+    app.world_mut().get_mut::<BackgroundColor>(view).unwrap().0 = Color::WHITE;
+
+    let mut previous = 0.0;
+    for _ in 0..5 {
+        app.update();
+        let red = app
+            .world()
+            .get::<BackgroundColor>(view)
+            .unwrap()
+            .0
+            .to_srgba()
+            .red;
+        assert!(red > previous, "{red} should grow past {previous}");
+        previous = red;
+    }
+    assert_eq!(
+        app.world().get::<BackgroundColor>(view).unwrap().0,
+        Color::WHITE
+    );
+}
+
+#[test]
+fn explicit_tweens_take_over() {
+    let mut app = app();
+    let view = app
+        .world_mut()
+        .spawn(
+            VStack::new()
+                .width(Val::Px(100.0))
+                .animation(Animation::linear(10.0)),
+        )
+        .id();
+    app.update();
+    app.world_mut()
+        .commands()
+        .entity(view)
+        .animate(UiProp::Width, Val::Px(200.0), 0.2, Ease::Linear)
+        .unwrap();
+    for expected in [150.0, 200.0] {
+        app.update();
+        assert_near(width(&app, view), expected);
+    }
+
+    // The value the tween left is not animated again
+    app.update();
+    assert_near(width(&app, view), 200.0);
+
+    // Later changes animate from where the tween left off
+    set_width(&mut app, view, 100.0);
+    app.update();
+    assert_near(width(&app, view), 199.0);
+}
+
+#[test]
+fn chained_tweens_replace_tweens_of_their_property() {
+    let mut app = app();
+    let view = app
+        .world_mut()
+        .spawn(VStack::new().width(Val::Px(100.0)).height(Val::Px(0.0)))
+        .id();
+    app.update();
+    app.world_mut()
+        .commands()
+        .entity(view)
+        .tween(
+            Tween::new(UiProp::Height, Val::Px(10.0), 0.1, Ease::Linear)
+                .unwrap()
+                .then(Tween::new(UiProp::Width, Val::Px(300.0), 0.2, Ease::Linear).unwrap()),
+        )
+        .animate(UiProp::Width, Val::Px(0.0), 10.0, Ease::Linear)
+        .unwrap();
+    for _ in 0..6 {
+        app.update();
+    }
+    assert_near(width(&app, view), 300.0);
+    assert!(app.world().get::<ActiveTweens>(view).is_none());
+}
+
+#[test]
+fn tweens_reject_values_of_the_wrong_kind() {
+    let err = Tween::new(UiProp::Width, Color::WHITE, 1.0, Ease::Linear).unwrap_err();
+    assert_eq!(err.prop, UiProp::Width);
+    assert_eq!(err.value, UiValue::Color(Color::WHITE));
+}
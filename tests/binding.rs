@@ -0,0 +1,93 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+use swoop_ui::prelude::*;
+
+#[derive(Resource, Default)]
+struct Volume(f32);
+
+fn label_text(app: &mut bevy_app::App, label: Entity) -> String {
+    app.world().get::<Text>(label).unwrap().0.clone()
+}
+
+#[test]
+fn reader_spawned_before_input_sees_edits() {
+    let mut app = common::app();
+    app.init_resource::<Volume>();
+    let label = app
+        .world_mut()
+        .spawn(SText::new().bind_text(from_resource(|volume: &Volume| volume.0.to_string())))
+        .id();
+    let slider = app
+        .world_mut()
+        .spawn(
+            Slider::new()
+                .bind(|volume: &mut Volume| &mut volume.0)
+                .pack(),
+        )
+        .id();
+    app.update();
+    assert_eq!(label_text(&mut app, label), "0");
+
+    app.world_mut()
+        .get_mut::<SliderState>(slider)
+        .unwrap()
+        .value = 0.5;
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<Volume>().0, 0.5);
+    assert_eq!(label_text(&mut app, label), "0.5");
+}
+
+#[test]
+fn input_follows_resource_after_being_edited() {
+    let mut app = common::app();
+    app.init_resource::<Volume>();
+    let slider = app
+        .world_mut()
+        .spawn(
+            Slider::new()
+                .bind(|volume: &mut Volume| &mut volume.0)
+                .pack(),
+        )
+        .id();
+    app.update();
+    app.world_mut()
+        .get_mut::<SliderState>(slider)
+        .unwrap()
+        .value = 0.5;
+    app.update();
+    app.update();
+
+    app.world_mut().resource_mut::<Volume>().0 = 0.25;
+    app.update();
+    assert_eq!(app.world().get::<SliderState>(slider).unwrap().value, 0.25);
+    assert_eq!(app.world().resource::<Volume>().0, 0.25);
+}
+
+#[derive(Component)]
+struct Health(u32);
+
+#[test]
+fn component_bindings_need_a_source_when_several_entities_match() {
+    let mut app = common::app();
+    app.world_mut().spawn(Health(10));
+    let player = app.world_mut().spawn(Health(20)).id();
+    let ambiguous = app
+        .world_mut()
+        .spawn(SText::new().bind_text(|health: &Health| health.0.to_string()))
+        .id();
+    let sourced = app
+        .world_mut()
+        .spawn(
+            SText::new()
+                .bind_source(player)
+                .bind_text(|health: &Health| health.0.to_string()),
+        )
+        .id();
+    app.update();
+
+    assert_eq!(label_text(&mut app, ambiguous), "");
+    assert_eq!(label_text(&mut app, sourced), "20");
+}
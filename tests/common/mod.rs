@@ -0,0 +1,58 @@
+//! Headless app setup shared by the integration tests.
+
+#![allow(dead_code)]
+
+use bevy_app::prelude::*;
+use bevy_asset::AssetPlugin;
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::InputPlugin;
+use bevy_input::keyboard::{Key, KeyCode, KeyboardInput};
+use bevy_input::mouse::{MouseButton, MouseButtonInput};
+use bevy_time::TimePlugin;
+use swoop_ui::prelude::*;
+
+/// An app with every Swoop UI plugin and no renderer, so layout never runs.
+pub fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        TaskPoolPlugin::default(),
+        TimePlugin,
+        AssetPlugin::default(),
+        InputPlugin,
+        SwoopUiPlugin,
+    ));
+    app
+}
+
+/// Sends a press and release of `key` and runs one frame.
+pub fn tap(app: &mut App, key_code: KeyCode, logical_key: Key) {
+    let window = app.world_mut().spawn_empty().id();
+    for state in [ButtonState::Pressed, ButtonState::Released] {
+        app.world_mut().send_event(KeyboardInput {
+            key_code,
+            logical_key: logical_key.clone(),
+            state,
+            text: None,
+            repeat: false,
+            window,
+        });
+    }
+    app.update();
+}
+
+/// Returns the number of entities in the world.
+pub fn entity_count(app: &mut App) -> usize {
+    app.world_mut().query::<Entity>().iter(app.world()).count()
+}
+
+/// Sends a press of the left mouse button and runs one frame.
+pub fn press_left_mouse(app: &mut App) {
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut().send_event(MouseButtonInput {
+        button: MouseButton::Left,
+        state: ButtonState::Pressed,
+        window,
+    });
+    app.update();
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use swoop_ui::prelude::*;
+
+#[derive(Resource, Default)]
+struct Session {
+    logged_in: bool,
+    banned: bool,
+}
+
+fn branch(name: &'static str) -> impl Fn() -> Name + Send + Sync + 'static {
+    move || Name::new(name)
+}
+
+/// Returns the names of the branches spawned under `view`.
+fn spawned(app: &bevy_app::App, view: Entity) -> Vec<String> {
+    let world = app.world();
+    world
+        .get::<Children>(view)
+        .map(|children| {
+            children
+                .iter()
+                .map(|child| world.get::<Name>(child).unwrap().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn set_session(app: &mut bevy_app::App, logged_in: bool, banned: bool) {
+    *app.world_mut().resource_mut::<Session>() = Session { logged_in, banned };
+    app.update();
+}
+
+#[test]
+fn if_new_switches_between_then_and_otherwise() {
+    let mut app = common::app();
+    app.init_resource::<Session>();
+    let view = app
+        .world_mut()
+        .spawn(If::new(
+            from_resource(|session: &Session| session.logged_in),
+            branch("Profile"),
+            branch("Login"),
+        ))
+        .id();
+    app.update();
+    assert_eq!(spawned(&app, view), ["Login"]);
+
+    set_session(&mut app, true, false);
+    assert_eq!(spawned(&app, view), ["Profile"]);
+
+    set_session(&mut app, false, false);
+    assert_eq!(spawned(&app, view), ["Login"]);
+}
+
+#[test]
+fn chained_conditions_are_checked_in_order() {
+    let mut app = common::app();
+    app.init_resource::<Session>();
+    let view = app
+        .world_mut()
+        .spawn(
+            If::default()
+                .when(from_resource(|s: &Session| s.banned), branch("Banned"))
+                .when(from_resource(|s: &Session| s.logged_in), branch("Profile"))
+                .otherwise(branch("Login")),
+        )
+        .id();
+    app.update();
+    assert_eq!(spawned(&app, view), ["Login"]);
+
+    // Only the second condition holds
+    set_session(&mut app, true, false);
+    assert_eq!(spawned(&app, view), ["Profile"]);
+
+    // Both hold, the first one wins
+    set_session(&mut app, true, true);
+    assert_eq!(spawned(&app, view), ["Banned"]);
+
+    set_session(&mut app, true, false);
+    assert_eq!(spawned(&app, view), ["Profile"]);
+
+    set_session(&mut app, false, true);
+    assert_eq!(spawned(&app, view), ["Banned"]);
+}
+
+#[derive(Resource, Clone, Copy, PartialEq)]
+enum Page {
+    Main,
+    Options,
+    Credits,
+}
+
+#[test]
+fn switch_shows_the_matching_case() {
+    let mut app = common::app();
+    app.insert_resource(Page::Main);
+    let view = app
+        .world_mut()
+        .spawn(
+            Switch::default()
+                .on(from_resource(|page: &Page| *page))
+                .case(Page::Main, branch("Main"))
+                .case(Page::Options, branch("Options"))
+                .otherwise(branch("Fallback")),
+        )
+        .id();
+    app.update();
+    assert_eq!(spawned(&app, view), ["Main"]);
+
+    for (page, expected) in [(Page::Options, "Options"), (Page::Credits, "Fallback")] {
+        app.insert_resource(page);
+        app.update();
+        assert_eq!(spawned(&app, view), [expected]);
+    }
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_time::TimeUpdateStrategy;
+use bevy_ui::ComputedNode;
+use bevy_ui::prelude::*;
+use swoop_ui::prelude::*;
+
+/// Returns the header and the animated content of a disclosure group.
+fn parts(app: &bevy_app::App, group: Entity) -> (Entity, Entity) {
+    let children = app.world().get::<Children>(group).unwrap();
+    (children[0], children[1])
+}
+
+/// Presses the header of `group` and runs a frame.
+fn press_header(app: &mut bevy_app::App, group: Entity) {
+    let (header, _) = parts(app, group);
+    *app.world_mut().get_mut::<Interaction>(header).unwrap() = Interaction::Pressed;
+    app.update();
+    *app.world_mut().get_mut::<Interaction>(header).unwrap() = Interaction::Hovered;
+    app.update();
+}
+
+/// Returns whether `group` is expanded.
+fn expanded(app: &bevy_app::App, group: Entity) -> bool {
+    app.world().get::<Disclosure>(group).unwrap().expanded
+}
+
+/// Returns the text of the header of `group`.
+fn header_text(app: &bevy_app::App, group: Entity) -> String {
+    let (header, _) = parts(app, group);
+    let label = app.world().get::<Children>(header).unwrap()[0];
+    app.world().get::<Text>(label).unwrap().0.clone()
+}
+
+#[test]
+fn headers_toggle_their_group_and_size_its_content() {
+    let mut app = common::app();
+    // Frames longer than the expand animation, so it ends within each frame
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        0.25,
+    )));
+    let group = app
+        .world_mut()
+        .spawn(DisclosureGroup::new().label("Video").content(VStack::new))
+        .id();
+    app.update();
+    let (_, content) = parts(&app, group);
+    let body = app.world().get::<Children>(content).unwrap()[0];
+    // Layout never runs, so give the content its natural size by hand
+    app.world_mut().entity_mut(body).insert(ComputedNode {
+        size: Vec2::new(200.0, 80.0),
+        inverse_scale_factor: 1.0,
+        ..Default::default()
+    });
+    assert!(!expanded(&app, group));
+    assert_eq!(header_text(&app, group), "▸ Video");
+
+    press_header(&mut app, group);
+    assert!(expanded(&app, group));
+    assert_eq!(header_text(&app, group), "▾ Video");
+    assert_eq!(
+        app.world().get::<Node>(content).unwrap().height,
+        Val::Px(80.0)
+    );
+
+    press_header(&mut app, group);
+    assert!(!expanded(&app, group));
+    assert_eq!(header_text(&app, group), "▸ Video");
+    assert_eq!(
+        app.world().get::<Node>(content).unwrap().height,
+        Val::Px(0.0)
+    );
+}
+
+#[test]
+fn accordions_keep_one_group_open() {
+    let mut app = common::app();
+    let accordion = app
+        .world_mut()
+        .spawn((
+            Accordion::new(),
+            children![
+                DisclosureGroup::new()
+                    .label("Video")
+                    .expanded(true)
+                    .content(VStack::new),
+                DisclosureGroup::new().label("Audio").content(VStack::new),
+                DisclosureGroup::new()
+                    .label("Controls")
+                    .content(VStack::new),
+            ],
+        ))
+        .id();
+    app.update();
+    let groups = app.world().get::<Children>(accordion).unwrap().to_vec();
+    let open = |app: &bevy_app::App| {
+        groups
+            .iter()
+            .map(|group| expanded(app, *group))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(open(&app), [true, false, false]);
+
+    press_header(&mut app, groups[1]);
+    assert_eq!(open(&app), [false, true, false]);
+
+    // Opening from code closes the others too
+    app.world_mut()
+        .get_mut::<Disclosure>(groups[2])
+        .unwrap()
+        .expanded = true;
+    app.update();
+    assert_eq!(open(&app), [false, false, true]);
+
+    press_header(&mut app, groups[2]);
+    assert_eq!(open(&app), [false, false, false]);
+}
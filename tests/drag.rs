@@ -0,0 +1,123 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::mouse::{MouseButton, MouseButtonInput};
+use bevy_math::{Rect, Vec2};
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, RelativeCursorPosition};
+use bevy_window::{PrimaryWindow, Window};
+use swoop_ui::prelude::*;
+
+#[derive(Component, Clone)]
+struct Slot(usize);
+
+/// The slot last dropped on the target.
+#[derive(Resource)]
+struct DroppedSlot(usize);
+
+fn set_cursor(app: &mut bevy_app::App, window: Entity, position: Vec2) {
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .set_cursor_position(Some(position));
+}
+
+fn send_left_mouse(app: &mut bevy_app::App, window: Entity, state: ButtonState) {
+    app.world_mut().send_event(MouseButtonInput {
+        button: MouseButton::Left,
+        state,
+        window,
+    });
+}
+
+/// Layout never runs, so place the view by hand.
+fn place(app: &mut bevy_app::App, view: Entity, center: Vec2) {
+    app.world_mut().entity_mut(view).insert((
+        ComputedNode {
+            size: Vec2::new(100.0, 40.0),
+            inverse_scale_factor: 1.0,
+            ..Default::default()
+        },
+        GlobalTransform::from_translation(center.extend(0.0)),
+    ));
+}
+
+/// Returns the children of `view`.
+fn children(app: &bevy_app::App, view: Entity) -> Vec<Entity> {
+    app.world()
+        .get::<Children>(view)
+        .map(|children| children.to_vec())
+        .unwrap_or_default()
+}
+
+/// Returns the bounds of the drop highlight, if one is shown.
+fn highlight(app: &mut bevy_app::App) -> Option<(Val, Val, Val, Val)> {
+    app.world_mut()
+        .query_filtered::<(&Node, &GlobalZIndex), Without<ChildOf>>()
+        .iter(app.world())
+        .find(|(_, z_index)| z_index.0 == 1999)
+        .map(|(node, _)| (node.left, node.top, node.width, node.height))
+}
+
+#[test]
+fn dropping_on_a_target_highlights_it_without_touching_its_children() {
+    let mut app = common::app();
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    let source = app
+        .world_mut()
+        .spawn(TextButton::new().text("Sword").draggable(Slot(3)).pack())
+        .id();
+    let target = app
+        .world_mut()
+        .spawn(
+            TextButton::new()
+                .text("Empty")
+                .drop_target::<Slot>(|commands, slot, _| {
+                    commands.insert_resource(DroppedSlot(slot.0));
+                })
+                .pack(),
+        )
+        .id();
+    app.update();
+    place(&mut app, source, Vec2::new(50.0, 20.0));
+    place(&mut app, target, Vec2::new(50.0, 120.0));
+    let target_children = children(&app, target);
+
+    set_cursor(&mut app, window, Vec2::new(50.0, 20.0));
+    *app.world_mut().get_mut::<Interaction>(source).unwrap() = Interaction::Pressed;
+    send_left_mouse(&mut app, window, ButtonState::Pressed);
+    app.update();
+    set_cursor(&mut app, window, Vec2::new(50.0, 120.0));
+    app.world_mut()
+        .entity_mut(target)
+        .insert(RelativeCursorPosition {
+            normalized_visible_node_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+            normalized: Some(Vec2::splat(0.5)),
+        });
+    app.update();
+    app.update();
+    assert_eq!(
+        highlight(&mut app),
+        Some((Val::Px(0.0), Val::Px(100.0), Val::Px(100.0), Val::Px(40.0)))
+    );
+    assert_eq!(children(&app, target), target_children);
+
+    // The highlight follows the target when it moves
+    place(&mut app, target, Vec2::new(50.0, 220.0));
+    app.update();
+    assert_eq!(
+        highlight(&mut app),
+        Some((Val::Px(0.0), Val::Px(200.0), Val::Px(100.0), Val::Px(40.0)))
+    );
+
+    send_left_mouse(&mut app, window, ButtonState::Released);
+    app.update();
+    assert_eq!(app.world().resource::<DroppedSlot>().0, 3);
+    assert_eq!(highlight(&mut app), None);
+    assert_eq!(children(&app, target), target_children);
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::keyboard::{Key, KeyCode, KeyboardInput};
+use bevy_math::{Vec2, Vec3};
+use bevy_transform::prelude::*;
+use bevy_ui::ComputedNode;
+use bevy_ui::prelude::*;
+use swoop_ui::prelude::*;
+
+/// Spawns a button centered at `(x, y)`, placed by hand since layout never runs.
+fn button(app: &mut bevy_app::App, x: f32, y: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            HButton::new(),
+            ComputedNode {
+                size: Vec2::new(80.0, 40.0),
+                inverse_scale_factor: 1.0,
+                ..Default::default()
+            },
+            GlobalTransform::from_translation(Vec3::new(x, y, 0.0)),
+        ))
+        .id()
+}
+
+/// Spawns a 2x2 grid of buttons, in reading order.
+fn grid(app: &mut bevy_app::App) -> [Entity; 4] {
+    let buttons =
+        [(0.0, 0.0), (100.0, 0.0), (0.0, 100.0), (100.0, 100.0)].map(|(x, y)| button(app, x, y));
+    app.update();
+    buttons
+}
+
+fn focused(app: &bevy_app::App) -> Option<Entity> {
+    app.world().resource::<FocusedEntity>().0
+}
+
+fn set_shift(app: &mut bevy_app::App, state: ButtonState) {
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut().send_event(KeyboardInput {
+        key_code: KeyCode::ShiftLeft,
+        logical_key: Key::Shift,
+        state,
+        text: None,
+        repeat: false,
+        window,
+    });
+}
+
+fn shift_tab(app: &mut bevy_app::App) {
+    set_shift(app, ButtonState::Pressed);
+    common::tap(app, KeyCode::Tab, Key::Tab);
+    set_shift(app, ButtonState::Released);
+    app.update();
+}
+
+#[test]
+fn tab_follows_reading_order_and_wraps() {
+    let mut app = common::app();
+    let [a, b, c, d] = grid(&mut app);
+    assert_eq!(focused(&app), None);
+
+    for expected in [a, b, c, d, a] {
+        common::tap(&mut app, KeyCode::Tab, Key::Tab);
+        assert_eq!(focused(&app), Some(expected));
+    }
+}
+
+#[test]
+fn shift_tab_goes_backwards() {
+    let mut app = common::app();
+    let [a, b, c, d] = grid(&mut app);
+
+    shift_tab(&mut app);
+    assert_eq!(focused(&app), Some(d));
+    for expected in [c, b, a, d] {
+        shift_tab(&mut app);
+        assert_eq!(focused(&app), Some(expected));
+    }
+}
+
+#[test]
+fn tab_order_comes_before_position() {
+    let mut app = common::app();
+    let [a, b, c, d] = grid(&mut app);
+    app.world_mut().get_mut::<Focusable>(d).unwrap().order = -1;
+    app.world_mut().get_mut::<Focusable>(b).unwrap().disabled = true;
+
+    for expected in [d, a, c, d] {
+        common::tap(&mut app, KeyCode::Tab, Key::Tab);
+        assert_eq!(focused(&app), Some(expected));
+    }
+}
+
+#[test]
+fn arrows_move_spatially() {
+    let mut app = common::app();
+    let [a, b, c, d] = grid(&mut app);
+
+    // Without focus, any arrow focuses the first view
+    common::tap(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+    assert_eq!(focused(&app), Some(a));
+    for (key, logical, expected) in [
+        (KeyCode::ArrowRight, Key::ArrowRight, b),
+        (KeyCode::ArrowDown, Key::ArrowDown, d),
+        (KeyCode::ArrowLeft, Key::ArrowLeft, c),
+        (KeyCode::ArrowUp, Key::ArrowUp, a),
+    ] {
+        common::tap(&mut app, key, logical);
+        assert_eq!(focused(&app), Some(expected), "after {key:?}");
+    }
+
+    // Nothing further left, focus stays
+    common::tap(&mut app, KeyCode::ArrowLeft, Key::ArrowLeft);
+    assert_eq!(focused(&app), Some(a));
+}
+
+#[test]
+fn enter_presses_the_focused_view() {
+    let mut app = common::app();
+    let [a, ..] = grid(&mut app);
+    common::tap(&mut app, KeyCode::Tab, Key::Tab);
+
+    common::tap(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(
+        *app.world().get::<Interaction>(a).unwrap(),
+        Interaction::Pressed
+    );
+    app.update();
+    assert_eq!(
+        *app.world().get::<Interaction>(a).unwrap(),
+        Interaction::None
+    );
+}
+
+#[test]
+fn focus_ring_restores_the_views_outline() {
+    let mut app = common::app();
+    let [a, b, ..] = grid(&mut app);
+    let own = Outline::new(Val::Px(1.0), Val::ZERO, Color::BLACK);
+    app.world_mut().entity_mut(a).insert(own);
+    let ring = *app.world().resource::<FocusRing>();
+    let outline = |app: &bevy_app::App, entity| app.world().get::<Outline>(entity).copied();
+
+    common::tap(&mut app, KeyCode::Tab, Key::Tab);
+    assert_eq!(outline(&app, a).unwrap().color, ring.color);
+
+    // Restyling the ring keeps the saved outline
+    app.world_mut().resource_mut::<FocusRing>().color = Color::WHITE;
+    app.update();
+    assert_eq!(outline(&app, a).unwrap().color, Color::WHITE);
+
+    common::tap(&mut app, KeyCode::Tab, Key::Tab);
+    assert_eq!(outline(&app, a), Some(own));
+    assert_eq!(outline(&app, b).unwrap().color, Color::WHITE);
+
+    common::tap(&mut app, KeyCode::Tab, Key::Tab);
+    assert_eq!(outline(&app, b), None);
+}
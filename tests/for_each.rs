@@ -0,0 +1,180 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_transform::prelude::*;
+use bevy_ui::ComputedNode;
+use bevy_ui::prelude::*;
+use swoop_ui::prelude::*;
+
+#[derive(Clone, PartialEq)]
+struct Item {
+    id: u32,
+    name: &'static str,
+}
+
+#[derive(Resource)]
+struct Inventory(Vec<Item>);
+
+/// Inserted by every build of a row, to tell whether it was rebuilt.
+#[derive(Component)]
+struct Built;
+
+#[derive(Component)]
+struct Server(&'static str);
+
+fn rows(app: &bevy_app::App, list: Entity) -> Vec<(Entity, String)> {
+    let world = app.world();
+    world
+        .get::<Children>(list)
+        .map(|children| {
+            children
+                .iter()
+                .map(|row| (row, world.get::<Name>(row).unwrap().to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn mark(app: &mut bevy_app::App, rows: &[(Entity, String)]) {
+    for (row, _) in rows {
+        app.world_mut().entity_mut(*row).remove::<Built>();
+    }
+}
+
+#[test]
+fn changed_items_are_rebuilt_in_place() {
+    let mut app = common::app();
+    app.insert_resource(Inventory(vec![
+        Item {
+            id: 1,
+            name: "Sword",
+        },
+        Item {
+            id: 2,
+            name: "Shield",
+        },
+    ]));
+    let list = app
+        .world_mut()
+        .spawn(ForEach::new().for_each(
+            |inventory: &Inventory| &inventory.0,
+            |item| item.id,
+            |item| (Name::new(item.name), Built),
+        ))
+        .id();
+    app.update();
+    let before = rows(&app, list);
+    mark(&mut app, &before);
+
+    app.world_mut().resource_mut::<Inventory>().0[1].name = "Tower Shield";
+    app.update();
+    let after = rows(&app, list);
+    assert_eq!(after[0], before[0]);
+    assert_eq!(after[1], (before[1].0, "Tower Shield".to_string()));
+    let untouched = |row| app.world().get::<Built>(row).is_none();
+    assert!(untouched(after[0].0));
+    assert!(!untouched(after[1].0));
+}
+
+#[test]
+fn duplicate_keys_get_one_row_and_settle() {
+    let mut app = common::app();
+    app.world_mut().spawn(Server("eu"));
+    app.world_mut().spawn(Server("eu"));
+    app.world_mut().spawn(Server("us"));
+    let list = app
+        .world_mut()
+        .spawn(ForEach::new().for_each_component(
+            |_, server: &Server| server.0,
+            |_, server: &Server| Name::new(server.0),
+        ))
+        .id();
+    app.update();
+    let names: Vec<_> = rows(&app, list).into_iter().map(|(_, name)| name).collect();
+    assert_eq!(names, ["eu", "us"]);
+
+    let changed = |app: &bevy_app::App| {
+        app.world()
+            .entity(list)
+            .get_ref::<Children>()
+            .unwrap()
+            .last_changed()
+    };
+    let settled = changed(&app);
+    app.update();
+    app.update();
+    assert_eq!(changed(&app), settled);
+}
+
+#[test]
+fn changed_components_are_rebuilt_in_place() {
+    let mut app = common::app();
+    let eu = app.world_mut().spawn(Server("eu")).id();
+    app.world_mut().spawn(Server("us"));
+    let list = app
+        .world_mut()
+        .spawn(ForEach::new().for_each_component(
+            |entity, _: &Server| entity,
+            |_, server: &Server| (Name::new(server.0), Built),
+        ))
+        .id();
+    app.update();
+    let before = rows(&app, list);
+    mark(&mut app, &before);
+
+    app.world_mut().get_mut::<Server>(eu).unwrap().0 = "eu-west";
+    app.update();
+    let after = rows(&app, list);
+    assert_eq!(after[0], (before[0].0, "eu-west".to_string()));
+    assert_eq!(after[1], before[1]);
+    assert!(app.world().get::<Built>(after[0].0).is_some());
+    assert!(app.world().get::<Built>(after[1].0).is_none());
+}
+
+#[test]
+fn rebuilt_rows_keep_focus_and_tweens() {
+    let mut app = common::app();
+    app.insert_resource(Inventory(vec![Item {
+        id: 1,
+        name: "Sword",
+    }]));
+    let list = app
+        .world_mut()
+        .spawn(ForEach::new().for_each(
+            |inventory: &Inventory| &inventory.0,
+            |item| item.id,
+            |item| HButton::new().name(item.name),
+        ))
+        .id();
+    app.update();
+    let row = rows(&app, list)[0].0;
+    // Placed by hand since layout never runs
+    app.world_mut().entity_mut(row).insert((
+        ComputedNode {
+            size: Vec2::new(80.0, 40.0),
+            inverse_scale_factor: 1.0,
+            ..Default::default()
+        },
+        GlobalTransform::default(),
+    ));
+    app.world_mut().resource_mut::<FocusedEntity>().0 = Some(row);
+    app.world_mut()
+        .commands()
+        .entity(row)
+        .animate(UiProp::Width, Val::Px(200.0), 60.0, Ease::Linear)
+        .unwrap();
+    app.update();
+    assert!(app.world().get::<Outline>(row).is_some());
+
+    app.world_mut().resource_mut::<Inventory>().0[0].name = "Broadsword";
+    app.update();
+    assert_eq!(rows(&app, list), [(row, "Broadsword".to_string())]);
+    assert_eq!(app.world().resource::<FocusedEntity>().0, Some(row));
+    assert!(app.world().get::<Outline>(row).is_some());
+    assert!(
+        app.world()
+            .get::<ActiveTweens>(row)
+            .is_some_and(|tweens| tweens.is_animating(UiProp::Width))
+    );
+}
@@ -0,0 +1,239 @@
+mod common;
+
+use bevy_color::prelude::*;
+use bevy_ecs::event::Events;
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::keyboard::{Key, KeyCode, KeyboardInput, NativeKeyCode};
+use bevy_input::mouse::{MouseButton, MouseButtonInput};
+use bevy_math::{Rect, Vec2};
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, RelativeCursorPosition};
+use swoop_ui::prelude::*;
+
+/// Sizes `entity` and puts the cursor at `x` across it, as layout and picking would.
+fn cursor_at(app: &mut bevy_app::App, entity: Entity, x: f32) {
+    app.world_mut().entity_mut(entity).insert((
+        ComputedNode {
+            size: Vec2::new(160.0, 8.0),
+            inverse_scale_factor: 1.0,
+            ..Default::default()
+        },
+        GlobalTransform::default(),
+        RelativeCursorPosition {
+            normalized_visible_node_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+            normalized: Some(Vec2::new(x, 0.5)),
+        },
+    ));
+}
+
+fn slider_value(app: &bevy_app::App, slider: Entity) -> f32 {
+    app.world().get::<SliderState>(slider).unwrap().value
+}
+
+/// Sends a key press for every character of `text` and runs one frame.
+fn type_text(app: &mut bevy_app::App, text: &str) {
+    let window = app.world_mut().spawn_empty().id();
+    for character in text.chars() {
+        let character: String = character.into();
+        app.world_mut().send_event(KeyboardInput {
+            key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+            logical_key: Key::Character(character.as_str().into()),
+            state: ButtonState::Pressed,
+            text: Some(character.as_str().into()),
+            repeat: false,
+            window,
+        });
+    }
+    app.update();
+}
+
+/// Returns the values reported since the last call.
+fn number_changes(app: &mut bevy_app::App) -> Vec<f32> {
+    app.world_mut()
+        .resource_mut::<Events<NumberChanged>>()
+        .drain()
+        .map(|changed| changed.value)
+        .collect()
+}
+
+/// Releases the left mouse button over `entity` and runs one frame.
+fn release_left_mouse(app: &mut bevy_app::App, entity: Entity) {
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut().send_event(MouseButtonInput {
+        button: MouseButton::Left,
+        state: ButtonState::Released,
+        window,
+    });
+    *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::Hovered;
+    app.update();
+}
+
+fn focus(app: &mut bevy_app::App, entity: Entity) {
+    app.world_mut().resource_mut::<FocusedEntity>().0 = Some(entity);
+    app.update();
+}
+
+#[test]
+fn pressed_sliders_follow_the_held_mouse() {
+    let mut app = common::app();
+    let slider = app.world_mut().spawn(Slider::new().pack()).id();
+    cursor_at(&mut app, slider, 0.25);
+    app.world_mut()
+        .entity_mut(slider)
+        .insert(Interaction::Pressed);
+    app.update();
+    assert_eq!(slider_value(&app, slider), 0.0);
+
+    common::press_left_mouse(&mut app);
+    assert_eq!(slider_value(&app, slider), 0.25);
+}
+
+#[test]
+fn activating_a_focused_slider_keeps_its_value() {
+    let mut app = common::app();
+    let slider = app.world_mut().spawn(Slider::new().pack()).id();
+    cursor_at(&mut app, slider, 0.8);
+    focus(&mut app, slider);
+    common::press_left_mouse(&mut app);
+
+    common::tap(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(
+        *app.world().get::<Interaction>(slider).unwrap(),
+        Interaction::Pressed
+    );
+    assert_eq!(slider_value(&app, slider), 0.0);
+}
+
+#[test]
+fn arrows_step_a_focused_slider() {
+    let mut app = common::app();
+    let stepped = app
+        .world_mut()
+        .spawn(Slider::new().range(0.0, 10.0).step(2.0).pack())
+        .id();
+    let continuous = app.world_mut().spawn(Slider::new().pack()).id();
+    cursor_at(&mut app, stepped, 0.0);
+    cursor_at(&mut app, continuous, 0.0);
+
+    focus(&mut app, stepped);
+    common::tap(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    common::tap(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    common::tap(&mut app, KeyCode::ArrowLeft, Key::ArrowLeft);
+    assert_eq!(slider_value(&app, stepped), 2.0);
+    // The arrows step the value instead of moving focus
+    assert_eq!(app.world().resource::<FocusedEntity>().0, Some(stepped));
+
+    focus(&mut app, continuous);
+    common::tap(&mut app, KeyCode::ArrowLeft, Key::ArrowLeft);
+    common::tap(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    assert!((slider_value(&app, continuous) - 0.1).abs() < 1e-6);
+}
+
+#[test]
+fn stepper_buttons_step_within_the_range() {
+    let mut app = common::app();
+    let stepper = app
+        .world_mut()
+        .spawn(Stepper::new().range(0.0, 3.0).value(1.0).pack())
+        .id();
+    app.update();
+    let children = app.world().get::<Children>(stepper).unwrap().to_vec();
+    let (minus, plus) = (children[0], children[2]);
+
+    for button in [plus, plus, plus, minus] {
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+        app.update();
+    }
+    assert_eq!(number_changes(&mut app), [2.0, 3.0, 2.0]);
+    assert_eq!(app.world().get::<NumberState>(stepper).unwrap().value, 2.0);
+    let label = app.world().get::<Text>(children[1]).unwrap();
+    assert_eq!(label.0, "2");
+}
+
+#[test]
+fn typed_numbers_are_clamped_on_commit() {
+    let mut app = common::app();
+    let field = app
+        .world_mut()
+        .spawn(NumberField::new().range(0.0, 10.0).pack())
+        .id();
+    focus(&mut app, field);
+    common::tap(&mut app, KeyCode::Backspace, Key::Backspace);
+    type_text(&mut app, "42");
+    // Nothing changes until the text is committed
+    assert_eq!(app.world().get::<NumberState>(field).unwrap().value, 0.0);
+    assert_eq!(number_changes(&mut app), []);
+
+    common::tap(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(app.world().get::<NumberState>(field).unwrap().value, 10.0);
+    assert_eq!(number_changes(&mut app), [10.0]);
+    assert_eq!(
+        app.world().get::<NumberFieldEdit>(field).unwrap().text,
+        "10"
+    );
+}
+
+#[test]
+fn dragging_the_color_square_picks_saturation_and_value() {
+    let mut app = common::app();
+    let picker = app.world_mut().spawn(ColorPicker::new().pack()).id();
+    app.update();
+    let square = app.world().get::<Children>(picker).unwrap()[0];
+    let picked = |app: &bevy_app::App| app.world().get::<ColorPickerState>(picker).unwrap().color;
+    cursor_at(&mut app, square, 0.25);
+    app.world_mut()
+        .entity_mut(square)
+        .insert(Interaction::Pressed);
+    app.update();
+    // Without a held pointer the press came from elsewhere
+    assert_eq!(picked(&app), Hsva::new(0.0, 0.0, 1.0, 1.0));
+
+    common::press_left_mouse(&mut app);
+    assert_eq!(picked(&app), Hsva::new(0.0, 0.25, 0.5, 1.0));
+    cursor_at(&mut app, square, 0.75);
+    app.update();
+    assert_eq!(picked(&app), Hsva::new(0.0, 0.75, 0.5, 1.0));
+    let changes = app
+        .world_mut()
+        .resource_mut::<Events<ColorChanged>>()
+        .drain()
+        .count();
+    assert_eq!(changes, 2);
+}
+
+#[test]
+fn releasing_a_color_drag_commits_the_color() {
+    let mut app = common::app();
+    let picker = app.world_mut().spawn(ColorPicker::new().pack()).id();
+    app.update();
+    let hue = app.world().get::<Children>(picker).unwrap()[1];
+    let commits = |app: &mut bevy_app::App| {
+        app.world_mut()
+            .resource_mut::<Events<ColorCommitted>>()
+            .drain()
+            .collect::<Vec<_>>()
+    };
+    app.world_mut()
+        .get_mut::<ColorPickerState>(picker)
+        .unwrap()
+        .color = Hsva::new(0.0, 1.0, 1.0, 1.0);
+    cursor_at(&mut app, hue, 1.0 / 3.0);
+    app.world_mut().entity_mut(hue).insert(Interaction::Pressed);
+    common::press_left_mouse(&mut app);
+    app.update();
+    assert!(commits(&mut app).is_empty());
+
+    release_left_mouse(&mut app, hue);
+    let committed = commits(&mut app);
+    assert_eq!(committed.len(), 1);
+    assert_eq!(committed[0].entity, picker);
+    let color = Hsva::from(committed[0].color);
+    assert!((color.hue - 120.0).abs() < 1e-3);
+
+    app.update();
+    assert!(commits(&mut app).is_empty());
+}
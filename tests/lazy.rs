@@ -0,0 +1,240 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_math::{Rect, Vec2};
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, RelativeCursorPosition};
+use swoop_ui::container::lazy::LazySlot;
+use swoop_ui::prelude::*;
+
+const ROWS: usize = 10_000;
+const ROW_HEIGHT: f32 = 20.0;
+
+fn spawn_list(app: &mut bevy_app::App) -> Entity {
+    let list = app
+        .world_mut()
+        .spawn(
+            LazyVStack::new()
+                .row_height(ROW_HEIGHT)
+                .lazy_range(ROWS, |index| Name::new(format!("Row {index}"))),
+        )
+        .id();
+    // Without a renderer the layout never runs, so size the viewport by hand
+    app.world_mut().entity_mut(list).insert(ComputedNode {
+        size: Vec2::new(300.0, 200.0),
+        inverse_scale_factor: 1.0,
+        ..Default::default()
+    });
+    list
+}
+
+fn scroll_to(app: &mut bevy_app::App, list: Entity, offset: f32) {
+    app.world_mut()
+        .get_mut::<ScrollPosition>(list)
+        .unwrap()
+        .offset_y = offset;
+    app.update();
+}
+
+/// Returns the names of the rows shown by visible slots, in order.
+fn shown_rows(app: &mut bevy_app::App, list: Entity) -> Vec<String> {
+    let world = app.world();
+    world
+        .get::<Children>(list)
+        .unwrap()
+        .iter()
+        .filter(|slot| {
+            world.get::<LazySlot>(*slot).is_some()
+                && world.get::<Node>(*slot).unwrap().display != Display::None
+        })
+        .map(|slot| {
+            let row = world.get::<Children>(slot).unwrap()[0];
+            world.get::<Name>(row).unwrap().to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn entity_count_stays_constant_while_scrolling() {
+    let mut app = common::app();
+    let list = spawn_list(&mut app);
+    app.update();
+    let entities = common::entity_count(&mut app);
+    for step in 0..=20 {
+        let offset = step as f32 / 20.0 * ROWS as f32 * ROW_HEIGHT;
+        scroll_to(&mut app, list, offset);
+        assert_eq!(
+            common::entity_count(&mut app),
+            entities,
+            "at offset {offset}"
+        );
+    }
+}
+
+#[test]
+fn slots_are_rebound_to_the_visible_rows() {
+    let mut app = common::app();
+    let list = spawn_list(&mut app);
+    app.update();
+    assert_eq!(shown_rows(&mut app, list).first().unwrap(), "Row 0");
+
+    scroll_to(&mut app, list, 1000.0 * ROW_HEIGHT);
+    let rows = shown_rows(&mut app, list);
+    // Two rows of overscan above the viewport
+    assert_eq!(rows.first().unwrap(), "Row 998");
+    assert_eq!(rows.len(), 10 + 2 * 2);
+    for (row, expected) in rows.iter().zip(998..) {
+        assert_eq!(*row, format!("Row {expected}"));
+    }
+}
+
+#[test]
+fn mouse_wheel_only_scrolls_lazy_views() {
+    let mut app = common::app();
+    let list = spawn_list(&mut app);
+    let other = app
+        .world_mut()
+        .spawn((
+            Node::default(),
+            ComputedNode {
+                size: Vec2::new(300.0, 200.0),
+                inverse_scale_factor: 1.0,
+                ..Default::default()
+            },
+            ScrollPosition {
+                offset_x: 0.0,
+                offset_y: 50.0,
+            },
+            children![Node::default()],
+        ))
+        .id();
+    app.update();
+    let under_cursor = RelativeCursorPosition {
+        normalized_visible_node_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+        normalized: Some(Vec2::splat(0.5)),
+    };
+    app.world_mut().entity_mut(list).insert(under_cursor);
+    app.world_mut().entity_mut(other).insert(under_cursor);
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut().send_event(MouseWheel {
+        unit: MouseScrollUnit::Pixel,
+        x: 0.0,
+        y: -100.0,
+        window,
+    });
+    app.update();
+    assert_eq!(
+        app.world().get::<ScrollPosition>(list).unwrap().offset_y,
+        100.0
+    );
+    assert_eq!(
+        app.world().get::<ScrollPosition>(other).unwrap().offset_y,
+        50.0
+    );
+}
+
+fn under_cursor() -> RelativeCursorPosition {
+    RelativeCursorPosition {
+        normalized_visible_node_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+        normalized: Some(Vec2::splat(0.5)),
+    }
+}
+
+fn wheel(app: &mut bevy_app::App, y: f32) {
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut().send_event(MouseWheel {
+        unit: MouseScrollUnit::Pixel,
+        x: 0.0,
+        y,
+        window,
+    });
+    app.update();
+}
+
+#[test]
+fn grids_scroll_to_the_end_of_their_last_row() {
+    let mut app = common::app();
+    let grid = app
+        .world_mut()
+        .spawn(
+            LazyVGrid::new()
+                .row_height(ROW_HEIGHT)
+                .columns(3)
+                .lazy_range(30, |index| Name::new(format!("Item {index}"))),
+        )
+        .id();
+    app.world_mut().entity_mut(grid).insert((
+        ComputedNode {
+            size: Vec2::new(300.0, 100.0),
+            inverse_scale_factor: 1.0,
+            ..Default::default()
+        },
+        under_cursor(),
+    ));
+    app.update();
+
+    // 30 items on 3 columns make 10 rows, 200px of content in a 100px viewport
+    wheel(&mut app, -1000.0);
+    assert_eq!(
+        app.world().get::<ScrollPosition>(grid).unwrap().offset_y,
+        100.0
+    );
+}
+
+#[derive(Resource)]
+struct Names(Vec<&'static str>);
+
+#[test]
+fn rebuilt_rows_keep_focus_and_tweens() {
+    let mut app = common::app();
+    app.insert_resource(Names(vec!["Sword", "Shield"]));
+    let list = app
+        .world_mut()
+        .spawn(LazyVStack::new().row_height(ROW_HEIGHT).lazy_rows(
+            |names: &Names| &names.0,
+            |_, name| HButton::new().name(*name),
+        ))
+        .id();
+    app.world_mut().entity_mut(list).insert(ComputedNode {
+        size: Vec2::new(300.0, 200.0),
+        inverse_scale_factor: 1.0,
+        ..Default::default()
+    });
+    app.update();
+    let row = |app: &bevy_app::App, name: &str| {
+        let mut rows = app.world().iter_entities().filter(|entity| {
+            entity.contains::<Button>() && entity.get::<Name>().is_some_and(|n| n.as_str() == name)
+        });
+        rows.next().unwrap().id()
+    };
+    let shield = row(&app, "Shield");
+    // Placed by hand since layout never runs
+    app.world_mut().entity_mut(shield).insert((
+        ComputedNode {
+            size: Vec2::new(80.0, 20.0),
+            inverse_scale_factor: 1.0,
+            ..Default::default()
+        },
+        GlobalTransform::default(),
+    ));
+    app.world_mut().resource_mut::<FocusedEntity>().0 = Some(shield);
+    app.world_mut()
+        .commands()
+        .entity(shield)
+        .animate(UiProp::Width, Val::Px(200.0), 60.0, Ease::Linear)
+        .unwrap();
+    app.update();
+
+    app.world_mut().resource_mut::<Names>().0[1] = "Tower Shield";
+    app.update();
+    assert_eq!(row(&app, "Tower Shield"), shield);
+    assert_eq!(app.world().resource::<FocusedEntity>().0, Some(shield));
+    assert!(app.world().get::<Outline>(shield).is_some());
+    assert!(
+        app.world()
+            .get::<ActiveTweens>(shield)
+            .is_some_and(|tweens| tweens.is_animating(UiProp::Width))
+    );
+}
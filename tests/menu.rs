@@ -0,0 +1,114 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::mouse::{MouseButton, MouseButtonInput};
+use bevy_input::touch::{TouchInput, TouchPhase};
+use bevy_math::Vec2;
+use bevy_time::TimeUpdateStrategy;
+use bevy_ui::prelude::*;
+use swoop_ui::prelude::*;
+
+/// An app whose clock advances by 0.1 seconds every frame, with a view that has a context menu.
+fn app() -> (bevy_app::App, Entity) {
+    let mut app = common::app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        0.1,
+    )));
+    let view = app
+        .world_mut()
+        .spawn(
+            TextButton::new()
+                .context_menu(|menu| menu.item("Drop", |_, _| {}))
+                .pack(),
+        )
+        .id();
+    app.update();
+    (app, view)
+}
+
+/// Keeps `view` pressed for a second.
+fn hold(app: &mut bevy_app::App, view: Entity) {
+    for _ in 0..10 {
+        *app.world_mut().get_mut::<Interaction>(view).unwrap() = Interaction::Pressed;
+        app.update();
+    }
+}
+
+fn open_menus(app: &mut bevy_app::App) -> usize {
+    app.world_mut()
+        .query::<&MenuPanel>()
+        .iter(app.world())
+        .count()
+}
+
+#[test]
+fn long_touch_opens_the_context_menu() {
+    let (mut app, view) = app();
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut().send_event(TouchInput {
+        phase: TouchPhase::Started,
+        position: Vec2::new(20.0, 30.0),
+        window,
+        force: None,
+        id: 0,
+    });
+    hold(&mut app, view);
+    assert_eq!(open_menus(&mut app), 1);
+}
+
+#[test]
+fn holding_the_mouse_button_does_not_open_the_context_menu() {
+    let (mut app, view) = app();
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut().send_event(MouseButtonInput {
+        button: MouseButton::Left,
+        state: ButtonState::Pressed,
+        window,
+    });
+    hold(&mut app, view);
+    assert_eq!(open_menus(&mut app), 0);
+}
+
+/// Finds the view named `name`, if it is spawned.
+fn named(app: &mut bevy_app::App, name: &str) -> Option<Entity> {
+    app.world_mut()
+        .query::<(Entity, &Name)>()
+        .iter(app.world())
+        .find(|(_, n)| n.as_str() == name)
+        .map(|(entity, _)| entity)
+}
+
+/// Sets the interaction of `view` and runs a frame.
+fn set_interaction(app: &mut bevy_app::App, view: Entity, interaction: Interaction) {
+    *app.world_mut().get_mut::<Interaction>(view).unwrap() = interaction;
+    app.update();
+}
+
+#[test]
+fn menu_bar_titles_drop_down_and_switch_on_hover() {
+    let mut app = common::app();
+    app.world_mut().spawn(
+        MenuBar::new()
+            .menu("File", |menu| menu.item("Save", |_, _| {}))
+            .menu("Edit", |menu| menu.item("Cut", |_, _| {})),
+    );
+    app.update();
+    let file = named(&mut app, "File").unwrap();
+    let edit = named(&mut app, "Edit").unwrap();
+
+    set_interaction(&mut app, file, Interaction::Pressed);
+    assert_eq!(open_menus(&mut app), 1);
+    assert!(named(&mut app, "Save").is_some());
+
+    set_interaction(&mut app, file, Interaction::None);
+    set_interaction(&mut app, edit, Interaction::Hovered);
+    assert_eq!(open_menus(&mut app), 1);
+    assert!(named(&mut app, "Save").is_none());
+    assert!(named(&mut app, "Cut").is_some());
+
+    set_interaction(&mut app, edit, Interaction::Pressed);
+    assert_eq!(open_menus(&mut app), 0);
+}
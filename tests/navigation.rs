@@ -0,0 +1,139 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::keyboard::{Key, KeyCode};
+use bevy_input::mouse::{MouseButton, MouseButtonInput};
+use bevy_math::{Vec2, Vec3};
+use bevy_transform::prelude::*;
+use bevy_ui::ComputedNode;
+use bevy_ui::prelude::*;
+use bevy_window::{PrimaryWindow, Window};
+use swoop_ui::prelude::*;
+
+struct MainMenu;
+
+impl Screen for MainMenu {
+    fn view(&self) -> impl Bundle {
+        (Node::default(), Name::new("MainMenu"))
+    }
+}
+
+/// Returns the screen shown by the stack and checks it lives in `host`.
+fn shown_screen(app: &mut bevy_app::App, host: Entity) -> Entity {
+    let world = app.world();
+    let top = world
+        .resource::<ScreenStack>()
+        .top()
+        .expect("no screen shown");
+    assert_eq!(world.get::<ChildOf>(top).map(ChildOf::parent), Some(host));
+    assert_eq!(world.get::<Name>(top).unwrap().as_str(), "MainMenu");
+    top
+}
+
+#[test]
+fn respawned_stack_shows_its_top_screen() {
+    let mut app = common::app();
+    let host = app
+        .world_mut()
+        .spawn(NavigationStack::new().root_screen(MainMenu))
+        .id();
+    app.update();
+    let first = shown_screen(&mut app, host);
+
+    app.world_mut().entity_mut(host).despawn();
+    app.update();
+    assert!(app.world().get_entity(first).is_err());
+    assert_eq!(app.world().resource::<ScreenStack>().top(), None);
+
+    let host = app
+        .world_mut()
+        .spawn(NavigationStack::new().root_screen(MainMenu))
+        .id();
+    app.update();
+    shown_screen(&mut app, host);
+    assert_eq!(app.world().resource::<ScreenStack>().len(), 1);
+}
+
+/// Spawns a stack showing two screens.
+fn two_screens(app: &mut bevy_app::App) {
+    app.world_mut()
+        .spawn(NavigationStack::new().root_screen(MainMenu));
+    app.update();
+    app.world_mut().commands().push_screen(MainMenu);
+    app.update();
+    assert_eq!(app.world().resource::<ScreenStack>().len(), 2);
+}
+
+fn escape(app: &mut bevy_app::App) {
+    common::tap(app, KeyCode::Escape, Key::Escape);
+    app.update();
+}
+
+#[test]
+fn escape_stays_inside_trapping_scopes() {
+    let mut app = common::app();
+    two_screens(&mut app);
+    let modal = app
+        .world_mut()
+        .spawn(VStack::new().focus_scope(FocusScope::trapped()))
+        .id();
+    app.update();
+
+    escape(&mut app);
+    assert_eq!(app.world().resource::<ScreenStack>().len(), 2);
+
+    app.world_mut().entity_mut(modal).despawn();
+    app.update();
+    escape(&mut app);
+    assert_eq!(app.world().resource::<ScreenStack>().len(), 1);
+}
+
+#[test]
+fn escape_cancels_a_reorder_before_going_back() {
+    let mut app = common::app();
+    two_screens(&mut app);
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    let list = app.world_mut().spawn(ReorderableVStack::new()).id();
+    // Layout never runs, so place the row by hand
+    let row = app
+        .world_mut()
+        .spawn((
+            Node::default(),
+            ComputedNode {
+                size: Vec2::new(100.0, 40.0),
+                inverse_scale_factor: 1.0,
+                ..Default::default()
+            },
+            GlobalTransform::from_translation(Vec3::new(50.0, 20.0, 0.0)),
+            ChildOf(list),
+        ))
+        .id();
+    app.update();
+
+    let set_cursor = |app: &mut bevy_app::App, y| {
+        app.world_mut()
+            .get_mut::<Window>(window)
+            .unwrap()
+            .set_cursor_position(Some(Vec2::new(50.0, y)));
+    };
+    set_cursor(&mut app, 20.0);
+    *app.world_mut().get_mut::<Interaction>(row).unwrap() = Interaction::Pressed;
+    app.world_mut().send_event(MouseButtonInput {
+        button: MouseButton::Left,
+        state: ButtonState::Pressed,
+        window,
+    });
+    app.update();
+    set_cursor(&mut app, 30.0);
+    app.update();
+    assert!(app.world().get::<GlobalZIndex>(row).is_some());
+
+    escape(&mut app);
+    assert_eq!(app.world().resource::<ScreenStack>().len(), 2);
+    // The row was put back
+    assert!(app.world().get::<GlobalZIndex>(row).is_none());
+}
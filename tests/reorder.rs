@@ -0,0 +1,73 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::mouse::{MouseButton, MouseButtonInput};
+use bevy_math::{Vec2, Vec3};
+use bevy_transform::prelude::*;
+use bevy_ui::ComputedNode;
+use bevy_ui::prelude::*;
+use bevy_window::{PrimaryWindow, Window};
+use swoop_ui::prelude::*;
+
+fn set_cursor(app: &mut bevy_app::App, window: Entity, position: Vec2) {
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .set_cursor_position(Some(position));
+}
+
+fn send_left_mouse(app: &mut bevy_app::App, window: Entity, state: ButtonState) {
+    app.world_mut().send_event(MouseButtonInput {
+        button: MouseButton::Left,
+        state,
+        window,
+    });
+}
+
+#[test]
+fn dropped_rows_keep_their_global_z_index() {
+    let mut app = common::app();
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    let list = app.world_mut().spawn(ReorderableVStack::new()).id();
+    // Layout never runs, so place the rows by hand
+    let rows = [(20.0, Some(GlobalZIndex(5))), (60.0, None)].map(|(y, z_index)| {
+        let mut row = app.world_mut().spawn((
+            Node::default(),
+            ComputedNode {
+                size: Vec2::new(100.0, 40.0),
+                inverse_scale_factor: 1.0,
+                ..Default::default()
+            },
+            GlobalTransform::from_translation(Vec3::new(50.0, y, 0.0)),
+            ChildOf(list),
+        ));
+        if let Some(z_index) = z_index {
+            row.insert(z_index);
+        }
+        row.id()
+    });
+    app.update();
+
+    for (row, expected) in [(rows[0], Some(GlobalZIndex(5))), (rows[1], None)] {
+        set_cursor(&mut app, window, Vec2::new(50.0, 20.0));
+        *app.world_mut().get_mut::<Interaction>(row).unwrap() = Interaction::Pressed;
+        send_left_mouse(&mut app, window, ButtonState::Pressed);
+        app.update();
+        set_cursor(&mut app, window, Vec2::new(50.0, 30.0));
+        app.update();
+        assert_eq!(
+            app.world().get::<GlobalZIndex>(row),
+            Some(&GlobalZIndex(2000))
+        );
+
+        send_left_mouse(&mut app, window, ButtonState::Released);
+        app.update();
+        assert_eq!(app.world().get::<GlobalZIndex>(row), expected.as_ref());
+        *app.world_mut().get_mut::<Interaction>(row).unwrap() = Interaction::None;
+        app.update();
+    }
+}
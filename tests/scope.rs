@@ -0,0 +1,51 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use bevy_state::app::StatesPlugin;
+use bevy_state::prelude::*;
+use swoop_ui::prelude::*;
+
+#[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+enum GameState {
+    #[default]
+    Menu,
+    Playing,
+}
+
+fn named(app: &mut bevy_app::App, name: &str) -> usize {
+    app.world_mut()
+        .query::<&Name>()
+        .iter(app.world())
+        .filter(|n| n.as_str() == name)
+        .count()
+}
+
+fn enter(app: &mut bevy_app::App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.update();
+}
+
+#[test]
+fn screens_live_as_long_as_their_state() {
+    let mut app = common::app();
+    app.add_plugins(StatesPlugin)
+        .init_state::<GameState>()
+        .add_swoop_screen(GameState::Menu, || VStack::from_name("MainMenu"));
+    app.update();
+    assert_eq!(named(&mut app, "MainMenu"), 1);
+
+    // Views spawned while in the state can be scoped to it as well
+    app.world_mut()
+        .spawn(HStack::from_name("Banner").scoped_to(GameState::Menu));
+    app.update();
+    assert_eq!(named(&mut app, "Banner"), 1);
+
+    enter(&mut app, GameState::Playing);
+    assert_eq!(named(&mut app, "MainMenu"), 0);
+    assert_eq!(named(&mut app, "Banner"), 0);
+
+    enter(&mut app, GameState::Menu);
+    assert_eq!(named(&mut app, "MainMenu"), 1);
+}
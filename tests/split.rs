@@ -0,0 +1,132 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonState;
+use bevy_input::mouse::{MouseButton, MouseButtonInput};
+use bevy_math::Vec2;
+use bevy_transform::prelude::*;
+use bevy_ui::ComputedNode;
+use bevy_ui::prelude::*;
+use bevy_window::{PrimaryWindow, Window};
+use swoop_ui::prelude::*;
+
+fn set_cursor(app: &mut bevy_app::App, window: Entity, position: Vec2) {
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .set_cursor_position(Some(position));
+}
+
+fn send_left_mouse(app: &mut bevy_app::App, window: Entity, state: ButtonState) {
+    app.world_mut().send_event(MouseButtonInput {
+        button: MouseButton::Left,
+        state,
+        window,
+    });
+}
+
+/// Spawns a 606 by 200 pixel split view whose first pane is kept between 100 and 300 pixels.
+fn app() -> (bevy_app::App, Entity, Entity, Entity) {
+    let mut app = common::app();
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    let view = app
+        .world_mut()
+        .spawn(
+            HSplitView::new()
+                .pane_limits(SplitPane::First, 100.0, 300.0)
+                .panes(VStack::new, VStack::new),
+        )
+        .id();
+    app.update();
+    // Layout never runs, so place the view by hand
+    app.world_mut().entity_mut(view).insert((
+        ComputedNode {
+            size: Vec2::new(606.0, 200.0),
+            inverse_scale_factor: 1.0,
+            ..Default::default()
+        },
+        GlobalTransform::from_translation(Vec2::new(303.0, 100.0).extend(0.0)),
+    ));
+    let divider = app.world().get::<Children>(view).unwrap()[1];
+    (app, window, view, divider)
+}
+
+/// Presses the divider with the cursor at `position` and runs a frame.
+fn press(app: &mut bevy_app::App, window: Entity, divider: Entity, position: Vec2) {
+    set_cursor(app, window, position);
+    *app.world_mut().get_mut::<Interaction>(divider).unwrap() = Interaction::Pressed;
+    send_left_mouse(app, window, ButtonState::Pressed);
+    app.update();
+}
+
+/// Releases the divider and runs a frame.
+fn release(app: &mut bevy_app::App, window: Entity, divider: Entity) {
+    *app.world_mut().get_mut::<Interaction>(divider).unwrap() = Interaction::Hovered;
+    send_left_mouse(app, window, ButtonState::Released);
+    app.update();
+}
+
+/// Returns the current split of `view`.
+fn split(app: &bevy_app::App, view: Entity) -> SplitRatio {
+    *app.world().get::<SplitRatio>(view).unwrap()
+}
+
+#[test]
+fn dragged_dividers_stay_within_the_pane_limits() {
+    let (mut app, window, view, divider) = app();
+    press(&mut app, window, divider, Vec2::new(303.0, 100.0));
+
+    set_cursor(&mut app, window, Vec2::new(203.0, 100.0));
+    app.update();
+    assert_eq!(split(&app, view).ratio, 200.0 / 606.0);
+
+    set_cursor(&mut app, window, Vec2::new(20.0, 100.0));
+    app.update();
+    assert_eq!(split(&app, view).ratio, 100.0 / 606.0);
+
+    set_cursor(&mut app, window, Vec2::new(580.0, 100.0));
+    app.update();
+    assert_eq!(split(&app, view).ratio, 300.0 / 606.0);
+
+    // Moving after the release leaves the split alone
+    release(&mut app, window, divider);
+    set_cursor(&mut app, window, Vec2::new(203.0, 100.0));
+    app.update();
+    assert_eq!(split(&app, view).ratio, 300.0 / 606.0);
+}
+
+#[test]
+fn double_clicking_the_divider_collapses_and_restores_the_pane() {
+    let (mut app, window, view, divider) = app();
+    let first_pane = app.world().get::<Children>(view).unwrap()[0];
+    let at = Vec2::new(303.0, 100.0);
+
+    press(&mut app, window, divider, at);
+    release(&mut app, window, divider);
+    press(&mut app, window, divider, at);
+    release(&mut app, window, divider);
+    assert_eq!(
+        split(&app, view),
+        SplitRatio {
+            ratio: 0.5,
+            collapsed: true
+        }
+    );
+    assert_eq!(
+        app.world().get::<Node>(first_pane).unwrap().display,
+        Display::None
+    );
+
+    press(&mut app, window, divider, at);
+    release(&mut app, window, divider);
+    press(&mut app, window, divider, at);
+    release(&mut app, window, divider);
+    assert!(!split(&app, view).collapsed);
+    assert_eq!(
+        app.world().get::<Node>(first_pane).unwrap().display,
+        Display::Flex
+    );
+}
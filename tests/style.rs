@@ -0,0 +1,79 @@
+mod common;
+
+use bevy_asset::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+use swoop_ui::prelude::*;
+
+fn sheet(source: &str) -> StyleSheet {
+    StyleSheet::parse(source).unwrap()
+}
+
+fn node(app: &bevy_app::App, entity: Entity) -> &Node {
+    app.world().get::<Node>(entity).unwrap()
+}
+
+#[test]
+fn reloaded_sheet_resets_removed_declarations() {
+    let mut app = common::app();
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<StyleSheet>>()
+        .add(sheet(
+            ".toolbar { padding: 8px; width: 50%; background-color: red; top: 4px; }",
+        ));
+    app.world_mut()
+        .resource_mut::<StyleSheets>()
+        .push(handle.clone());
+    let view = app
+        .world_mut()
+        .spawn(
+            HStack::new()
+                .class("toolbar")
+                .width(Val::Px(120.0))
+                .background_color(Color::BLACK),
+        )
+        .id();
+    app.update();
+    assert_eq!(node(&app, view).padding, UiRect::all(Val::Px(8.0)));
+    assert_eq!(node(&app, view).width, Val::Percent(50.0));
+    assert_eq!(node(&app, view).position_type, PositionType::Absolute);
+
+    app.world_mut()
+        .resource_mut::<Assets<StyleSheet>>()
+        .insert(&handle, sheet(".toolbar { padding: 2px; }"));
+    // Asset events are sent after styles are applied, so the reload shows a frame later
+    app.update();
+    app.update();
+    let node = node(&app, view);
+    assert_eq!(node.padding, UiRect::all(Val::Px(2.0)));
+    assert_eq!(node.width, Val::Px(120.0));
+    assert_eq!(node.top, Val::Auto);
+    assert_eq!(node.position_type, PositionType::Relative);
+    assert_eq!(
+        app.world().get::<BackgroundColor>(view).unwrap().0,
+        Color::BLACK
+    );
+}
+
+#[test]
+fn removing_a_class_resets_its_declarations() {
+    let mut app = common::app();
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<StyleSheet>>()
+        .add(sheet(".wide { width: 300px; } HStack { height: 20px; }"));
+    app.world_mut().resource_mut::<StyleSheets>().push(handle);
+    let view = app.world_mut().spawn(HStack::new().class("wide")).id();
+    app.update();
+    assert_eq!(node(&app, view).width, Val::Px(300.0));
+
+    app.world_mut()
+        .get_mut::<ViewClass>(view)
+        .unwrap()
+        .remove("wide");
+    app.update();
+    assert_eq!(node(&app, view).width, Val::Auto);
+    assert_eq!(node(&app, view).height, Val::Px(20.0));
+}
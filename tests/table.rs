@@ -0,0 +1,142 @@
+mod common;
+
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_math::{Rect, Vec2};
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, RelativeCursorPosition};
+use swoop_ui::container::table::{TableBody, TableCell};
+use swoop_ui::prelude::*;
+
+struct Player {
+    id: u64,
+    name: &'static str,
+}
+
+#[derive(Resource)]
+struct Players(Vec<Player>);
+
+fn player(id: u64, name: &'static str) -> Player {
+    Player { id, name }
+}
+
+fn spawn_table(app: &mut bevy_app::App, table: Table) -> Entity {
+    app.insert_resource(Players(vec![player(1, "Ada"), player(2, "Grace")]));
+    let table = app
+        .world_mut()
+        .spawn(table.rows(
+            |players: &Players| &players.0,
+            |player| player.id,
+            vec![TableColumn::new(
+                "Name",
+                GridTrack::flex(1.0),
+                |player: &Player| SText::new().text(player.name),
+            )],
+        ))
+        .id();
+    app.update();
+    table
+}
+
+fn selected_row(app: &bevy_app::App, table: Entity) -> Option<usize> {
+    app.world().get::<TableSelection>(table).unwrap().row
+}
+
+/// Returns the collection indices of the highlighted cells.
+fn highlighted(app: &mut bevy_app::App) -> Vec<usize> {
+    app.world_mut()
+        .query::<(&TableCell, &BackgroundColor)>()
+        .iter(app.world())
+        .filter(|(_, background)| background.0 != Color::NONE)
+        .map(|(cell, _)| cell.row)
+        .collect()
+}
+
+#[test]
+fn selection_follows_its_item() {
+    let mut app = common::app();
+    let table = spawn_table(&mut app, Table::new());
+    let grace = app
+        .world_mut()
+        .query::<(Entity, &TableCell)>()
+        .iter(app.world())
+        .find(|(_, cell)| cell.row == 1)
+        .map(|(entity, _)| entity)
+        .unwrap();
+    *app.world_mut().get_mut::<Interaction>(grace).unwrap() = Interaction::Pressed;
+    app.update();
+    assert_eq!(selected_row(&app, table), Some(1));
+
+    let mut players = app.world_mut().resource_mut::<Players>();
+    players.0.insert(0, player(3, "Linus"));
+    app.update();
+    assert_eq!(selected_row(&app, table), Some(2));
+    assert_eq!(highlighted(&mut app), vec![2]);
+
+    app.world_mut().resource_mut::<Players>().0.remove(2);
+    app.update();
+    assert_eq!(selected_row(&app, table), None);
+    assert!(highlighted(&mut app).is_empty());
+}
+
+/// Sizes `entity` by hand, since layout never runs, with room to scroll 800 pixels.
+fn size_scrollable(app: &mut bevy_app::App, entity: Entity) {
+    app.world_mut().entity_mut(entity).insert(ComputedNode {
+        size: Vec2::new(300.0, 200.0),
+        content_size: Vec2::new(300.0, 1000.0),
+        inverse_scale_factor: 1.0,
+        ..Default::default()
+    });
+}
+
+fn hover_and_scroll(app: &mut bevy_app::App, table: Entity, unit: MouseScrollUnit, y: f32) {
+    app.world_mut()
+        .entity_mut(table)
+        .insert(RelativeCursorPosition {
+            normalized_visible_node_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+            normalized: Some(Vec2::splat(0.5)),
+        });
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut().send_event(MouseWheel {
+        unit,
+        x: 0.0,
+        y,
+        window,
+    });
+    app.update();
+}
+
+fn offset(app: &bevy_app::App, entity: Entity) -> f32 {
+    app.world().get::<ScrollPosition>(entity).unwrap().offset_y
+}
+
+#[test]
+fn mouse_wheel_scrolls_the_hovered_table() {
+    let mut app = common::app();
+    let table = spawn_table(&mut app, Table::new());
+    size_scrollable(&mut app, table);
+
+    hover_and_scroll(&mut app, table, MouseScrollUnit::Line, -2.0);
+    assert_eq!(offset(&app, table), 48.0);
+    hover_and_scroll(&mut app, table, MouseScrollUnit::Pixel, -5000.0);
+    assert_eq!(offset(&app, table), 800.0);
+}
+
+#[test]
+fn mouse_wheel_scrolls_the_body_below_a_sticky_header() {
+    let mut app = common::app();
+    let table = spawn_table(&mut app, Table::new().sticky_header(true));
+    let body = app
+        .world_mut()
+        .query::<(Entity, &TableBody)>()
+        .iter(app.world())
+        .find(|(_, body)| body.table == table)
+        .map(|(entity, _)| entity)
+        .unwrap();
+    size_scrollable(&mut app, body);
+
+    hover_and_scroll(&mut app, table, MouseScrollUnit::Pixel, -100.0);
+    assert_eq!(offset(&app, body), 100.0);
+    assert_eq!(offset(&app, table), 0.0);
+}
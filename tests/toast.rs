@@ -0,0 +1,64 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy_app::App;
+use bevy_time::TimeUpdateStrategy;
+use swoop_ui::prelude::*;
+
+/// An app whose clock advances by 0.25 seconds every frame.
+fn app() -> App {
+    let mut app = common::app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        0.25,
+    )));
+    app.update();
+    app
+}
+
+/// Runs frames until `seconds` have passed.
+fn run_for(app: &mut App, seconds: f32) {
+    for _ in 0..(seconds / 0.25).round() as usize {
+        app.update();
+    }
+}
+
+/// Returns the number of visible and pending toasts.
+fn counts(app: &App) -> (usize, usize) {
+    let toasts = app.world().resource::<Toasts>();
+    (toasts.visible_count(), toasts.pending_count())
+}
+
+#[test]
+fn toasts_beyond_the_visible_cap_wait_their_turn() {
+    let mut app = app();
+    let mut toasts = app.world_mut().resource_mut::<Toasts>();
+    toasts.duration = 2.0;
+    for message in ["One", "Two", "Three", "Four", "Five"] {
+        toasts.push(Toast::info(message));
+    }
+    app.update();
+    assert_eq!(counts(&app), (3, 2));
+
+    run_for(&mut app, 1.0);
+    assert_eq!(counts(&app), (3, 2));
+
+    run_for(&mut app, 2.0);
+    assert_eq!(counts(&app), (2, 0));
+
+    run_for(&mut app, 3.0);
+    assert_eq!(counts(&app), (0, 0));
+}
+
+#[test]
+fn persistent_toasts_outlive_the_duration() {
+    let mut app = app();
+    let mut toasts = app.world_mut().resource_mut::<Toasts>();
+    toasts.push(Toast::warning("Disk almost full").persistent());
+    toasts.push(Toast::info("Saved").duration(0.5));
+    app.update();
+    assert_eq!(counts(&app), (2, 0));
+
+    run_for(&mut app, 10.0);
+    assert_eq!(counts(&app), (1, 0));
+}
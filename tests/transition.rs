@@ -0,0 +1,116 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::TimeUpdateStrategy;
+use bevy_ui::prelude::*;
+use swoop_ui::prelude::*;
+
+/// An app whose clock advances by 0.1 seconds every frame.
+fn app() -> bevy_app::App {
+    let mut app = common::app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        0.1,
+    )));
+    app
+}
+
+fn alpha(app: &bevy_app::App, entity: Entity) -> f32 {
+    app.world()
+        .get::<BackgroundColor>(entity)
+        .unwrap()
+        .0
+        .alpha()
+}
+
+fn left(app: &bevy_app::App, entity: Entity) -> Val {
+    app.world().get::<Node>(entity).unwrap().left
+}
+
+fn spawn_view(app: &mut bevy_app::App) -> Entity {
+    app.world_mut()
+        .spawn(
+            VStack::new()
+                .background_color(Color::srgb(1.0, 0.0, 0.0))
+                .transition(
+                    Transition::fade()
+                        .combined(Transition::move_by(Edge::Leading, Val::Px(40.0)))
+                        .animation(Animation::linear(0.4)),
+                ),
+        )
+        .id()
+}
+
+fn assert_near(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "expected {expected}, got {actual}"
+    );
+}
+
+fn left_px(app: &bevy_app::App, entity: Entity) -> f32 {
+    match left(app, entity) {
+        Val::Px(left) => left,
+        other => panic!("unexpected left {other:?}"),
+    }
+}
+
+#[test]
+fn views_appear_from_their_hidden_keyframes() {
+    let mut app = app();
+    let view = spawn_view(&mut app);
+
+    app.update();
+    assert_near(alpha(&app, view), 0.0);
+    assert_near(left_px(&app, view), -40.0);
+
+    for (expected_alpha, expected_left) in [(0.25, -30.0), (0.5, -20.0), (0.75, -10.0), (1.0, 0.0)]
+    {
+        app.update();
+        assert_near(alpha(&app, view), expected_alpha);
+        assert_near(left_px(&app, view), expected_left);
+    }
+    assert!(app.world().get::<ActiveTweens>(view).is_none());
+}
+
+#[test]
+fn exiting_views_stay_alive_until_their_exit_transition_ends() {
+    let mut app = app();
+    let view = spawn_view(&mut app);
+    for _ in 0..6 {
+        app.update();
+    }
+
+    app.world_mut()
+        .commands()
+        .entity(view)
+        .despawn_with_transition();
+    for (expected_alpha, expected_left) in [(0.75, -10.0), (0.5, -20.0), (0.25, -30.0)] {
+        app.update();
+        assert!(app.world().get::<Exiting>(view).is_some());
+        assert_near(alpha(&app, view), expected_alpha);
+        assert_near(left_px(&app, view), expected_left);
+    }
+
+    // Allow for the countdown landing a rounding error short of zero
+    for _ in 0..2 {
+        app.update();
+    }
+    assert!(app.world().get_entity(view).is_err());
+}
+
+#[test]
+fn views_without_a_transition_despawn_immediately() {
+    let mut app = app();
+    let view = app.world_mut().spawn(VStack::new()).id();
+    app.update();
+
+    app.world_mut()
+        .commands()
+        .entity(view)
+        .despawn_with_transition();
+    app.world_mut().flush();
+    assert!(app.world().get_entity(view).is_err());
+}
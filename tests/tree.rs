@@ -0,0 +1,93 @@
+mod common;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bevy_ecs::prelude::*;
+use bevy_input::keyboard::{Key, KeyCode};
+use bevy_math::{Vec2, Vec3};
+use bevy_transform::prelude::*;
+use bevy_ui::ComputedNode;
+use swoop_ui::container::tree::TreeRow;
+use swoop_ui::prelude::*;
+
+/// Spawns a tree of `u32` nodes where nodes below 10 have two children, counting
+/// how often children are requested.
+fn tree(app: &mut bevy_app::App, requests: Arc<AtomicUsize>) -> Entity {
+    let tree = app
+        .world_mut()
+        .spawn(Tree::new().nodes(
+            |_| vec![1, 2],
+            move |_, node: &u32| {
+                requests.fetch_add(1, Ordering::Relaxed);
+                if *node < 10 {
+                    vec![node * 10, node * 10 + 1]
+                } else {
+                    vec![]
+                }
+            },
+            |_, node| SText::new().text(node.to_string()),
+        ))
+        .id();
+    app.update();
+    tree
+}
+
+fn rows(app: &mut bevy_app::App) -> Vec<(Entity, TreeRow)> {
+    let mut rows: Vec<_> = app
+        .world_mut()
+        .query::<(Entity, &TreeRow)>()
+        .iter(app.world())
+        .map(|(entity, row)| (entity, *row))
+        .collect();
+    rows.sort_by_key(|(_, row)| row.index);
+    rows
+}
+
+#[test]
+fn children_are_only_requested_for_expanded_nodes() {
+    let mut app = common::app();
+    let requests = Arc::new(AtomicUsize::new(0));
+    let tree = tree(&mut app, requests.clone());
+    assert_eq!(rows(&mut app).len(), 2);
+    assert_eq!(requests.load(Ordering::Relaxed), 0);
+
+    app.world_mut()
+        .get_mut::<TreeState<u32>>(tree)
+        .unwrap()
+        .expand(1);
+    app.update();
+    let rows = rows(&mut app);
+    assert_eq!(rows.len(), 4);
+    assert_eq!(requests.load(Ordering::Relaxed), 1);
+    // Collapsed nodes keep their chevron until expanding them shows what is inside
+    assert!(rows.iter().all(|(_, row)| row.expandable));
+}
+
+#[test]
+fn left_and_right_expand_instead_of_moving_focus() {
+    let mut app = common::app();
+    tree(&mut app, Arc::new(AtomicUsize::new(0)));
+    let (row, _) = rows(&mut app)[0];
+    let button = app.world_mut().spawn(HButton::new()).id();
+    // Layout never runs, so place the first row and a button to its right by hand
+    for (entity, x) in [(row, 0.0), (button, 200.0)] {
+        app.world_mut().entity_mut(entity).insert((
+            ComputedNode {
+                size: Vec2::new(80.0, 40.0),
+                inverse_scale_factor: 1.0,
+                ..Default::default()
+            },
+            GlobalTransform::from_translation(Vec3::new(x, 0.0, 0.0)),
+        ));
+    }
+    app.world_mut().resource_mut::<FocusedEntity>().0 = Some(row);
+    app.update();
+
+    common::tap(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    app.update();
+    let rows = rows(&mut app);
+    assert_eq!(rows.len(), 4);
+    assert!(rows[0].1.expanded);
+    assert_ne!(app.world().resource::<FocusedEntity>().0, Some(button));
+}
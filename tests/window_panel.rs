@@ -0,0 +1,55 @@
+mod common;
+
+use bevy_ecs::prelude::*;
+use bevy_math::{Rect, Vec2};
+use bevy_ui::prelude::*;
+use bevy_ui::{ComputedNode, RelativeCursorPosition};
+use swoop_ui::prelude::*;
+
+/// Places `entity` under the cursor at the given stacking order, as layout and picking would.
+fn under_cursor(app: &mut bevy_app::App, entity: Entity, stack_index: u32) {
+    app.world_mut().entity_mut(entity).insert((
+        ComputedNode {
+            size: Vec2::new(100.0, 100.0),
+            stack_index,
+            inverse_scale_factor: 1.0,
+            ..Default::default()
+        },
+        RelativeCursorPosition {
+            normalized_visible_node_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+            normalized: Some(Vec2::splat(0.5)),
+        },
+    ));
+}
+
+#[test]
+fn pressing_a_slider_raises_its_panel() {
+    let mut app = common::app();
+    let root = app.world_mut().spawn(Node::default()).id();
+    let back = app
+        .world_mut()
+        .spawn((WindowPanel::new(), ChildOf(root)))
+        .id();
+    let front = app
+        .world_mut()
+        .spawn((WindowPanel::new(), ChildOf(root)))
+        .id();
+    let slider = app
+        .world_mut()
+        .spawn((Slider::new().pack(), ChildOf(back)))
+        .id();
+    let sibling = app
+        .world_mut()
+        .spawn((Slider::new().pack(), ChildOf(back)))
+        .id();
+    app.update();
+    app.world_mut().entity_mut(front).insert(ZIndex(1));
+    under_cursor(&mut app, back, 1);
+    under_cursor(&mut app, slider, 5);
+
+    common::press_left_mouse(&mut app);
+    let z_index = |app: &bevy_app::App, entity| app.world().get::<ZIndex>(entity).unwrap().0;
+    assert_eq!(z_index(&app, back), 2);
+    assert_eq!(z_index(&app, front), 1);
+    assert_eq!(z_index(&app, slider), z_index(&app, sibling));
+}